@@ -2,7 +2,9 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use syn::{ReturnType, Type, TypePath};
 
-use crate::function::utils::{clear_ref, get_literal_type};
+use crate::function::utils::{
+    clear_ref, get_literal_type, get_option_type, get_result_types, is_bytes, is_unit,
+};
 
 pub(crate) fn generate_function(
     externals: &Vec<(Ident, &Type)>,
@@ -23,6 +25,28 @@ pub(crate) fn generate_function(
     }
 }
 
+// Arguments are taken one by one from a mutable iterator,
+// so that mutable references to them can coexist
+pub(crate) fn generate_function_mut(
+    externals: &Vec<(Ident, &Type)>,
+    inputs: &Vec<(Ident, &Type)>,
+    output: &ReturnType,
+    args: TokenStream,
+    block: TokenStream,
+) -> TokenStream {
+    let exts = generate_exts(externals);
+    let ins = generate_inputs_mut(inputs);
+    let call = function_call(exts, ins, output);
+    let out = return_output(output);
+
+    quote! {
+        let func = move |#args| #output #block;
+        let mut args = args.iter_mut();
+        #call
+        #out
+    }
+}
+
 fn generate_exts(externals: &Vec<(Ident, &Type)>) -> TokenStream {
     let exts: Vec<TokenStream> = externals
         .iter()
@@ -38,20 +62,80 @@ fn generate_inputs(inputs: &Vec<(Ident, &Type)>) -> TokenStream {
     let args: Vec<TokenStream> = inputs
         .iter()
         .enumerate()
-        .map(|(index, (_, ty))| {
-            let ty = clear_ref(*ty);
-            let type_name = ty.path.segments.last().unwrap().ident.to_string();
-            if type_name == "Variable" {
-                quote! { &args[#index].clone() }
-            } else {
-                quote! { args[#index].try_parse_ref::<#ty>()? }
+        .map(|(index, (_, ty))| match get_option_type(ty) {
+            Some(ty) => {
+                let arg = generate_input(quote! { arg }, ty);
+                quote! {
+                    match args.get(#index) {
+                        None | Some(plux_rs::variable::Variable::Null) => None,
+                        Some(arg) => Some(#arg),
+                    }
+                }
             }
+            None => generate_input(quote! { args[#index] }, ty),
         })
         .collect();
 
     quote! { #(#args), * }
 }
 
+fn generate_inputs_mut(inputs: &Vec<(Ident, &Type)>) -> TokenStream {
+    let args: Vec<TokenStream> = inputs
+        .iter()
+        .map(|(_, ty)| match get_option_type(ty) {
+            Some(ty) => {
+                let arg = generate_input(quote! { arg }, ty);
+                quote! {
+                    match args.next() {
+                        None | Some(plux_rs::variable::Variable::Null) => None,
+                        Some(arg) => Some(#arg),
+                    }
+                }
+            }
+            None => generate_input(quote! { args.next().ok_or("missing argument")? }, ty),
+        })
+        .collect();
+
+    quote! { #(#args), * }
+}
+
+fn generate_input(arg: TokenStream, ty: &Type) -> TokenStream {
+    if let Type::Reference(r) = ty
+        && r.mutability.is_some()
+    {
+        return generate_input_mut(arg, &r.elem);
+    }
+
+    if is_bytes(ty) {
+        return match ty {
+            Type::Reference(_) => quote! { #arg.try_parse_ref::<[u8]>()? },
+            _ => quote! { #arg.try_parse_ref::<[u8]>()?.to_vec() },
+        };
+    }
+
+    let ty = clear_ref(ty);
+    let type_name = ty.path.segments.last().unwrap().ident.to_string();
+    if type_name == "Variable" {
+        quote! { &#arg.clone() }
+    } else {
+        quote! { #arg.try_parse_ref::<#ty>()? }
+    }
+}
+
+fn generate_input_mut(arg: TokenStream, ty: &Type) -> TokenStream {
+    if is_bytes(ty) {
+        return quote! { #arg.try_parse_mut::<[u8]>()? };
+    }
+
+    let ty = get_literal_type(ty);
+    let type_name = ty.path.segments.last().unwrap().ident.to_string();
+    if type_name == "Variable" {
+        quote! { #arg }
+    } else {
+        quote! { #arg.try_parse_mut::<#ty>()? }
+    }
+}
+
 fn function_call(exts: TokenStream, args: TokenStream, output: &ReturnType) -> TokenStream {
     let output_token = match output {
         syn::ReturnType::Default => None,
@@ -64,22 +148,43 @@ fn function_call(exts: TokenStream, args: TokenStream, output: &ReturnType) -> T
 fn return_output(output: &ReturnType) -> TokenStream {
     match output {
         syn::ReturnType::Default => quote! { Ok(None) },
-        syn::ReturnType::Type(_, ty) => {
-            let result = serialize_output(get_literal_type(&*ty));
-            quote! { Ok(Some(#result)) }
-        }
+        syn::ReturnType::Type(_, ty) => match get_result_types(ty) {
+            Some((ok, _)) => {
+                let result = return_result(ok);
+                quote! {
+                    match result {
+                        Ok(result) => #result,
+                        Err(e) => Err(e.into()),
+                    }
+                }
+            }
+            None => return_result(ty),
+        },
+    }
+}
+
+fn return_result(ty: &Type) -> TokenStream {
+    if is_unit(ty) {
+        quote! { Ok(None) }
+    } else if is_bytes(ty) {
+        quote! { Ok(Some(plux_rs::variable::Variable::Bytes(result.into()))) }
+    } else {
+        let result = serialize_output(get_literal_type(ty));
+        quote! { Ok(Some(#result)) }
     }
 }
 
-const VARIABLE_DATAS: [(&str, &str); 13] = [
+const VARIABLE_DATAS: [(&str, &str); 15] = [
     ("i8", "I8"),
     ("i16", "I16"),
     ("i32", "I32"),
     ("i64", "I64"),
+    ("i128", "I128"),
     ("u8", "U8"),
     ("u16", "U16"),
     ("u32", "U32"),
     ("u64", "U64"),
+    ("u128", "U128"),
     ("f32", "F32"),
     ("f64", "F64"),
     ("bool", "Bool"),
@@ -3,8 +3,11 @@ use quote::{format_ident, quote, ToTokens};
 use syn::{Error, ItemFn, Result, ReturnType, Signature, Type, TypePath};
 
 use super::{
-    generate_function::generate_function,
-    utils::{get_attributes, get_inputs, get_literal_type},
+    generate_function::{generate_function, generate_function_mut},
+    utils::{
+        get_attributes, get_inputs, get_literal_type, get_option_type, get_result_types, is_bytes,
+        is_mut_ref, is_unit,
+    },
 };
 
 pub(crate) fn generate_struct(
@@ -14,28 +17,56 @@ pub(crate) fn generate_struct(
     attr: &TokenStream,
     exts: &Vec<(Ident, &Type)>,
 ) -> Result<TokenStream> {
-    let attrs = get_attributes(attr);
+    let attrs = get_attributes(attr)?;
 
     let externals = generate_externals(exts);
 
     let ins = get_inputs(sig.inputs.iter().skip(1));
 
-    let name = generate_name(attrs.get("name"), &ident.to_string());
-    // //TODO: Внедрить описание функций в August
-    // // let description = generate_description(
-    // //     attrs.get("description"),
-    // //     &"Description is missing".to_string(),
-    // // );
+    let name = generate_name(
+        attrs.get("export").or(attrs.get("name")),
+        &ident.to_string(),
+    );
+    let description = generate_description(attrs.get("description"));
     let inputs = generate_inputs(&ins)?;
     let output = generate_output(&sig.output)?;
 
-    let function = generate_function(
-        &exts,
-        &ins,
-        &sig.output,
-        ast.sig.inputs.to_token_stream(),
-        ast.block.as_ref().to_token_stream(),
-    );
+    let call = match ins.iter().any(|(_, ty)| is_mut_ref(ty)) {
+        true => {
+            let function = generate_function_mut(
+                exts,
+                &ins,
+                &sig.output,
+                ast.sig.inputs.to_token_stream(),
+                ast.block.as_ref().to_token_stream(),
+            );
+
+            quote! {
+                fn call(&self, args: &[plux_rs::variable::Variable]) -> Self::Output {
+                    self.call_mut(&mut args.to_vec())
+                }
+
+                fn call_mut(&self, args: &mut [plux_rs::variable::Variable]) -> Self::Output {
+                    #function
+                }
+            }
+        }
+        false => {
+            let function = generate_function(
+                exts,
+                &ins,
+                &sig.output,
+                ast.sig.inputs.to_token_stream(),
+                ast.block.as_ref().to_token_stream(),
+            );
+
+            quote! {
+                fn call(&self, args: &[plux_rs::variable::Variable]) -> Self::Output {
+                    #function
+                }
+            }
+        }
+    };
 
     Ok(quote! {
         struct Function { #externals }
@@ -47,6 +78,10 @@ pub(crate) fn generate_struct(
                 #name
             }
 
+            fn description(&self) -> Option<String> {
+                #description
+            }
+
             fn inputs(&self) -> Vec<plux_rs::function::Arg> {
                 #inputs
             }
@@ -55,9 +90,7 @@ pub(crate) fn generate_struct(
                 #output
             }
 
-            fn call(&self, args: &[plux_rs::variable::Variable]) -> Self::Output {
-                #function
-            }
+            #call
         }
     })
 }
@@ -78,11 +111,12 @@ fn generate_name(name: Option<&String>, or: &String) -> TokenStream {
     quote! { #name.to_string() }
 }
 
-//TODO: Внедрить описание функций в August
-// fn generate_description(description: Option<&String>, or: &String) -> TokenStream {
-//     let description = description.map(|x| x.clone()).unwrap_or(or.to_string());
-//     quote! { #description }
-// }
+fn generate_description(description: Option<&String>) -> TokenStream {
+    match description {
+        Some(description) => quote! { Some(#description.to_string()) },
+        None => quote! { None },
+    }
+}
 
 fn generate_inputs(inputs: &Vec<(Ident, &Type)>) -> Result<TokenStream> {
     let mut result = vec![];
@@ -98,26 +132,44 @@ fn generate_output(output: &ReturnType) -> Result<TokenStream> {
     match output {
         syn::ReturnType::Default => Ok(quote! { None }),
         syn::ReturnType::Type(_, ty) => {
-            let arg = generate_arg(&"output".to_string(), &*ty)?;
+            let ty = get_result_types(ty).map_or(&**ty, |(ok, _)| ok);
+            if is_unit(ty) {
+                return Ok(quote! { None });
+            }
+
+            let arg = generate_arg(&"output".to_string(), ty)?;
             Ok(quote! { Some(#arg) })
         }
     }
 }
 
 fn generate_arg(name: &String, ty: &Type) -> Result<TokenStream> {
+    if let Some(ty) = get_option_type(ty) {
+        let arg = generate_arg(name, ty)?;
+        return Ok(quote! { #arg.optional() });
+    }
+
+    if is_bytes(ty) {
+        return Ok(
+            quote! { plux_rs::function::Arg::new(#name, plux_rs::variable::VariableType::Bytes) },
+        );
+    }
+
     let ty = get_variable_type_path(get_literal_type(ty))?;
     Ok(quote! { plux_rs::function::Arg::new(#name, #ty) })
 }
 
-const VARIABLE_TYPES: [(&str, &str); 15] = [
+const VARIABLE_TYPES: [(&str, &str); 17] = [
     ("i8", "I8"),
     ("i16", "I16"),
     ("i32", "I32"),
     ("i64", "I64"),
+    ("i128", "I128"),
     ("u8", "U8"),
     ("u16", "U16"),
     ("u32", "U32"),
     ("u64", "U64"),
+    ("u128", "U128"),
     ("f32", "F32"),
     ("f64", "F64"),
     ("bool", "Bool"),
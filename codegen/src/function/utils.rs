@@ -1,7 +1,11 @@
 use std::collections::HashMap;
 
 use proc_macro2::{Ident, Span, TokenStream};
-use syn::{Error, FnArg, Pat, Result, Type, TypePath};
+use quote::ToTokens;
+use syn::{
+    Error, Expr, ExprLit, FnArg, Lit, MetaNameValue, Pat, Result, Token, Type, TypePath,
+    parse::Parser, punctuated::Punctuated,
+};
 
 pub(crate) fn get_literal_type(ty: &Type) -> &TypePath {
     match ty {
@@ -14,20 +18,128 @@ pub(crate) fn get_literal_type(ty: &Type) -> &TypePath {
     }
 }
 
-pub(crate) fn get_attributes(attr: &TokenStream) -> HashMap<String, String> {
-    let attrs_str = attr.to_string();
-    match attrs_str.is_empty() {
-        true => HashMap::new(),
-        false => attrs_str
-            .split(',')
-            .map(|attr| {
-                let attr: Vec<&str> = attr.split('=').map(|token| token.trim()).collect();
-                (attr[0].to_string(), attr[1].trim_matches('"').to_string())
-            })
-            .collect(),
+// Raw binary data is passed as `&[u8]` or `Vec<u8>` and maps to `Variable::Bytes`
+pub(crate) fn is_bytes(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(r) => match &*r.elem {
+            Type::Slice(slice) => is_u8(&slice.elem),
+            _ => false,
+        },
+        Type::Path(path) => {
+            let segment = path.path.segments.last().unwrap();
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) if segment.ident == "Vec" => {
+                    match args.args.first() {
+                        Some(syn::GenericArgument::Type(ty)) => is_u8(ty),
+                        _ => false,
+                    }
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+// Fallible functions return `Result<T, E>`, where `T` is a supported output type
+pub(crate) fn get_result_types(ty: &Type) -> Option<(&Type, &Type)> {
+    match ty {
+        Type::Path(path) => {
+            let segment = path.path.segments.last().unwrap();
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) if segment.ident == "Result" => {
+                    let mut types = args.args.iter().filter_map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    });
+                    match (types.next(), types.next(), types.next()) {
+                        (Some(ok), Some(err), None) => Some((ok, err)),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
     }
 }
 
+// Optional arguments are passed as `Option<&T>`, where `Variable::Null` maps to `None`
+pub(crate) fn get_option_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(path) => {
+            let segment = path.path.segments.last().unwrap();
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) if segment.ident == "Option" => {
+                    match args.args.first() {
+                        Some(syn::GenericArgument::Type(ty)) if args.args.len() == 1 => Some(ty),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// Mutable arguments are passed as `&mut T`, where `T` is not a list (except `Vec<u8>`)
+pub(crate) fn is_mut_ref(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(r) => r.mutability.is_some(),
+        _ => false,
+    }
+}
+
+pub(crate) fn is_unit(ty: &Type) -> bool {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.is_empty(),
+        _ => false,
+    }
+}
+
+fn is_u8(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.is_ident("u8"),
+        _ => false,
+    }
+}
+
+pub(crate) fn parse_attributes(attr: &TokenStream) -> Result<Punctuated<MetaNameValue, Token![,]>> {
+    Punctuated::<MetaNameValue, Token![,]>::parse_terminated
+        .parse2(attr.clone())
+        .map_err(|e| Error::new(e.span(), "attributes must have the format `path = data`"))
+}
+
+// Attribute values are string literals, so they may contain `,` and `=`
+pub(crate) fn get_attribute_string(attr: &MetaNameValue) -> Result<String> {
+    match &attr.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) => Ok(value.value()),
+        value => Err(Error::new_spanned(
+            value,
+            format!(
+                "attribute `{}` must contain string",
+                attr.path.to_token_stream()
+            ),
+        )),
+    }
+}
+
+pub(crate) fn get_attributes(attr: &TokenStream) -> Result<HashMap<String, String>> {
+    parse_attributes(attr)?
+        .iter()
+        .map(|attr| {
+            Ok((
+                attr.path.to_token_stream().to_string(),
+                get_attribute_string(attr)?,
+            ))
+        })
+        .collect()
+}
+
 pub(crate) fn get_externals(arg: &FnArg) -> Vec<(Ident, &Type)> {
     match arg {
         FnArg::Receiver(_) => panic!("Receiver is not supported"),
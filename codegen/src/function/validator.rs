@@ -1,9 +1,13 @@
 use proc_macro2::TokenStream;
+use quote::ToTokens;
 use syn::{
     Error, FnArg, GenericArgument, ItemFn, Pat, PathArguments, Result, Signature, Type, TypePath,
 };
 
-use super::utils::pat_to_ident;
+use super::utils::{
+    get_attribute_string, get_option_type, get_result_types, is_bytes, is_mut_ref, is_unit,
+    parse_attributes, pat_to_ident,
+};
 
 pub(crate) fn validate(ast: &ItemFn, attr: &TokenStream) -> Result<()> {
     if !ast.sig.generics.params.is_empty() {
@@ -14,45 +18,52 @@ pub(crate) fn validate(ast: &ItemFn, attr: &TokenStream) -> Result<()> {
     validate_function(&ast.sig)
 }
 
-//TODO: Внедрить описание функций в August
-// const VALIDATE_ATTRIBUTES: [&str; 2] = ["name", "description"];
-// const VALIDATE_STRING_ATTRIBUTES: [&str; 2] = ["name", "description"];
-
-const VALIDATE_ATTRIBUTES: [&str; 1] = ["name"];
-const VALIDATE_STRING_ATTRIBUTES: [&str; 1] = ["name"];
+const VALIDATE_ATTRIBUTES: [&str; 3] = ["name", "description", "export"];
+const VALIDATE_STRING_ATTRIBUTES: [&str; 3] = ["name", "description", "export"];
+const VALIDATE_IDENT_ATTRIBUTES: [&str; 1] = ["export"];
+const VALIDATE_EXCLUSIVE_ATTRIBUTES: [(&str, &str); 1] = [("name", "export")];
 
 fn validate_attributes(attrs: &TokenStream) -> Result<()> {
-    let attrs_str = attrs.to_string();
-    if !attrs_str.is_empty() {
-        for attr in attrs_str.split(',') {
-            let attr: Vec<&str> = attr.split('=').map(|token| token.trim()).collect();
-
-            if attr.len() != 2 {
-                return Err(Error::new_spanned(
-                    attrs,
-                    "attributes must have the format `path = data`",
-                ));
-            }
+    let mut paths = vec![];
 
-            let path = attr[0];
+    for attr in parse_attributes(attrs)? {
+        let path = attr.path.to_token_stream().to_string();
 
-            if !VALIDATE_ATTRIBUTES.iter().any(|attr| *attr == path) {
-                return Err(Error::new_spanned(
-                    attrs,
-                    format!("attribute `{}` does not exist", path),
-                ));
-            }
+        if !VALIDATE_ATTRIBUTES.iter().any(|attr| *attr == path) {
+            return Err(Error::new_spanned(
+                &attr.path,
+                format!("attribute `{}` does not exist", path),
+            ));
+        }
 
-            if VALIDATE_STRING_ATTRIBUTES.iter().any(|attr| *attr == path) {
-                let data: Vec<char> = attr[1].chars().collect();
-                if data.first() != Some(&'"') || data.last() != Some(&'"') {
+        if VALIDATE_STRING_ATTRIBUTES.iter().any(|attr| *attr == path) {
+            let data = get_attribute_string(&attr)?;
+
+            if VALIDATE_IDENT_ATTRIBUTES.contains(&path.as_str()) {
+                let mut chars = data.chars();
+                let is_ident = chars
+                    .next()
+                    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !is_ident {
                     return Err(Error::new_spanned(
-                        attrs,
-                        format!("attribute `{}` must contain string", path),
+                        &attr.value,
+                        format!("attribute `{}` must contain an identifier", path),
                     ));
                 }
             }
         }
+
+        paths.push(path);
+    }
+
+    for (a, b) in VALIDATE_EXCLUSIVE_ATTRIBUTES {
+        if paths.iter().any(|path| path == a) && paths.iter().any(|path| path == b) {
+            return Err(Error::new_spanned(
+                attrs,
+                format!("attributes `{}` and `{}` cannot be used together", a, b),
+            ));
+        }
     }
 
     Ok(())
@@ -63,7 +74,11 @@ fn validate_function(sig: &Signature) -> Result<()> {
     validate_args(sig.inputs.iter().skip(1))?;
 
     if let syn::ReturnType::Type(_, ref ty) = sig.output {
-        validate_type(ty.as_ref(), false)?;
+        match get_result_types(ty.as_ref()) {
+            Some((ok, _)) if is_unit(ok) => (),
+            Some((ok, _)) => validate_type(ok, false)?,
+            None => validate_type(ty.as_ref(), false)?,
+        }
     }
 
     Ok(())
@@ -99,15 +114,52 @@ fn validate_args<'a, I>(mut args: I) -> Result<()>
 where
     I: Iterator<Item = &'a FnArg>,
 {
+    // Optional arguments can only be omitted at the end of a call
+    let mut optional = false;
+
     args.try_for_each(|arg| match arg {
         FnArg::Receiver(_) => Err(Error::new_spanned(arg, "Receiver is not supported")),
-        FnArg::Typed(pat) => validate_type(&*pat.ty, true),
+        FnArg::Typed(pat) => match get_option_type(&pat.ty) {
+            Some(ty) => {
+                optional = true;
+                validate_type(ty, true)
+            }
+            None if optional => Err(Error::new_spanned(
+                arg,
+                "required argument must not follow an optional argument (Option<&T>)",
+            )),
+            None if is_mut_ref(&pat.ty) => validate_mut_type(&pat.ty),
+            None => validate_type(&*pat.ty, true),
+        },
     })?;
 
     Ok(())
 }
 
+fn validate_mut_type(ty: &Type) -> Result<()> {
+    match ty {
+        Type::Reference(r) => match &*r.elem {
+            elem if is_bytes(elem) => Ok(()),
+            Type::Path(path) if path.path.segments.last().unwrap().ident == "Vec" => {
+                Err(Error::new_spanned(
+                    ty,
+                    "mutable reference must not contain Vec<T>, except Vec<u8>",
+                ))
+            }
+            elem => validate_type(elem, false),
+        },
+        ty => Err(Error::new_spanned(
+            ty,
+            "type must contain a mutable reference (&mut T)",
+        )),
+    }
+}
+
 fn validate_type(ty: &Type, is_ref: bool) -> Result<()> {
+    if is_bytes(ty) {
+        return Ok(());
+    }
+
     match is_ref {
         true => match ty {
             Type::Path(path) => validate_type_path(&path, is_ref),
@@ -133,9 +185,9 @@ fn validate_type(ty: &Type, is_ref: bool) -> Result<()> {
     }
 }
 
-const VALIDATE_TYPE: [&str; 15] = [
-    "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "f32", "f64", "bool", "char", "String",
-    "Vec", "Variable",
+const VALIDATE_TYPE: [&str; 17] = [
+    "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128", "f32", "f64", "bool",
+    "char", "String", "Vec", "Variable",
 ];
 
 fn validate_type_path(path: &TypePath, is_ref: bool) -> Result<()> {
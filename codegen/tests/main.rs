@@ -16,7 +16,7 @@ mod main {
             c.to_vec()
         }
 
-        #[function(name = "Sub function")]
+        #[function(name = "Sub function", description = "Subtracts two numbers")]
         fn sub(_: (), a: &i32, b: &i32) -> i32 {
             let c = a - b;
             println!("{} - {} = {}", a, b, c);
@@ -33,6 +33,46 @@ mod main {
             true.into()
         }
 
+        #[function]
+        fn xor(_: (), data: &[u8], key: &u8) -> Vec<u8> {
+            data.iter().map(|byte| byte ^ key).collect()
+        }
+
+        #[function]
+        fn next_id(_: (), id: &u128) -> u128 {
+            id + 1
+        }
+
+        #[function(description = "Divides a by b, fails if b = 0")]
+        fn divide(_: (), a: &i32, b: &i32) -> Result<i32, String> {
+            match b {
+                0 => Err("division by zero".to_string()),
+                b => Ok(a / b),
+            }
+        }
+
+        #[function]
+        fn check(_: (), value: &i32) -> Result<(), std::num::TryFromIntError> {
+            u8::try_from(*value).map(|_| ())
+        }
+
+        #[function]
+        fn greet(_: (), name: Option<&String>, times: Option<&i32>) -> String {
+            let name = name.map_or("guest", |name| name.as_str());
+            format!("Hello, {name}!").repeat(*times.unwrap_or(&1) as usize)
+        }
+
+        #[function(export = "mul_fn")]
+        fn mul(_: (), a: &i32, b: &i32) -> i32 {
+            a * b
+        }
+
+        #[function]
+        fn fill(_: (), buffer: &mut Vec<u8>, value: &u8, count: &mut i32) {
+            buffer.iter_mut().for_each(|byte| *byte = *value);
+            *count = buffer.len() as i32;
+        }
+
         #[function(name = "Logging")]
         fn log((title, code): (&Option<String>, &i32), message: &String) {
             let title = title.clone().unwrap_or("[INFO]".to_string());
@@ -55,6 +95,7 @@ mod main {
     fn serialize_sub() {
         let sub = functions::sub();
         println!("`sub` name: {}", sub.name(),);
+        assert_eq!(sub.description(), Some("Subtracts two numbers".to_string()));
 
         let result = function_call!(sub, 3, 2);
 
@@ -78,10 +119,124 @@ mod main {
         assert_eq!(result.unwrap(), Some(Variable::Null));
     }
 
+    #[test]
+    fn serialize_xor() {
+        let xor = functions::xor();
+        println!("`xor` name: {}", xor.name());
+
+        let result = function_call!(xor, Variable::from_bytes(vec![0x0F, 0xF0]), 0xFF_u8);
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(Variable::from_bytes(vec![0xF0, 0x0F])));
+    }
+
+    #[test]
+    fn serialize_next_id() {
+        let next_id = functions::next_id();
+        println!("`next_id` name: {}", next_id.name());
+
+        let result = function_call!(next_id, u64::MAX as u128);
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(Variable::U128(u64::MAX as u128 + 1)));
+    }
+
+    #[test]
+    fn serialize_divide() {
+        let divide = functions::divide();
+        println!("`divide` name: {}", divide.name());
+        assert_eq!(divide.name(), "divide");
+        assert_eq!(
+            divide.description(),
+            Some("Divides a by b, fails if b = 0".to_string())
+        );
+
+        let result = function_call!(divide, 6, 2);
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(3.into()));
+
+        let result = function_call!(divide, 6, 0);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "division by zero");
+    }
+
+    #[test]
+    fn serialize_check() {
+        let check = functions::check();
+        println!("`check` name: {}", check.name());
+        assert_eq!(check.output(), None);
+
+        let result = function_call!(check, 255);
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), None);
+
+        let result = function_call!(check, 256);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serialize_greet() {
+        let greet = functions::greet();
+        println!("`greet` name: {}", greet.name());
+        assert!(greet.inputs().iter().all(|arg| arg.optional));
+
+        let result = function_call!(greet, Variable::Null, Variable::Null);
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some("Hello, guest!".into()));
+
+        let result = function_call!(greet, "Alice", 2);
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some("Hello, Alice!Hello, Alice!".into()));
+
+        let result = function_call!(greet, "Bob");
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some("Hello, Bob!".into()));
+    }
+
+    #[test]
+    fn serialize_mul() {
+        let mul = functions::mul();
+        println!("`mul` name: {}", mul.name());
+        assert_eq!(mul.name(), "mul_fn");
+
+        let result = function_call!(mul, 3, 4);
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(12.into()));
+    }
+
+    #[test]
+    fn serialize_fill() {
+        let fill = functions::fill();
+        println!("`fill` name: {}", fill.name());
+
+        let mut args = [Variable::from_bytes(vec![0; 3]), 7_u8.into(), 0.into()];
+        let result = fill.call_mut(&mut args);
+
+        assert!(result.is_ok());
+        assert_eq!(args[0], Variable::from_bytes(vec![7; 3]));
+        assert_eq!(args[2], 3.into());
+
+        // Immutable call leaves the arguments unchanged
+        let args = [Variable::from_bytes(vec![0; 3]), 7_u8.into(), 0.into()];
+        let result = fill.call(&args);
+
+        assert!(result.is_ok());
+        assert_eq!(args[0], Variable::from_bytes(vec![0; 3]));
+    }
+
     #[test]
     fn serialize_log() {
         let log = functions::log(Some("[ERROR]".to_string()), 264);
         println!("`log` name: {}", log.name(),);
+        assert_eq!(log.description(), None);
 
         let mut result = function_call!(log, "It's error");
 
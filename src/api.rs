@@ -1,12 +1,14 @@
+use std::{path::Path, time::Duration};
+
 use rayon::prelude::IntoParallelIterator;
-use semver::Version;
+use semver::{Version, VersionReq};
 
 use crate::{
-    Bundle, Info, Loader, Manager, Plugin, Registry, Requests,
+    Bundle, Info, Loader, Manager, Plugin, PluginEvent, PluginInfo, Registry, Requests,
     utils::{
-        CallFunctionDependError, LoadPluginError, PluginCallFunctionError, PluginCallRequestError,
-        Ptr, RegisterManagerError, RegisterPluginError, UnloadPluginError, UnregisterManagerError,
-        UnregisterPluginError,
+        CallFunctionDependError, LoadPluginError, LoadPluginsError, PluginCallFunctionError,
+        PluginCallRequestError, Ptr, RegisterManagerError, RegisterPluginError, UnloadPluginError,
+        UnregisterManagerError, UnregisterPluginError,
     },
     variable::Variable,
 };
@@ -67,6 +69,7 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
     /// Gets access to the function registry.
     ///
     /// Returns a reference to the registry containing all functions available to plugins.
+    /// To call a host function by name without searching the registry, use `call_function`.
     ///
     /// # Returns
     ///
@@ -86,6 +89,22 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         &self.plugin
     }
 
+    /// Gets the full information about the current plugin.
+    ///
+    /// Unlike `plugin`, this looks the plugin up in the loader and gives access to its
+    /// path and the dependency information parsed by the manager.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&PluginInfo<I>>` containing the plugin information, or `None`
+    /// if the plugin has been unregistered.
+    pub fn plugin_info(&self) -> Option<&PluginInfo<I>> {
+        self.loader
+            .as_ref()
+            .get_plugin_by_bundle(&self.plugin)
+            .map(|plugin| plugin.info())
+    }
+
     /// Gets the list of required dependencies.
     ///
     /// Returns all dependencies that must be available for this plugin to function.
@@ -108,6 +127,25 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         &self.optional_depends
     }
 
+    /// Emits an event to the host.
+    ///
+    /// The event is sent to every channel subscribed with `Loader::events`, with the
+    /// current plugin as its source. Emitting never blocks, and events emitted from
+    /// the same thread are received in order.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the event
+    /// * `payload` - Data attached to the event
+    pub fn emit(&self, name: &str, payload: Variable) {
+        let event = PluginEvent {
+            source: self.plugin.clone(),
+            name: name.to_string(),
+            payload,
+        };
+        self.loader.as_ref().emit_event(event);
+    }
+
     // Loader functions
 
     /// Registers a plugin manager with the loader.
@@ -134,7 +172,8 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
 
     /// Registers multiple plugin managers with the loader.
     ///
-    /// This method allows plugins to register multiple managers in sequence.
+    /// This method allows plugins to register multiple managers in sequence. If a manager
+    /// fails to register, the managers already registered by this call are unregistered again.
     ///
     /// # Parameters
     ///
@@ -183,6 +222,60 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_mut().unregister_manager(format)
     }
 
+    /// Unregisters a plugin manager by its concrete type.
+    ///
+    /// This method allows plugins to unregister managers without knowing their format.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), UnregisterManagerError>` indicating success or failure.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - Type of the manager to unregister
+    pub fn unregister_manager_by_type<M>(&self) -> Result<(), UnregisterManagerError>
+    where
+        M: Manager<'static, O, I> + 'static,
+    {
+        self.loader.as_mut().unregister_manager_by_type::<M>()
+    }
+
+    /// Gets an immutable reference to a manager by its concrete type.
+    ///
+    /// This method allows plugins to access the concrete state of registered managers.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&M>` containing the manager if found.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - Type of the manager to retrieve
+    pub fn get_manager_by_type<M>(&self) -> Option<&M>
+    where
+        M: Manager<'static, O, I> + 'static,
+    {
+        self.loader.as_ref().get_manager_by_type::<M>()
+    }
+
+    /// Gets a mutable reference to a manager by its concrete type.
+    ///
+    /// This method allows plugins to modify the concrete state of registered managers.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&mut M>` containing the manager if found.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - Type of the manager to retrieve
+    pub fn get_manager_mut_by_type<M>(&self) -> Option<&mut M>
+    where
+        M: Manager<'static, O, I> + 'static,
+    {
+        self.loader.as_mut().get_manager_mut_by_type::<M>()
+    }
+
     /// Gets an immutable reference to a manager by format.
     ///
     /// This method allows plugins to access registered managers.
@@ -246,6 +339,15 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_mut().par_get_manager_mut(format)
     }
 
+    /// Gets the plugin formats handled by the registered managers.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&'static str>` containing every format once, in manager registration order.
+    pub fn registered_formats(&self) -> Vec<&'static str> {
+        self.loader.as_ref().registered_formats()
+    }
+
     /// Registers a plugin with the loader.
     ///
     /// This method allows plugins to register new plugins during execution.
@@ -261,6 +363,27 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_mut().register_plugin(path)
     }
 
+    /// Registers a virtual plugin with the loader.
+    ///
+    /// This method allows plugins to register plugins whose information is constructed
+    /// in memory, without reading the filesystem.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - Format of the manager that will handle this plugin
+    /// * `info` - Plugin information to register
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Bundle, RegisterPluginError>` containing the plugin bundle on success.
+    pub fn register_virtual_plugin(
+        &self,
+        format: &str,
+        info: PluginInfo<I>,
+    ) -> Result<Bundle, RegisterPluginError> {
+        self.loader.as_mut().register_virtual_plugin(format, info)
+    }
+
     /// Registers multiple plugins with the loader.
     ///
     /// This method allows plugins to register multiple plugins in sequence.
@@ -286,7 +409,7 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
 
     /// Registers multiple plugins with the loader in parallel.
     ///
-    /// This method allows plugins to register multiple plugins concurrently.
+    /// This method allows plugins to register multiple plugins, checking their paths concurrently.
     ///
     /// # Parameters
     ///
@@ -363,6 +486,42 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_mut().par_unregister_plugin_by_bundle(bundle)
     }
 
+    /// Unregisters several plugins from the loader by bundle.
+    ///
+    /// This method allows plugins to unregister plugins in dependency order, dependents first.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundles` - Bundles of the plugins to unregister
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), UnregisterPluginError>` indicating success or failure.
+    pub fn unregister_plugins(&self, bundles: &[Bundle]) -> Result<(), UnregisterPluginError> {
+        self.loader.as_mut().unregister_plugins(bundles)
+    }
+
+    /// Unregisters all versions of a plugin matching a version requirement.
+    ///
+    /// This method allows plugins to unregister several versions of a plugin at once.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    /// * `req` - Version requirement the plugin versions must satisfy
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Bundle>, UnregisterPluginError>` containing the bundles of the
+    /// unregistered plugins.
+    pub fn unregister_plugins_matching(
+        &self,
+        id: &str,
+        req: &VersionReq,
+    ) -> Result<Vec<Bundle>, UnregisterPluginError> {
+        self.loader.as_mut().unregister_plugins_matching(id, req)
+    }
+
     /// Loads a plugin into the execution environment.
     ///
     /// This method allows plugins to load other plugins by ID and version.
@@ -435,12 +594,9 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
     ///
     /// # Returns
     ///
-    /// Returns `Result<Bundle, (Option<RegisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundle on success, or errors from registration or loading.
-    pub fn load_plugin_now(
-        &self,
-        path: &str,
-    ) -> Result<Bundle, (Option<RegisterPluginError>, Option<LoadPluginError>)> {
+    /// Returns `Result<Bundle, LoadPluginsError>`
+    /// containing the plugin bundle on success, or an error from registration or loading.
+    pub fn load_plugin_now(&self, path: &str) -> Result<Bundle, LoadPluginsError> {
         self.loader.as_mut().load_plugin_now(path)
     }
 
@@ -454,17 +610,14 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
     ///
     /// # Returns
     ///
-    /// Returns `Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundles on success, or errors from registration or loading.
+    /// Returns `Result<Vec<Bundle>, LoadPluginsError>`
+    /// containing the plugin bundles on success, or an error from registration or loading.
     ///
     /// # Type Parameters
     ///
     /// * `'b` - Lifetime of the path references
     /// * `P` - Type of the iterator containing path references
-    pub fn load_plugins<'b, P>(
-        &self,
-        paths: P,
-    ) -> Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<LoadPluginError>)>
+    pub fn load_plugins<'b, P>(&self, paths: P) -> Result<Vec<Bundle>, LoadPluginsError>
     where
         P: IntoIterator<Item = &'b str>,
     {
@@ -473,7 +626,8 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
 
     /// Loads multiple plugins from the specified paths (parallel version).
     ///
-    /// This method allows plugins to register and load multiple plugins concurrently.
+    /// This method allows plugins to register and load multiple plugins, using parallel
+    /// processing for the checks that do not mutate the loader.
     ///
     /// # Parameters
     ///
@@ -481,17 +635,14 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
     ///
     /// # Returns
     ///
-    /// Returns `Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundles on success, or errors from registration or loading.
+    /// Returns `Result<Vec<Bundle>, LoadPluginsError>`
+    /// containing the plugin bundles on success, or an error from registration or loading.
     ///
     /// # Type Parameters
     ///
     /// * `'b` - Lifetime of the path references
     /// * `P` - Type of the parallel iterator containing path references
-    pub fn par_load_plugins<'b, P>(
-        &self,
-        paths: P,
-    ) -> Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<LoadPluginError>)>
+    pub fn par_load_plugins<'b, P>(&self, paths: P) -> Result<Vec<Bundle>, LoadPluginsError>
     where
         P: IntoParallelIterator<Item = &'b str>,
     {
@@ -509,24 +660,14 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
     ///
     /// # Returns
     ///
-    /// Returns `Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<UnregisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundles on success, or errors from registration, unregistration, or loading.
+    /// Returns `Result<Vec<Bundle>, LoadPluginsError>`
+    /// containing the plugin bundles on success, or an error from registration, unregistration, or loading.
     ///
     /// # Type Parameters
     ///
     /// * `'b` - Lifetime of the path references
     /// * `P` - Type of the iterator containing path references
-    pub fn load_only_used_plugins<'b, P>(
-        &self,
-        paths: P,
-    ) -> Result<
-        Vec<Bundle>,
-        (
-            Option<RegisterPluginError>,
-            Option<UnregisterPluginError>,
-            Option<LoadPluginError>,
-        ),
-    >
+    pub fn load_only_used_plugins<'b, P>(&self, paths: P) -> Result<Vec<Bundle>, LoadPluginsError>
     where
         P: IntoIterator<Item = &'b str>,
     {
@@ -544,8 +685,8 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
     ///
     /// # Returns
     ///
-    /// Returns `Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<UnregisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundles on success, or errors from registration, unregistration, or loading.
+    /// Returns `Result<Vec<Bundle>, LoadPluginsError>`
+    /// containing the plugin bundles on success, or an error from registration, unregistration, or loading.
     ///
     /// # Type Parameters
     ///
@@ -554,14 +695,7 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
     pub fn par_load_only_used_plugins<'b, P>(
         &self,
         paths: P,
-    ) -> Result<
-        Vec<Bundle>,
-        (
-            Option<RegisterPluginError>,
-            Option<UnregisterPluginError>,
-            Option<LoadPluginError>,
-        ),
-    >
+    ) -> Result<Vec<Bundle>, LoadPluginsError>
     where
         P: IntoParallelIterator<Item = &'b str>,
     {
@@ -615,6 +749,42 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_mut().unload_plugin_by_bundle(bundle)
     }
 
+    /// Unloads several plugins from the execution environment by bundle.
+    ///
+    /// This method allows plugins to unload plugins in dependency order, dependents first.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundles` - Bundles of the plugins to unload
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), UnloadPluginError>` indicating success or failure.
+    pub fn unload_plugins(&self, bundles: &[Bundle]) -> Result<(), UnloadPluginError> {
+        self.loader.as_mut().unload_plugins(bundles)
+    }
+
+    /// Unloads all versions of a plugin matching a version requirement.
+    ///
+    /// This method allows plugins to unload several versions of a plugin at once.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    /// * `req` - Version requirement the plugin versions must satisfy
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Bundle>, UnloadPluginError>` containing the bundles of the
+    /// matching plugins.
+    pub fn unload_plugins_matching(
+        &self,
+        id: &str,
+        req: &VersionReq,
+    ) -> Result<Vec<Bundle>, UnloadPluginError> {
+        self.loader.as_mut().unload_plugins_matching(id, req)
+    }
+
     /// Unloads a plugin from the execution environment by bundle (parallel version).
     ///
     /// This method allows plugins to unload other plugins by bundle information using parallel processing.
@@ -646,6 +816,50 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_ref().get_plugin(id, version)
     }
 
+    /// Gets the highest loaded version of a plugin matching a version requirement.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    /// * `req` - Version requirement the plugin must match
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Plugin<'static, O, I>>` containing the highest matching loaded plugin if found.
+    pub fn get_plugin_matching(
+        &self,
+        id: &str,
+        req: &VersionReq,
+    ) -> Option<&Plugin<'static, O, I>> {
+        self.loader.as_ref().get_plugin_matching(id, req)
+    }
+
+    /// Gets the highest registered version of a plugin.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Plugin<'static, O, I>>` containing the highest version of the plugin if found.
+    pub fn get_latest_plugin(&self, id: &str) -> Option<&Plugin<'static, O, I>> {
+        self.loader.as_ref().get_latest_plugin(id)
+    }
+
+    /// Gets the highest registered version of a plugin (parallel version).
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Plugin<'static, O, I>>` containing the highest version of the plugin if found.
+    pub fn par_get_latest_plugin(&self, id: &str) -> Option<&Plugin<'static, O, I>> {
+        self.loader.as_ref().par_get_latest_plugin(id)
+    }
+
     /// Gets an immutable reference to a plugin by ID and version (parallel version).
     ///
     /// This method allows plugins to access other registered plugins using parallel processing.
@@ -692,6 +906,68 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_ref().par_get_plugin_by_bundle(bundle)
     }
 
+    /// Gets an immutable reference to a plugin by its path.
+    ///
+    /// This method allows plugins to access other registered plugins by the path they
+    /// were registered from.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - Path of the plugin directory
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Plugin<'static, O, I>>` containing the plugin if found.
+    pub fn get_plugin_by_path(&self, path: &Path) -> Option<&Plugin<'static, O, I>> {
+        self.loader.as_ref().get_plugin_by_path(path)
+    }
+
+    /// Checks if a plugin is loaded by bundle.
+    ///
+    /// This method allows plugins to check the state of other registered plugins.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Plugin bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the plugin is registered and loaded, `false` otherwise.
+    pub fn is_loaded(&self, bundle: &Bundle) -> bool {
+        self.loader.as_ref().is_loaded(bundle)
+    }
+
+    /// Returns the memory used by a plugin.
+    ///
+    /// This method allows plugins to inspect the resource usage of other registered plugins.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Plugin bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<usize>` containing the memory usage in bytes, or `None` if it is unknown.
+    pub fn plugin_memory(&self, bundle: &Bundle) -> Option<usize> {
+        self.loader.as_ref().plugin_memory(bundle)
+    }
+
+    /// Checks if a plugin is loaded by ID and version.
+    ///
+    /// This method allows plugins to check the state of other registered plugins.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    /// * `version` - Plugin version
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the plugin is registered and loaded, `false` otherwise.
+    pub fn is_loaded_by_id(&self, id: &str, version: &Version) -> bool {
+        self.loader.as_ref().is_loaded_by_id(id, version)
+    }
+
     /// Gets a mutable reference to a plugin by ID and version.
     ///
     /// This method allows plugins to access other registered plugins for modification.
@@ -712,6 +988,32 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_mut().get_plugin_mut(id, version)
     }
 
+    /// Gets a mutable reference to the highest registered version of a plugin.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&mut Plugin<'static, O, I>>` containing the highest version of the plugin if found.
+    pub fn get_latest_plugin_mut(&self, id: &str) -> Option<&mut Plugin<'static, O, I>> {
+        self.loader.as_mut().get_latest_plugin_mut(id)
+    }
+
+    /// Gets a mutable reference to the highest registered version of a plugin (parallel version).
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&mut Plugin<'static, O, I>>` containing the highest version of the plugin if found.
+    pub fn par_get_latest_plugin_mut(&self, id: &str) -> Option<&mut Plugin<'static, O, I>> {
+        self.loader.as_mut().par_get_latest_plugin_mut(id)
+    }
+
     /// Gets a mutable reference to a plugin by ID and version (parallel version).
     ///
     /// This method allows plugins to access other registered plugins for modification using parallel processing.
@@ -795,6 +1097,89 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_ref().par_get_plugins_by_id(id)
     }
 
+    /// Gets all plugins matching a predicate.
+    ///
+    /// This method allows plugins to query other plugins by any of their properties.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - Function returning `true` for the plugins to include
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'static, O, I>>` containing all matching plugins.
+    pub fn find_plugins<F>(&self, predicate: F) -> Vec<&Plugin<'static, O, I>>
+    where
+        F: Fn(&Plugin<'static, O, I>) -> bool,
+    {
+        self.loader.as_ref().find_plugins(predicate)
+    }
+
+    /// Gets all plugins matching a predicate (parallel version).
+    ///
+    /// This method allows plugins to query other plugins by any of their properties
+    /// using parallel processing.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - Function returning `true` for the plugins to include
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'static, O, I>>` containing all matching plugins.
+    pub fn par_find_plugins<F>(&self, predicate: F) -> Vec<&Plugin<'static, O, I>>
+    where
+        F: Fn(&Plugin<'static, O, I>) -> bool + Send + Sync,
+    {
+        self.loader.as_ref().par_find_plugins(predicate)
+    }
+
+    /// Gets all loaded plugins that use a plugin as a dependency.
+    ///
+    /// This method allows plugins to check who depends on a plugin before unloading it.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Bundle of the plugin the dependents of which are requested
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'static, O, I>>` containing the direct dependents of the plugin.
+    pub fn dependents_of(&self, bundle: &Bundle) -> Vec<&Plugin<'static, O, I>> {
+        self.loader.as_ref().dependents_of(bundle)
+    }
+
+    /// Gets all loaded plugins that use a plugin as a dependency (parallel version).
+    ///
+    /// This method allows plugins to check who depends on a plugin using parallel processing.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Bundle of the plugin the dependents of which are requested
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'static, O, I>>` containing the direct dependents of the plugin.
+    pub fn par_dependents_of(&self, bundle: &Bundle) -> Vec<&Plugin<'static, O, I>> {
+        self.loader.as_ref().par_dependents_of(bundle)
+    }
+
+    /// Checks that plugins can be loaded, without loading them.
+    ///
+    /// This method allows plugins to validate the dependencies of plugins before loading them,
+    /// see `Loader::validate_plugins`.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundles` - Bundles of the plugins to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), Vec<LoadPluginError>>` containing all problems found.
+    pub fn validate_plugins(&self, bundles: &[Bundle]) -> Result<(), Vec<LoadPluginError>> {
+        self.loader.as_ref().validate_plugins(bundles)
+    }
+
     /// Gets mutable references to all plugins with the specified ID.
     ///
     /// This method allows plugins to access all versions of plugins with a specific ID for modification.
@@ -847,6 +1232,37 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_ref().get_registry()
     }
 
+    /// Returns the signatures of all functions in the registry.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of formatted function signatures in registration order.
+    pub fn registry_signatures(&self) -> Vec<String> {
+        self.loader.as_ref().registry_signatures()
+    }
+
+    /// Calls a function from the registry by name.
+    ///
+    /// This method allows plugins to call a host function by name instead of by its
+    /// index in the registry.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<O, PluginCallFunctionError>` containing the function result on success,
+    /// or an error if the function is not found.
+    pub fn call_function(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<O, PluginCallFunctionError> {
+        self.loader.as_ref().call_function(name, args)
+    }
+
     /// Gets a reference to the function requests.
     ///
     /// This method allows plugins to access the collection of function requests.
@@ -858,6 +1274,15 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_ref().get_requests()
     }
 
+    /// Returns the signatures of all function requests.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of formatted request signatures in registration order.
+    pub fn request_signatures(&self) -> Vec<String> {
+        self.loader.as_ref().request_signatures()
+    }
+
     /// Calls a function request across all eligible plugins.
     ///
     /// This method allows plugins to call a function request on all plugins that have the highest
@@ -880,6 +1305,77 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         self.loader.as_ref().call_request(name, args)
     }
 
+    /// Calls a function request on the first eligible plugin that implements it.
+    ///
+    /// This method allows plugins to dispatch a request to a single plugin,
+    /// see `Loader::call_request_first`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function request to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Option<O>, PluginCallRequestError>` containing the result of the
+    /// first plugin that has the requested function, or `None` if no plugin has it.
+    pub fn call_request_first(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<Option<O>, PluginCallRequestError> {
+        self.loader.as_ref().call_request_first(name, args)
+    }
+
+    /// Calls a function request across all eligible plugins without blocking the executor.
+    ///
+    /// This method runs the request on tokio's blocking thread pool. The returned future
+    /// must be awaited within a tokio runtime. See `Loader::call_request_async` for details.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function request to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns a future resolving to `Result<Vec<O>, PluginCallRequestError>` containing
+    /// results from all eligible plugins that have the requested function.
+    #[cfg(feature = "async")]
+    pub fn call_request_async(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> impl Future<Output = Result<Vec<O>, PluginCallRequestError>> + use<O, I> {
+        self.loader.as_ref().call_request_async(name, args)
+    }
+
+    /// Calls a function request across all eligible plugins with a time limit.
+    ///
+    /// Requests that do not respond in time are abandoned and keep running in the
+    /// background. See `Loader::call_request_timeout` for details.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function request to call
+    /// * `args` - Arguments to pass to the function
+    /// * `timeout` - Maximum time to wait for the plugins to respond
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<O>, PluginCallRequestError>` containing results from all
+    /// eligible plugins that have the requested function.
+    pub fn call_request_timeout(
+        &self,
+        name: &str,
+        args: &[Variable],
+        timeout: Duration,
+    ) -> Result<Vec<O>, PluginCallRequestError> {
+        self.loader
+            .as_ref()
+            .call_request_timeout(name, args, timeout)
+    }
+
     /// Calls a function request across all eligible plugins (parallel version).
     ///
     /// This method allows plugins to call a function request on all plugins that have the highest
@@ -945,6 +1441,86 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Api<O, I> {
         Ok(plugin.call_function(name, args)?)
     }
 
+    /// Calls a function on a required dependency matching a version requirement.
+    ///
+    /// Like `call_function_depend`, but the dependency is selected by a version requirement,
+    /// such as the one declared in the plugin's manifest, instead of an exact version.
+    /// If several resolved dependencies match, the highest version is called.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Dependency plugin ID
+    /// * `req` - Version requirement of the dependency
+    /// * `name` - Function name to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<O, CallFunctionDependError>` containing the function result on success,
+    /// or an error if no dependency matches or the function call fails.
+    pub fn call_function_depend_req(
+        &self,
+        id: &str,
+        req: &VersionReq,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<O, CallFunctionDependError> {
+        let depend = self
+            .depends
+            .iter()
+            .filter(|depend| depend.id == id && req.matches(&depend.version))
+            .max_by(|a, b| a.version.cmp(&b.version))
+            .ok_or(CallFunctionDependError::DependNotFound)?;
+
+        let plugin = self
+            .loader
+            .as_ref()
+            .get_plugin_by_bundle(depend)
+            .ok_or(CallFunctionDependError::DependNotFound)?;
+
+        Ok(plugin.call_function(name, args)?)
+    }
+
+    /// Calls a request on a required dependency.
+    ///
+    /// Unlike `call_request`, which calls the request on every plugin, this method targets
+    /// the request implemented by a single dependency of the current plugin.
+    /// It ensures that the dependency exists before attempting the call.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Dependency plugin ID
+    /// * `version` - Dependency plugin version
+    /// * `name` - Request name to call
+    /// * `args` - Arguments to pass to the request
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<O, CallFunctionDependError>` containing the request result on success,
+    /// or an error if the dependency is not found or does not implement the request.
+    /// Missing trailing optional arguments are filled with `Variable::Null`.
+    pub fn call_request_depend(
+        &self,
+        id: &str,
+        version: &Version,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<O, CallFunctionDependError> {
+        let depend = self
+            .depends
+            .iter()
+            .find(|&depend| *depend == (id, version))
+            .ok_or(CallFunctionDependError::DependNotFound)?;
+
+        let plugin = self
+            .loader
+            .as_ref()
+            .get_plugin_by_bundle(depend)
+            .ok_or(CallFunctionDependError::DependNotFound)?;
+
+        Ok(plugin.call_request(name, args)?)
+    }
+
     /// Calls a function on an optional dependency.
     ///
     /// This method allows the current plugin to call functions exposed by its optional dependencies.
@@ -0,0 +1,308 @@
+use std::{future::Future, pin::Pin};
+
+use crate::{
+    Api, Info, LoadPluginContext, Manager, Plugin, RegisterPluginContext,
+    utils::{ManagerResult, block_on},
+};
+
+/// Boxed future returned by the asynchronous manager methods.
+///
+/// # Type Parameters
+///
+/// * `'f` - Lifetime of the data borrowed by the future
+/// * `T` - Value produced by the future on success
+pub type ManagerFuture<'f, T> = Pin<Box<dyn Future<Output = ManagerResult<T>> + Send + 'f>>;
+
+/// Trait for implementing plugin managers with asynchronous lifecycle methods.
+///
+/// This is the asynchronous counterpart of [`Manager`]. It is useful for managers whose
+/// plugins need to download resources, compile code or perform other I/O during registration
+/// and loading. An asynchronous manager is registered in the loader through
+/// [`AsyncManagerAdapter`], and its futures are awaited by the asynchronous loader methods
+/// such as `Loader::register_plugin_async` and `Loader::load_plugin_async`.
+///
+/// # Type Parameters
+///
+/// * `'a` - Lifetime parameter for references within the manager
+/// * `O` - Output type for plugin functions (must implement Send + Sync)
+/// * `I` - Plugin information type (must implement Info trait)
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use plux_rs::{AsyncManager, ManagerFuture, RegisterPluginContext, StdInfo, function::FunctionOutput};
+///
+/// struct MyAsyncManager;
+///
+/// impl<'a> AsyncManager<'a, FunctionOutput, StdInfo> for MyAsyncManager {
+///     fn format(&self) -> &'static str {
+///         "my_format"
+///     }
+///
+///     fn register_plugin<'f>(
+///         &'f mut self,
+///         _context: RegisterPluginContext<'f>,
+///     ) -> ManagerFuture<'f, StdInfo> {
+///         Box::pin(async move {
+///             // Asynchronous implementation for registering a plugin
+///             Ok(StdInfo {
+///                 depends: vec![],
+///                 optional_depends: vec![],
+///             })
+///         })
+///     }
+/// }
+/// ```
+pub trait AsyncManager<'a, O: Send + Sync, I: Info>: Send + Sync {
+    /// Returns the file format/extension this manager handles (e.g., "lua", "rs", "wasm").
+    fn format(&self) -> &'static str;
+
+    /// Returns all file formats/extensions this manager handles.
+    ///
+    /// Default implementation returns only `format()`.
+    fn formats(&self) -> Vec<&'static str> {
+        vec![self.format()]
+    }
+
+    /// Returns the priority of the manager when resolving a plugin format.
+    ///
+    /// Default implementation returns `0`. See [`Manager::priority`].
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Called when the manager is registered with the loader.
+    ///
+    /// Default implementation does nothing and returns Ok(()).
+    fn register_manager(&mut self) -> ManagerFuture<'_, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called when the manager is unregistered from the loader.
+    ///
+    /// Default implementation does nothing and returns Ok(()).
+    fn unregister_manager(&mut self) -> ManagerFuture<'_, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Verifies the integrity of a plugin before it is registered.
+    ///
+    /// Default implementation does nothing and returns Ok(()).
+    ///
+    /// # Parameters
+    ///
+    /// * `context` - Context containing plugin path and bundle information
+    fn verify_plugin<'f>(
+        &'f self,
+        _context: &'f RegisterPluginContext<'f>,
+    ) -> ManagerFuture<'f, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Registers a plugin with this manager.
+    ///
+    /// # Parameters
+    ///
+    /// * `context` - Context containing plugin path and bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns a future resolving to the plugin information.
+    fn register_plugin<'f>(
+        &'f mut self,
+        context: RegisterPluginContext<'f>,
+    ) -> ManagerFuture<'f, I>;
+
+    /// Unregisters a plugin from this manager.
+    ///
+    /// Default implementation does nothing and returns Ok(()).
+    ///
+    /// # Parameters
+    ///
+    /// * `plugin` - Reference to the plugin being unregistered
+    fn unregister_plugin<'f>(&'f mut self, _plugin: &'f Plugin<'a, O, I>) -> ManagerFuture<'f, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Loads a plugin into the execution environment.
+    ///
+    /// Default implementation does nothing and returns Ok(()).
+    ///
+    /// # Parameters
+    ///
+    /// * `context` - Context containing plugin and loader information
+    /// * `api` - API interface for interacting with the host application
+    fn load_plugin<'f>(
+        &'f mut self,
+        _context: LoadPluginContext<'a, 'f, O, I>,
+        _api: Api<O, I>,
+    ) -> ManagerFuture<'f, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Unloads a plugin from the execution environment.
+    ///
+    /// Default implementation does nothing and returns Ok(()).
+    ///
+    /// # Parameters
+    ///
+    /// * `plugin` - Reference to the plugin being unloaded
+    fn unload_plugin<'f>(&'f mut self, _plugin: &'f Plugin<'a, O, I>) -> ManagerFuture<'f, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called before the loader loads a batch of plugins.
+    ///
+    /// Default implementation does nothing. See [`Manager::begin_batch`].
+    fn begin_batch(&mut self) {}
+
+    /// Called after the loader has loaded a batch of plugins.
+    ///
+    /// Default implementation does nothing. See [`Manager::end_batch`].
+    fn end_batch(&mut self) {}
+
+    /// Reports the memory used by a plugin.
+    ///
+    /// Default implementation returns `None`. See [`Manager::plugin_memory`].
+    fn plugin_memory(&self, _plugin: &Plugin<'a, O, I>) -> Option<usize> {
+        None
+    }
+}
+
+/// Adapter that registers an [`AsyncManager`] in the loader as a regular [`Manager`].
+///
+/// The asynchronous loader methods await the manager's futures directly, while the
+/// synchronous loader methods run them to completion with [`block_on`].
+///
+/// # Type Parameters
+///
+/// * `M` - The wrapped asynchronous manager
+///
+/// # Example
+///
+/// ```rust,ignore
+/// loader.context(|mut ctx| {
+///     ctx.register_manager(AsyncManagerAdapter::new(MyAsyncManager))
+/// })?;
+/// ```
+pub struct AsyncManagerAdapter<M> {
+    manager: M,
+}
+
+impl<M> AsyncManagerAdapter<M> {
+    /// Wraps an asynchronous manager.
+    ///
+    /// # Parameters
+    ///
+    /// * `manager` - The asynchronous manager to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new AsyncManagerAdapter instance.
+    pub const fn new(manager: M) -> Self {
+        Self { manager }
+    }
+
+    /// Gets a reference to the wrapped manager.
+    pub const fn inner(&self) -> &M {
+        &self.manager
+    }
+
+    /// Gets a mutable reference to the wrapped manager.
+    pub const fn inner_mut(&mut self) -> &mut M {
+        &mut self.manager
+    }
+
+    /// Consumes the adapter and returns the wrapped manager.
+    pub fn into_inner(self) -> M {
+        self.manager
+    }
+}
+
+impl<'a, O, I, M> Manager<'a, O, I> for AsyncManagerAdapter<M>
+where
+    O: Send + Sync,
+    I: Info,
+    M: AsyncManager<'a, O, I> + 'static,
+{
+    fn format(&self) -> &'static str {
+        self.manager.format()
+    }
+
+    fn formats(&self) -> Vec<&'static str> {
+        self.manager.formats()
+    }
+
+    fn priority(&self) -> i32 {
+        self.manager.priority()
+    }
+
+    fn register_manager(&mut self) -> ManagerResult<()> {
+        block_on(self.manager.register_manager())
+    }
+
+    fn unregister_manager(&mut self) -> ManagerResult<()> {
+        block_on(self.manager.unregister_manager())
+    }
+
+    fn verify_plugin(&self, context: &RegisterPluginContext) -> ManagerResult<()> {
+        block_on(self.manager.verify_plugin(context))
+    }
+
+    fn register_plugin(&mut self, context: RegisterPluginContext) -> ManagerResult<I> {
+        block_on(self.manager.register_plugin(context))
+    }
+
+    fn unregister_plugin(&mut self, plugin: &Plugin<'a, O, I>) -> ManagerResult<()> {
+        block_on(self.manager.unregister_plugin(plugin))
+    }
+
+    fn load_plugin(
+        &mut self,
+        context: LoadPluginContext<'a, '_, O, I>,
+        api: Api<O, I>,
+    ) -> ManagerResult<()> {
+        block_on(self.manager.load_plugin(context, api))
+    }
+
+    fn unload_plugin(&mut self, plugin: &Plugin<'a, O, I>) -> ManagerResult<()> {
+        block_on(self.manager.unload_plugin(plugin))
+    }
+
+    fn begin_batch(&mut self) {
+        self.manager.begin_batch()
+    }
+
+    fn end_batch(&mut self) {
+        self.manager.end_batch()
+    }
+
+    fn plugin_memory(&self, plugin: &Plugin<'a, O, I>) -> Option<usize> {
+        self.manager.plugin_memory(plugin)
+    }
+
+    fn verify_plugin_async<'f>(
+        &'f self,
+        context: &'f RegisterPluginContext<'f>,
+    ) -> ManagerFuture<'f, ()> {
+        self.manager.verify_plugin(context)
+    }
+
+    fn register_plugin_async<'f>(
+        &'f mut self,
+        context: RegisterPluginContext<'f>,
+    ) -> ManagerFuture<'f, I>
+    where
+        I: 'f,
+    {
+        self.manager.register_plugin(context)
+    }
+
+    fn load_plugin_async<'f>(
+        &'f mut self,
+        context: LoadPluginContext<'a, 'f, O, I>,
+        api: Api<O, I>,
+    ) -> ManagerFuture<'f, ()> {
+        self.manager.load_plugin(context, api)
+    }
+}
@@ -32,11 +32,92 @@ pub struct Bundle {
 }
 
 impl Bundle {
+    /// Creates a new Bundle from its parts.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Unique identifier for the plugin
+    /// * `version` - Semantic version of the plugin
+    /// * `format` - File format/extension of the plugin, without the leading dot
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Self, BundleFromError>` containing the new Bundle on success,
+    /// or `IDFailed` if the ID is empty and `FormatFailed` if the format is empty
+    /// or contains a dot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::Bundle;
+    /// use semver::Version;
+    ///
+    /// let bundle = Bundle::new("calculator", Version::new(1, 0, 0), "lua")?;
+    /// assert_eq!(bundle, Bundle::from_filename("calculator-v1.0.0.lua")?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        id: impl Into<String>,
+        version: Version,
+        format: impl Into<String>,
+    ) -> Result<Self, BundleFromError> {
+        let id = id.into();
+        if id.is_empty() {
+            return Err(BundleFromError::IDFailed);
+        }
+
+        Self {
+            id,
+            version,
+            format: String::new(),
+        }
+        .with_format(format)
+    }
+
+    /// Creates a copy of the Bundle with another format.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - File format/extension of the plugin, without the leading dot
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Self, BundleFromError>` containing the new Bundle on success,
+    /// or `FormatFailed` if the format is empty or contains a dot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::Bundle;
+    ///
+    /// let bundle = Bundle::from_filename("calculator-v1.0.0.lua")?.with_format("wasm")?;
+    /// assert_eq!(bundle.to_string(), "calculator-v1.0.0.wasm");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_format(&self, format: impl Into<String>) -> Result<Self, BundleFromError> {
+        // A dot would make the format ambiguous in the filename
+        let format = format.into();
+        if format.is_empty() || format.contains('.') {
+            return Err(BundleFromError::FormatFailed);
+        }
+
+        Ok(Self {
+            id: self.id.clone(),
+            version: self.version.clone(),
+            format,
+        })
+    }
+
     /// Creates a Bundle from a filename string.
     ///
     /// Parses a plugin filename following the standard Plux naming convention
     /// `{id}-v{version}.{format}` and extracts the bundle information.
     ///
+    /// The `-v` markers are tried from the right and the first one followed by a valid
+    /// semantic version is used, so IDs containing `-v` (e.g. `my-video-v1.0.0.lua`) as well
+    /// as pre-release and build metadata versions (e.g. `my_plugin-v1.0.0-rc.1.lua`)
+    /// are supported.
+    ///
     /// # Parameters
     ///
     /// * `filename` - The filename to parse (e.g., "calculator-v1.0.0.lua")
@@ -70,37 +151,44 @@ impl Bundle {
     where
         S: AsRef<OsStr> + ?Sized,
     {
-        let mut path = filename
+        let filename = filename
             .as_ref()
             .to_str()
-            .ok_or(BundleFromError::OsStrToStrFailed)?
-            .to_string();
-
-        let format = path
-            .drain(path.rfind('.').ok_or(BundleFromError::FormatFailed)? + 1..)
-            .collect::<String>();
-        let version = path
-            .drain(path.rfind("-v").ok_or(BundleFromError::VersionFailed)? + 2..path.len() - 1)
-            .collect::<String>();
-        let id = path
-            .drain(..path.rfind("-v").ok_or(BundleFromError::IDFailed)?)
-            .collect::<String>();
+            .ok_or(BundleFromError::OsStrToStrFailed)?;
 
+        let (name, format) = filename
+            .rsplit_once('.')
+            .ok_or(BundleFromError::FormatFailed)?;
         if format.is_empty() {
             return Err(BundleFromError::FormatFailed);
         }
-        if version.is_empty() {
-            return Err(BundleFromError::VersionFailed);
-        }
-        if id.is_empty() {
-            return Err(BundleFromError::IDFailed);
+
+        // Find the last version marker followed by a valid version
+        let mut error = BundleFromError::VersionFailed;
+        for (index, _) in name.rmatch_indices("-v") {
+            let (id, version) = (&name[..index], &name[index + 2..]);
+            if version.is_empty() {
+                continue;
+            }
+
+            match Version::parse(version) {
+                Ok(_) if id.is_empty() => return Err(BundleFromError::IDFailed),
+                Ok(version) => {
+                    return Ok(Self {
+                        id: id.to_string(),
+                        version,
+                        format: format.to_string(),
+                    });
+                }
+                Err(e) => {
+                    if let BundleFromError::VersionFailed = error {
+                        error = e.into();
+                    }
+                }
+            }
         }
 
-        Ok(Self {
-            id,
-            version: Version::parse(version.as_str())?,
-            format,
-        })
+        Err(error)
     }
 }
 
@@ -117,7 +205,12 @@ impl<O: Send + Sync, I: Info> PartialEq<Plugin<'_, O, I>> for Bundle {
 }
 
 impl PartialEq<Depend> for Bundle {
-    fn eq(&self, Depend { id: name, version }: &Depend) -> bool {
+    fn eq(
+        &self,
+        Depend {
+            id: name, version, ..
+        }: &Depend,
+    ) -> bool {
         self.id == *name && version.matches(&self.version)
     }
 }
@@ -145,3 +238,157 @@ impl Display for Bundle {
         write!(f, "{}-v{}.{}", self.id, self.version, self.format)
     }
 }
+
+/// Serde helpers for (de)serializing a Bundle as a single filename string.
+///
+/// By default a Bundle is serialized as a struct with `id`, `version` and `format` fields.
+/// Annotating a field with `#[serde(with = "plux_rs::bundle::as_filename")]` writes it as
+/// `{id}-v{version}.{format}` instead, using `Display` and `Bundle::from_filename`.
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::Bundle;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "plux_rs::bundle::as_filename")]
+///     plugin: Bundle,
+/// }
+///
+/// let config: Config = toml::from_str(r#"plugin = "calculator-v1.0.0.lua""#).unwrap();
+/// assert_eq!(config.plugin.id, "calculator");
+/// assert_eq!(toml::to_string(&config).unwrap().trim(), r#"plugin = "calculator-v1.0.0.lua""#);
+/// ```
+pub mod as_filename {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use super::Bundle;
+
+    /// Serializes a Bundle as its filename.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - The bundle to serialize
+    /// * `serializer` - The serializer to write the filename to
+    ///
+    /// # Returns
+    ///
+    /// Returns the result of the serializer.
+    pub fn serialize<S: Serializer>(bundle: &Bundle, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(bundle)
+    }
+
+    /// Deserializes a Bundle from its filename.
+    ///
+    /// # Parameters
+    ///
+    /// * `deserializer` - The deserializer to read the filename from
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Bundle, D::Error>` containing the parsed Bundle, or an error
+    /// if the string does not follow the `{id}-v{version}.{format}` convention.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Bundle, D::Error> {
+        let filename = String::deserialize(deserializer)?;
+        Bundle::from_filename(&filename).map_err(D::Error::custom)
+    }
+}
+
+#[test]
+fn from_filename() {
+    for filename in [
+        "foo-v1.0.0.lua",
+        "foo-v1.0.0-rc.1.lua",
+        "foo-v2.0.0+build5.wasm",
+        "foo-v1.0.0-vendor.2+build.lua",
+        "my-view-v0.3.1.vpl",
+    ] {
+        let bundle = Bundle::from_filename(filename).unwrap();
+        assert_eq!(bundle.to_string(), filename);
+    }
+
+    let bundle = Bundle::from_filename("foo-v1.0.0-rc.1.lua").unwrap();
+    assert_eq!(bundle.id, "foo");
+    assert_eq!(bundle.version, Version::parse("1.0.0-rc.1").unwrap());
+    assert_eq!(bundle.format, "lua");
+
+    let bundle = Bundle::from_filename("foo-v1.0.0-vendor.2+build.lua").unwrap();
+    assert_eq!(bundle.id, "foo");
+    assert_eq!(
+        bundle.version,
+        Version::parse("1.0.0-vendor.2+build").unwrap()
+    );
+
+    for (filename, id, version) in [
+        ("my-video-v1.0.0.lua", "my-video", "1.0.0"),
+        ("conv-er-ter-v2.1.0.vpl", "conv-er-ter", "2.1.0"),
+        ("-v-v-v1.0.0.lua", "-v-v", "1.0.0"),
+        ("video-v1-v1.2.3.lua", "video-v1", "1.2.3"),
+        ("legacy-v1.0.0-v2.0.0.lua", "legacy-v1.0.0", "2.0.0"),
+        ("legacy-v1.0.0-v2.lua", "legacy", "1.0.0-v2"),
+    ] {
+        let bundle = Bundle::from_filename(filename).unwrap();
+        assert_eq!(bundle.id, id);
+        assert_eq!(bundle.version, Version::parse(version).unwrap());
+        assert_eq!(bundle.to_string(), filename);
+    }
+
+    assert!(matches!(
+        Bundle::from_filename("foo-v1.0.0"),
+        Err(BundleFromError::ParseVersion(_))
+    ));
+    assert!(matches!(
+        Bundle::from_filename("foo.lua"),
+        Err(BundleFromError::VersionFailed)
+    ));
+    assert!(matches!(
+        Bundle::from_filename("-v1.0.0.lua"),
+        Err(BundleFromError::IDFailed)
+    ));
+    assert!(matches!(
+        Bundle::from_filename("foo-v1.0.0."),
+        Err(BundleFromError::FormatFailed)
+    ));
+}
+
+#[test]
+fn new() {
+    let bundle = Bundle::new("my-view", Version::new(1, 2, 3), "vpl").unwrap();
+    assert_eq!(bundle, Bundle::from_filename("my-view-v1.2.3.vpl").unwrap());
+
+    let bundle = bundle.with_format("lua").unwrap();
+    assert_eq!(bundle.to_string(), "my-view-v1.2.3.lua");
+
+    assert!(matches!(
+        Bundle::new("", Version::new(1, 0, 0), "lua"),
+        Err(BundleFromError::IDFailed)
+    ));
+    assert!(matches!(
+        Bundle::new("foo", Version::new(1, 0, 0), ""),
+        Err(BundleFromError::FormatFailed)
+    ));
+    assert!(matches!(
+        bundle.with_format("tar.gz"),
+        Err(BundleFromError::FormatFailed)
+    ));
+}
+
+#[test]
+fn as_filename() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "as_filename")]
+        plugin: Bundle,
+    }
+
+    let config = Config {
+        plugin: Bundle::from_filename("my-view-v1.0.0-rc.1.vpl").unwrap(),
+    };
+    let content = toml::to_string(&config).unwrap();
+    assert_eq!(content.trim(), r#"plugin = "my-view-v1.0.0-rc.1.vpl""#);
+    assert_eq!(toml::from_str::<Config>(&content).unwrap(), config);
+
+    assert!(toml::from_str::<Config>(r#"plugin = "calculator.lua""#).is_err());
+}
@@ -0,0 +1,237 @@
+use std::{collections::BTreeMap, path::Path};
+
+use semver::VersionReq;
+use serde::{Deserialize, Serialize};
+
+use crate::{Depend, StdInfo, utils::InfoParseError};
+
+/// Descriptive metadata of a plugin read from its config file.
+///
+/// All fields are optional, a config file may omit the `[plugin]` section entirely.
+///
+/// # Fields
+///
+/// * `name` - Human-readable name of the plugin
+/// * `description` - Short description of what the plugin does
+/// * `author` - Author of the plugin
+/// * `license` - License of the plugin
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginMetadata {
+    /// Human-readable name of the plugin
+    pub name: Option<String>,
+    /// Short description of what the plugin does
+    pub description: Option<String>,
+    /// Author of the plugin
+    pub author: Option<String>,
+    /// License of the plugin
+    pub license: Option<String>,
+}
+
+// Standard layout of a plugin config file
+#[derive(Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    plugin: PluginMetadata,
+    #[serde(default)]
+    dependencies: BTreeMap<String, VersionReq>,
+    #[serde(default)]
+    optional_dependencies: BTreeMap<String, VersionReq>,
+}
+
+impl ConfigFile {
+    fn into_parts(self) -> (StdInfo, PluginMetadata) {
+        let depends = |depends: BTreeMap<String, VersionReq>| {
+            depends
+                .into_iter()
+                .map(|(id, version)| Depend::new(id, version))
+                .collect()
+        };
+
+        let info = StdInfo {
+            depends: depends(self.dependencies),
+            optional_depends: depends(self.optional_dependencies),
+        };
+        (info, self.plugin)
+    }
+}
+
+impl StdInfo {
+    /// Reads plugin information from a TOML config file.
+    ///
+    /// The config file uses the following layout, where every section is optional
+    /// and unknown sections are ignored, so managers can keep their own settings
+    /// in the same file:
+    ///
+    /// ```toml
+    /// [plugin]
+    /// name = "Paint"
+    /// description = "Drawing tools"
+    /// author = "Bleyn"
+    /// license = "MIT"
+    ///
+    /// [dependencies]
+    /// brush = "1.0"
+    ///
+    /// [optional_dependencies]
+    /// palette = "2.*"
+    /// ```
+    ///
+    /// Dependencies map a plugin ID to a version requirement and are sorted by ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - Path to the config file
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(StdInfo, PluginMetadata), InfoParseError>` containing the dependency
+    /// information and the metadata of the plugin.
+    pub fn from_toml_file(path: &Path) -> Result<(StdInfo, PluginMetadata), InfoParseError> {
+        Self::from_toml_str(&std::fs::read_to_string(path)?)
+    }
+
+    /// Reads plugin information from TOML config content.
+    ///
+    /// See `from_toml_file` for the expected layout.
+    ///
+    /// # Parameters
+    ///
+    /// * `content` - Content of the config file
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(StdInfo, PluginMetadata), InfoParseError>` containing the dependency
+    /// information and the metadata of the plugin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::StdInfo;
+    ///
+    /// let (info, metadata) = StdInfo::from_toml_str(
+    ///     r#"
+    ///     [plugin]
+    ///     name = "Paint"
+    ///
+    ///     [dependencies]
+    ///     brush = "1.0"
+    ///     "#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(metadata.name.as_deref(), Some("Paint"));
+    /// assert_eq!(info.depends[0].id, "brush");
+    /// ```
+    pub fn from_toml_str(content: &str) -> Result<(StdInfo, PluginMetadata), InfoParseError> {
+        Ok(toml::from_str::<ConfigFile>(content)?.into_parts())
+    }
+
+    /// Reads plugin information from a JSON config file.
+    ///
+    /// The file uses the same layout as the TOML config, with `plugin`, `dependencies`
+    /// and `optional_dependencies` as top-level objects.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - Path to the config file
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(StdInfo, PluginMetadata), InfoParseError>` containing the dependency
+    /// information and the metadata of the plugin.
+    #[cfg(feature = "json")]
+    pub fn from_json_file(path: &Path) -> Result<(StdInfo, PluginMetadata), InfoParseError> {
+        Self::from_json_str(&std::fs::read_to_string(path)?)
+    }
+
+    /// Reads plugin information from JSON config content.
+    ///
+    /// See `from_json_file` for the expected layout.
+    ///
+    /// # Parameters
+    ///
+    /// * `content` - Content of the config file
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(StdInfo, PluginMetadata), InfoParseError>` containing the dependency
+    /// information and the metadata of the plugin.
+    #[cfg(feature = "json")]
+    pub fn from_json_str(content: &str) -> Result<(StdInfo, PluginMetadata), InfoParseError> {
+        Ok(serde_json::from_str::<ConfigFile>(content)?.into_parts())
+    }
+}
+
+#[test]
+fn from_toml_str() {
+    let (info, metadata) = StdInfo::from_toml_str(
+        r#"
+        [plugin]
+        name = "Paint"
+        author = "Bleyn"
+
+        [dependencies]
+        photoshop = "1.0.0"
+        brush = "=2.0.0"
+
+        [optional_dependencies]
+        palette = "2.*"
+
+        [manager]
+        entry = "main.lua"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(metadata.name.as_deref(), Some("Paint"));
+    assert_eq!(metadata.author.as_deref(), Some("Bleyn"));
+    assert_eq!(metadata.license, None);
+
+    assert_eq!(
+        info,
+        StdInfo::new()
+            .with_depend(Depend::new("brush".into(), "=2.0.0".parse().unwrap()))
+            .with_depend(Depend::new("photoshop".into(), "1.0.0".parse().unwrap()))
+            .with_optional_depend(Depend::new("palette".into(), "2.*".parse().unwrap()))
+    );
+
+    let (info, metadata) = StdInfo::from_toml_str("").unwrap();
+    assert_eq!(info, StdInfo::new());
+    assert_eq!(metadata, PluginMetadata::default());
+
+    assert!(matches!(
+        StdInfo::from_toml_str("[dependencies]\nbrush = \"not a version\""),
+        Err(InfoParseError::ParseToml(_))
+    ));
+}
+
+#[test]
+fn from_toml_file() {
+    let path = std::env::temp_dir().join("plux_from_toml_file.toml");
+    std::fs::write(&path, "[dependencies]\nbrush = \"1.0\"").unwrap();
+
+    let (info, _) = StdInfo::from_toml_file(&path).unwrap();
+    assert_eq!(info.depends[0].id, "brush");
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        StdInfo::from_toml_file(&path),
+        Err(InfoParseError::ReadFailed(_))
+    ));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn from_json_str() {
+    let (info, metadata) = StdInfo::from_json_str(
+        r#"{
+            "plugin": { "name": "Paint" },
+            "dependencies": { "brush": "1.0" }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(metadata.name.as_deref(), Some("Paint"));
+    assert_eq!(info.depends[0].id, "brush");
+    assert!(info.optional_depends.is_empty());
+}
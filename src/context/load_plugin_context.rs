@@ -1,4 +1,6 @@
-use crate::{Info, Plugin, Requests, function::Function, utils::RegisterRequestError};
+use std::sync::Arc;
+
+use crate::{Info, LoadPolicy, Plugin, Requests, function::Function, utils::RegisterRequestError};
 
 /// Context provided during plugin loading.
 ///
@@ -17,6 +19,7 @@ use crate::{Info, Plugin, Requests, function::Function, utils::RegisterRequestEr
 ///
 /// * `plugin` - Mutable reference to the plugin being loaded
 /// * `requests` - Reference to the system's function requests
+/// * `policy` - Reference to the resource policy for the plugin
 ///
 /// # Example
 ///
@@ -48,6 +51,7 @@ use crate::{Info, Plugin, Requests, function::Function, utils::RegisterRequestEr
 pub struct LoadPluginContext<'a, 'b, O: Send + Sync, I: Info> {
     plugin: &'b mut Plugin<'a, O, I>,
     requests: &'b Requests,
+    policy: &'b LoadPolicy,
 }
 
 impl<'a, 'b, O: Send + Sync, I: Info> LoadPluginContext<'a, 'b, O, I> {
@@ -59,12 +63,21 @@ impl<'a, 'b, O: Send + Sync, I: Info> LoadPluginContext<'a, 'b, O, I> {
     ///
     /// * `plugin` - Mutable reference to the plugin being loaded
     /// * `requests` - Reference to the system's function requests
+    /// * `policy` - Reference to the resource policy for the plugin
     ///
     /// # Returns
     ///
     /// Returns a new LoadPluginContext instance.
-    pub(crate) fn new(plugin: &'b mut Plugin<'a, O, I>, requests: &'b Requests) -> Self {
-        Self { plugin, requests }
+    pub(crate) fn new(
+        plugin: &'b mut Plugin<'a, O, I>,
+        requests: &'b Requests,
+        policy: &'b LoadPolicy,
+    ) -> Self {
+        Self {
+            plugin,
+            requests,
+            policy,
+        }
     }
 
     /// Gets a reference to the plugin being loaded.
@@ -85,10 +98,23 @@ impl<'a, 'b, O: Send + Sync, I: Info> LoadPluginContext<'a, 'b, O, I> {
         self.requests
     }
 
+    /// Gets the resource policy for the plugin being loaded.
+    ///
+    /// Managers read the policy to configure the runtime of the plugin, for example
+    /// its memory limit or call timeout. Enforcing the policy is up to the manager.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the load policy.
+    pub const fn policy(&self) -> &'b LoadPolicy {
+        self.policy
+    }
+
     /// Registers a function that implements a system request.
     ///
     /// This method validates that the provided function matches the signature of
     /// a registered system request and then registers it with the plugin.
+    /// Types are matched with `VariableType::is_compatible_with`, so `Let` acts as a wildcard.
     ///
     /// # Parameters
     ///
@@ -97,7 +123,9 @@ impl<'a, 'b, O: Send + Sync, I: Info> LoadPluginContext<'a, 'b, O, I> {
     /// # Returns
     ///
     /// Returns `Result<(), RegisterRequestError>` indicating success or failure.
-    /// Fails if the function doesn't match any registered request or has incorrect arguments.
+    /// Fails if the function doesn't match any registered request, if its arguments
+    /// don't match the request positionally in both number and type, or if the plugin
+    /// has already registered a function for the request.
     ///
     /// # Type Parameters
     ///
@@ -106,24 +134,224 @@ impl<'a, 'b, O: Send + Sync, I: Info> LoadPluginContext<'a, 'b, O, I> {
     where
         F: Function<Output = O> + 'static,
     {
-        if let Some(req) = self.requests.iter().find(|req| *req.name == request.name()) {
-            for input in req.inputs.iter() {
-                request
-                    .inputs()
-                    .iter()
-                    .find(|arg| *input == arg.ty)
-                    .ok_or(RegisterRequestError::ArgumentsIncorrectly)?;
-            }
-
-            if req.output != request.output().map(|arg| arg.ty) {
-                return Err(RegisterRequestError::ArgumentsIncorrectly);
-            }
-        } else {
-            return Err(RegisterRequestError::NotFound);
+        let req = self
+            .requests
+            .iter()
+            .find(|req| *req.name == request.name())
+            .ok_or(RegisterRequestError::NotFound)?;
+
+        req.check_signature(&request)?;
+
+        if self
+            .plugin
+            .requests
+            .iter()
+            .any(|r| r.name() == request.name())
+        {
+            return Err(RegisterRequestError::DuplicateName(request.name()));
         }
 
-        self.plugin.requests.push(Box::new(request));
+        self.plugin.requests.push(Arc::new(request));
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Bundle, Manager, Plugin, PluginInfo, RegisterPluginContext, StdInfo,
+        function::FunctionOutput,
+        utils::{ManagerResult, Ptr},
+    };
+
+    struct TestManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for TestManager {
+        fn format(&self) -> &'static str {
+            "test"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+    }
+
+    pub fn manager<'a>() -> Box<dyn Manager<'a, FunctionOutput, StdInfo>> {
+        Box::new(TestManager)
+    }
+
+    pub fn plugin<'a>(
+        manager: &mut Box<dyn Manager<'a, FunctionOutput, StdInfo>>,
+    ) -> Plugin<'a, FunctionOutput, StdInfo> {
+        Plugin::new(
+            Ptr::new(&mut **manager),
+            PluginInfo {
+                path: "test-v1.0.0.test".into(),
+                bundle: Bundle::from_filename("test-v1.0.0.test").unwrap(),
+                info: StdInfo::new(),
+            },
+        )
+    }
+}
+
+#[test]
+fn register_request_arguments() {
+    use crate::{
+        function::{Arg, DynamicFunction, FunctionOutput, Request},
+        variable::VariableType,
+    };
+
+    let mut manager = tests::manager();
+    let mut plugin = tests::plugin(&mut manager);
+
+    let requests = vec![Request::new(
+        "repeat",
+        vec![VariableType::String, VariableType::I32],
+        Some(VariableType::String),
+    )];
+    let policy = LoadPolicy::new();
+    let mut context = LoadPluginContext::new(&mut plugin, &requests, &policy);
+
+    let function = |inputs| {
+        DynamicFunction::new(
+            "repeat",
+            inputs,
+            Some(Arg::new("result", VariableType::String)),
+            |_| -> FunctionOutput { Ok(None) },
+        )
+    };
+
+    // Reordered arguments
+    let result = context.register_request(function(vec![
+        Arg::new("count", VariableType::I32),
+        Arg::new("text", VariableType::String),
+    ]));
+    assert!(matches!(
+        result,
+        Err(RegisterRequestError::ArgumentsIncorrectly(0))
+    ));
+
+    // Extra argument
+    let result = context.register_request(function(vec![
+        Arg::new("text", VariableType::String),
+        Arg::new("count", VariableType::I32),
+        Arg::new("separator", VariableType::String),
+    ]));
+    assert!(matches!(
+        result,
+        Err(RegisterRequestError::ArgumentsIncorrectly(2))
+    ));
+
+    let result = context.register_request(function(vec![
+        Arg::new("text", VariableType::String),
+        Arg::new("count", VariableType::I32),
+    ]));
+    assert!(result.is_ok());
+
+    // The request is already implemented
+    let result = context.register_request(function(vec![
+        Arg::new("text", VariableType::String),
+        Arg::new("count", VariableType::I32),
+    ]));
+    assert!(matches!(
+        result,
+        Err(RegisterRequestError::DuplicateName(name)) if name == "repeat"
+    ));
+}
+
+#[test]
+fn register_request_optional_arguments() {
+    use crate::{
+        function::{Arg, DynamicFunction, FunctionOutput, Request},
+        variable::{Variable, VariableType},
+    };
+
+    let mut manager = tests::manager();
+    let mut plugin = tests::plugin(&mut manager);
+
+    let requests = vec![
+        Request::new(
+            "join",
+            vec![VariableType::List, VariableType::String],
+            Some(VariableType::String),
+        )
+        .with_optional_inputs(1),
+    ];
+    let policy = LoadPolicy::new();
+    let mut context = LoadPluginContext::new(&mut plugin, &requests, &policy);
+
+    // Omitting the optional input of the request is allowed
+    let result = context.register_request(DynamicFunction::new(
+        "join",
+        vec![Arg::new("list", VariableType::List)],
+        Some(Arg::new("result", VariableType::String)),
+        |_| -> FunctionOutput { Ok(None) },
+    ));
+    assert!(result.is_ok());
+
+    // Omitting the required input is not
+    let result = context.register_request(DynamicFunction::new(
+        "join",
+        vec![],
+        Some(Arg::new("result", VariableType::String)),
+        |_| -> FunctionOutput { Ok(None) },
+    ));
+    assert!(matches!(
+        result,
+        Err(RegisterRequestError::ArgumentsIncorrectly(0))
+    ));
+
+    // An argument at an optional position must be optional, otherwise a call
+    // without it would pass fewer arguments than the function reads
+    let result = context.register_request(DynamicFunction::new(
+        "join",
+        vec![
+            Arg::new("list", VariableType::List),
+            Arg::new("separator", VariableType::String),
+        ],
+        Some(Arg::new("result", VariableType::String)),
+        |args| -> FunctionOutput { Ok(Some(args[1].clone())) },
+    ));
+    assert!(matches!(
+        result,
+        Err(RegisterRequestError::ArgumentsIncorrectly(1))
+    ));
+
+    // Missing optional arguments are filled with `Variable::Null`
+    let mut plugin = tests::plugin(&mut manager);
+    let policy = LoadPolicy::new();
+    let mut context = LoadPluginContext::new(&mut plugin, &requests, &policy);
+    context
+        .register_request(DynamicFunction::new(
+            "join",
+            vec![
+                Arg::new("list", VariableType::List),
+                Arg::new("separator", VariableType::String).optional(),
+            ],
+            Some(Arg::new("result", VariableType::String)),
+            |args| -> FunctionOutput {
+                let separator = match &args[1] {
+                    Variable::Null => ", ".to_string(),
+                    separator => separator.parse_ref::<String>().clone(),
+                };
+                let list = args[0].parse_ref::<Vec<Variable>>();
+                Ok(Some(
+                    list.iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(&separator)
+                        .into(),
+                ))
+            },
+        ))
+        .unwrap();
+
+    let result = plugin.call_request("join", &[vec![1, 2].into()]).unwrap();
+    assert_eq!(result.unwrap(), Some("1, 2".into()));
+
+    let result = plugin
+        .call_request("join", &[vec![1, 2].into(), "-".into()])
+        .unwrap();
+    assert_eq!(result.unwrap(), Some("1-2".into()));
+}
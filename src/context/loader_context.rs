@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
 use crate::{
-    Info, Loader, Manager,
+    Info, LoadPolicy, Loader, Manager,
     function::{Function, Request},
-    utils::RegisterManagerError,
+    utils::{RegisterManagerError, RegisterRequestError},
 };
 
 /// Context for configuring the plugin loader.
@@ -90,7 +90,8 @@ impl<'a, 'b, O: Send + Sync, I: Info> LoaderContext<'a, 'b, O, I> {
 
     /// Registers multiple plugin managers with the loader.
     ///
-    /// This method registers a collection of managers in sequence.
+    /// This method registers a collection of managers in sequence. If a manager fails
+    /// to register, the managers already registered by this call are unregistered again.
     ///
     /// # Parameters
     ///
@@ -109,17 +110,48 @@ impl<'a, 'b, O: Send + Sync, I: Info> LoaderContext<'a, 'b, O, I> {
     /// Registers a function request with the loader.
     ///
     /// Function requests define the interface that plugins must implement.
+    /// Request names are unique, a request with the same name as an already
+    /// registered one replaces it. Use `try_register_request` to keep the existing request.
     ///
     /// # Parameters
     ///
     /// * `request` - The request to register
     pub fn register_request(&mut self, request: Request) {
+        let requests = &mut self.loader.requests;
+        match requests.iter_mut().find(|req| req.name == request.name) {
+            Some(req) => *req = request,
+            None => requests.push(request),
+        }
+    }
+
+    /// Registers a function request with the loader unless its name is already taken.
+    ///
+    /// # Parameters
+    ///
+    /// * `request` - The request to register
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), RegisterRequestError>`, failing with `DuplicateName`
+    /// if a request with the same name is already registered.
+    pub fn try_register_request(&mut self, request: Request) -> Result<(), RegisterRequestError> {
+        if self
+            .loader
+            .requests
+            .iter()
+            .any(|req| req.name == request.name)
+        {
+            return Err(RegisterRequestError::DuplicateName(request.name));
+        }
+
         self.loader.requests.push(request);
+        Ok(())
     }
 
     /// Registers multiple function requests with the loader.
     ///
-    /// This method registers a collection of requests.
+    /// This method registers a collection of requests. As with `register_request`,
+    /// later requests replace earlier ones with the same name.
     ///
     /// # Parameters
     ///
@@ -132,12 +164,27 @@ impl<'a, 'b, O: Send + Sync, I: Info> LoaderContext<'a, 'b, O, I> {
     where
         IT: IntoIterator<Item = Request>,
     {
-        self.loader.requests.extend(requests);
+        requests
+            .into_iter()
+            .for_each(|request| self.register_request(request));
+    }
+
+    /// Sets the resource policy passed to managers when loading plugins.
+    ///
+    /// The policy is available to managers through `LoadPluginContext::policy`.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy` - The policy to apply to plugins loaded afterwards
+    pub fn set_default_policy(&mut self, policy: LoadPolicy) {
+        self.loader.default_policy = policy;
     }
 
     /// Registers a function in the loader's registry.
     ///
     /// Functions registered here are available to all plugins.
+    /// Duplicates are not checked, use `try_register_function` to skip functions
+    /// that are already registered.
     ///
     /// # Parameters
     ///
@@ -153,6 +200,38 @@ impl<'a, 'b, O: Send + Sync, I: Info> LoaderContext<'a, 'b, O, I> {
         self.loader.registry.push(Arc::new(function));
     }
 
+    /// Registers a function in the loader's registry unless it is already registered.
+    ///
+    /// Unlike `register_function`, this method checks the registry for a function with
+    /// the same name and signature and skips the registration if one is found.
+    ///
+    /// # Parameters
+    ///
+    /// * `function` - The function to register
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the function was registered, `false` if it was already in the registry.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - Type of the function (must implement Function trait)
+    pub fn try_register_function<F>(&mut self, function: F) -> bool
+    where
+        F: Function<Output = O> + 'static,
+    {
+        let exists = self
+            .loader
+            .registry
+            .iter()
+            .any(|f| f.as_ref() == &function as &dyn Function<Output = O>);
+
+        if !exists {
+            self.loader.registry.push(Arc::new(function));
+        }
+        !exists
+    }
+
     /// Registers multiple functions in the loader's registry.
     ///
     /// This method registers a collection of functions.
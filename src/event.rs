@@ -0,0 +1,36 @@
+use crate::{Bundle, variable::Variable};
+
+/// Event sent by a plugin to the host.
+///
+/// Plugins emit events with `Api::emit` to signal the host, e.g. that their state changed,
+/// without the host having to call them first. The host receives the events through
+/// the channels returned by `Loader::events`.
+///
+/// # Fields
+///
+/// * `source` - Bundle of the plugin that emitted the event
+/// * `name` - Name of the event
+/// * `payload` - Data attached to the event
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::{Bundle, PluginEvent, variable::Variable};
+///
+/// let event = PluginEvent {
+///     source: Bundle::from_filename("paint-v1.0.0.lua").unwrap(),
+///     name: "saved".to_string(),
+///     payload: Variable::from("picture.png"),
+/// };
+///
+/// assert_eq!(event.source.id, "paint");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginEvent {
+    /// Bundle of the plugin that emitted the event
+    pub source: Bundle,
+    /// Name of the event
+    pub name: String,
+    /// Data attached to the event
+    pub payload: Variable,
+}
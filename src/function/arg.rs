@@ -1,8 +1,9 @@
-use std::fmt::Display;
+use alloc::string::{String, ToString};
+use core::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::variable::VariableType;
+use crate::variable::{Variable, VariableType};
 
 /// Represents a function argument with name and type.
 ///
@@ -13,6 +14,8 @@ use crate::variable::VariableType;
 ///
 /// * `name` - The argument name (used for documentation and debugging)
 /// * `ty` - The data type of the argument
+/// * `optional` - Whether the argument may be omitted by the caller
+/// * `constraint` - Optional constraint on the value of the argument
 ///
 /// # Examples
 ///
@@ -33,6 +36,105 @@ pub struct Arg {
     pub name: String,
     /// The data type of the argument
     pub ty: VariableType,
+    /// Whether the argument may be omitted by the caller
+    #[serde(default)]
+    pub optional: bool,
+    /// Constraint the value of the argument must satisfy
+    #[serde(default)]
+    pub constraint: Option<ArgConstraint>,
+}
+
+/// Constraint on the value of a function argument.
+///
+/// Constraints are checked by `Function::call_checked` in addition to the argument type,
+/// which gives a declarative way to reject bad input.
+///
+/// # Variants
+///
+/// * `IntRange` - An integer within the inclusive range `min..=max`
+/// * `MaxLen` - A string, list, byte array or map with at most the given number of elements
+/// * `NonEmpty` - A string, list, byte array or map with at least one element
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::function::ArgConstraint;
+///
+/// assert!(ArgConstraint::IntRange(1, 10).is_satisfied_by(&5.into()));
+/// assert!(!ArgConstraint::MaxLen(3).is_satisfied_by(&"long".into()));
+/// assert!(!ArgConstraint::NonEmpty.is_satisfied_by(&"".into()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub enum ArgConstraint {
+    /// An integer within the inclusive range `min..=max`
+    IntRange(i64, i64),
+    /// A string, list, byte array or map with at most the given number of elements,
+    /// strings are measured in characters
+    MaxLen(usize),
+    /// A string, list, byte array or map with at least one element
+    NonEmpty,
+}
+
+impl ArgConstraint {
+    /// Checks if a value satisfies the constraint.
+    ///
+    /// Values of a type the constraint does not apply to, such as a string
+    /// for `IntRange`, do not satisfy it.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the value satisfies the constraint, `false` otherwise.
+    pub fn is_satisfied_by(&self, value: &Variable) -> bool {
+        match self {
+            ArgConstraint::IntRange(min, max) => {
+                int_value(value).is_some_and(|value| (*min as i128..=*max as i128).contains(&value))
+            }
+            ArgConstraint::MaxLen(max) => len(value).is_some_and(|len| len <= *max),
+            ArgConstraint::NonEmpty => len(value).is_some_and(|len| len > 0),
+        }
+    }
+}
+
+// Integer value of the variable, if it is an integer that fits in i128
+fn int_value(value: &Variable) -> Option<i128> {
+    match value {
+        Variable::I8(v) => Some(*v as i128),
+        Variable::I16(v) => Some(*v as i128),
+        Variable::I32(v) => Some(*v as i128),
+        Variable::I64(v) => Some(*v as i128),
+        Variable::I128(v) => Some(*v),
+        Variable::U8(v) => Some(*v as i128),
+        Variable::U16(v) => Some(*v as i128),
+        Variable::U32(v) => Some(*v as i128),
+        Variable::U64(v) => Some(*v as i128),
+        Variable::U128(v) => i128::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+// Number of elements of the variable, if it is a collection
+fn len(value: &Variable) -> Option<usize> {
+    match value {
+        Variable::String(v) => Some(v.chars().count()),
+        Variable::List(v) => Some(v.len()),
+        Variable::Bytes(v) => Some(v.len()),
+        Variable::Map(v) => Some(v.len()),
+        _ => None,
+    }
+}
+
+impl Display for ArgConstraint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ArgConstraint::IntRange(min, max) => write!(f, "{min}..={max}"),
+            ArgConstraint::MaxLen(max) => write!(f, "len <= {max}"),
+            ArgConstraint::NonEmpty => write!(f, "non-empty"),
+        }
+    }
 }
 
 impl Arg {
@@ -65,8 +167,59 @@ impl Arg {
         Self {
             name: name.into(),
             ty,
+            optional: false,
+            constraint: None,
         }
     }
+
+    /// Marks the argument as optional.
+    ///
+    /// Optional arguments must be trailing. When a function is called with fewer
+    /// arguments than declared, missing optional arguments are filled with `Variable::Null`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the Arg marked as optional.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::function::Arg;
+    /// use plux_rs::variable::VariableType;
+    ///
+    /// let arg = Arg::new("separator", VariableType::String).optional();
+    /// assert!(arg.optional);
+    /// ```
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Sets the constraint the value of the argument must satisfy.
+    ///
+    /// The constraint is enforced by `Function::call_checked`.
+    ///
+    /// # Parameters
+    ///
+    /// * `constraint` - The constraint on the argument value
+    ///
+    /// # Returns
+    ///
+    /// Returns the Arg with the constraint set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::function::{Arg, ArgConstraint};
+    /// use plux_rs::variable::VariableType;
+    ///
+    /// let arg = Arg::new("name", VariableType::String).with_constraint(ArgConstraint::MaxLen(32));
+    /// assert_eq!(arg.constraint, Some(ArgConstraint::MaxLen(32)));
+    /// ```
+    pub fn with_constraint(mut self, constraint: ArgConstraint) -> Self {
+        self.constraint = Some(constraint);
+        self
+    }
 }
 
 impl Default for Arg {
@@ -74,12 +227,53 @@ impl Default for Arg {
         Self {
             name: "arg".to_string(),
             ty: Default::default(),
+            optional: false,
+            constraint: None,
         }
     }
 }
 
 impl Display for Arg {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}: {}", self.name, self.ty)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.optional {
+            true => write!(f, "{}?: {}", self.name, self.ty),
+            false => write!(f, "{}: {}", self.name, self.ty),
+        }
     }
 }
+
+#[test]
+fn constraints() {
+    let range = ArgConstraint::IntRange(-5, 5);
+    assert!(range.is_satisfied_by(&Variable::I8(-5)));
+    assert!(range.is_satisfied_by(&Variable::U64(5)));
+    assert!(!range.is_satisfied_by(&Variable::I32(6)));
+    assert!(!range.is_satisfied_by(&Variable::U128(u128::MAX)));
+    assert!(!range.is_satisfied_by(&Variable::F64(1.0)));
+
+    let max_len = ArgConstraint::MaxLen(2);
+    assert!(max_len.is_satisfied_by(&"ая".into()));
+    assert!(max_len.is_satisfied_by(&Variable::List(vec![])));
+    assert!(!max_len.is_satisfied_by(&Variable::Bytes(vec![0; 3])));
+    assert!(!max_len.is_satisfied_by(&Variable::I32(1)));
+
+    assert!(ArgConstraint::NonEmpty.is_satisfied_by(&Variable::Map(vec![(1.into(), 2.into())])));
+    assert!(!ArgConstraint::NonEmpty.is_satisfied_by(&"".into()));
+    assert!(!ArgConstraint::NonEmpty.is_satisfied_by(&Variable::Null));
+}
+
+#[test]
+fn display() {
+    assert_eq!(Arg::new("a", VariableType::I32).to_string(), "a: I32");
+    assert_eq!(
+        Arg::new("level", VariableType::U8).optional().to_string(),
+        "level?: U8"
+    );
+
+    // Constraints are not part of the signature
+    let arg = Arg::new("name", VariableType::String).with_constraint(ArgConstraint::NonEmpty);
+    assert_eq!(arg.to_string(), "name: String");
+    assert_eq!(ArgConstraint::IntRange(-5, 5).to_string(), "-5..=5");
+    assert_eq!(ArgConstraint::MaxLen(8).to_string(), "len <= 8");
+    assert_eq!(ArgConstraint::NonEmpty.to_string(), "non-empty");
+}
@@ -1,6 +1,21 @@
-use std::fmt::{Debug, Display};
-
-use crate::variable::Variable;
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use std::{
+    borrow::Cow,
+    sync::{Mutex, PoisonError},
+};
+
+use crate::{
+    utils::{FunctionCallError, FunctionOutputError},
+    variable::{Variable, VariableType},
+};
 
 use super::Arg;
 
@@ -20,6 +35,13 @@ use super::Arg;
 /// * `output` - Returns the output argument (if any)
 /// * `call` - Executes the function with the given arguments
 ///
+/// # Provided Methods
+///
+/// * `description` - Returns the function description (if any)
+/// * `call_mut` - Executes the function with arguments that it may modify in place
+/// * `call_stateful` - Executes the function with exclusive access to its own state
+/// * `call_checked` - Validates the arguments against the signature before calling
+///
 /// # Example
 ///
 /// ```rust
@@ -56,6 +78,17 @@ pub trait Function: Send + Sync {
     /// Returns the function name as a String.
     fn name(&self) -> String;
 
+    /// Returns the description of the function.
+    ///
+    /// Default implementation returns `None`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(String)` with a human-readable description, or `None` if it is missing.
+    fn description(&self) -> Option<String> {
+        None
+    }
+
     /// Returns the input arguments of the function.
     ///
     /// # Returns
@@ -80,6 +113,105 @@ pub trait Function: Send + Sync {
     ///
     /// Returns the function's output of type `Self::Output`.
     fn call(&self, args: &[Variable]) -> Self::Output;
+
+    /// Calls the function with the given arguments, allowing it to modify them in place.
+    ///
+    /// Default implementation forwards to `call` and leaves the arguments unchanged.
+    /// Functions generated by `#[function]` with `&mut T` parameters write their
+    /// changes back into `args`.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - Mutable slice of Variable arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns the function's output of type `Self::Output`.
+    fn call_mut(&self, args: &mut [Variable]) -> Self::Output {
+        self.call(args)
+    }
+
+    /// Calls the function with exclusive access to its own state.
+    ///
+    /// Default implementation forwards to `call`. Functions that keep internal state,
+    /// such as `StatefulFunction`, override it to mutate that state without locking.
+    /// It is used by `Plugin::call_request_mut`.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - Slice of Variable arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns the function's output of type `Self::Output`.
+    fn call_stateful(&mut self, args: &[Variable]) -> Self::Output {
+        self.call(args)
+    }
+
+    /// Calls the function after validating the arguments against its signature.
+    ///
+    /// The number of arguments must match `inputs()`, and the type of each argument
+    /// must match the declared `Arg::ty`. Arguments declared as `VariableType::Let`
+    /// accept values of any type. Each argument must also satisfy its `Arg::constraint`,
+    /// if one is declared.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - Slice of Variable arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Self::Output, FunctionCallError>` containing the function's
+    /// output, or an error if the arguments do not match the signature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::function::{Function, Arg, DynamicFunction, FunctionOutput};
+    /// use plux_rs::variable::VariableType;
+    ///
+    /// let neg = DynamicFunction::new(
+    ///     "neg",
+    ///     vec![Arg::new("x", VariableType::I32)],
+    ///     Some(Arg::new("result", VariableType::I32)),
+    ///     |args| -> FunctionOutput { Ok(Some((-args[0].parse_ref::<i32>()).into())) },
+    /// );
+    ///
+    /// assert!(neg.call_checked(&[1.into()]).is_ok());
+    /// assert!(neg.call_checked(&["1".into()]).is_err());
+    /// assert!(neg.call_checked(&[]).is_err());
+    /// ```
+    fn call_checked(&self, args: &[Variable]) -> Result<Self::Output, FunctionCallError> {
+        let inputs = self.inputs();
+        if inputs.len() != args.len() {
+            return Err(FunctionCallError::ArgumentsCount {
+                expected: inputs.len(),
+                actual: args.len(),
+            });
+        }
+
+        for (input, arg) in inputs.into_iter().zip(args) {
+            let ty = arg.type_of();
+            if input.ty != VariableType::Let && input.ty != ty {
+                return Err(FunctionCallError::ArgumentType {
+                    name: input.name,
+                    expected: input.ty,
+                    actual: ty,
+                });
+            }
+
+            if let Some(constraint) = input.constraint
+                && !constraint.is_satisfied_by(arg)
+            {
+                return Err(FunctionCallError::ArgumentConstraint {
+                    name: input.name,
+                    constraint,
+                });
+            }
+        }
+
+        Ok(self.call(args))
+    }
 }
 
 impl<O: Send + Sync> PartialEq for dyn Function<Output = O> {
@@ -93,10 +225,11 @@ impl<O: Send + Sync> PartialEq for dyn Function<Output = O> {
 impl<O: Send + Sync> Eq for dyn Function<Output = O> {}
 
 impl<O: Send + Sync> Display for dyn Function<Output = O> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        //TODO: Implement function descriptions in August
-        // // Comment as function description
-        // write!(f, "# {}\n", self.description);
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Comment as function description
+        if let Some(description) = self.description() {
+            writeln!(f, "# {description}")?;
+        }
 
         // Function
         write!(
@@ -117,16 +250,72 @@ impl<O: Send + Sync> Display for dyn Function<Output = O> {
 }
 
 impl<O: Send + Sync> Debug for dyn Function<Output = O> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
+// Fills missing trailing optional arguments with `Variable::Null`
+#[cfg(feature = "std")]
+pub(crate) fn fill_optional_args<'v>(inputs: &[Arg], args: &'v [Variable]) -> Cow<'v, [Variable]> {
+    match args.len() < inputs.len() && inputs[args.len()..].iter().all(|arg| arg.optional) {
+        true => {
+            let mut args = args.to_vec();
+            args.resize(inputs.len(), Variable::Null);
+            Cow::Owned(args)
+        }
+        false => Cow::Borrowed(args),
+    }
+}
+
 /// Standard output type for dynamic functions.
 ///
 /// This type alias represents the result of calling a dynamic function.
 /// It can either succeed with an optional Variable result or fail with an error.
-pub type FunctionOutput = Result<Option<Variable>, Box<dyn std::error::Error + Send + Sync>>;
+pub type FunctionOutput = Result<Option<Variable>, Box<dyn core::error::Error + Send + Sync>>;
+
+/// Conversions of a [`FunctionOutput`] into the values used by host code.
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::function::{FunctionOutput, FunctionOutputExt};
+/// use plux_rs::utils::FunctionOutputError;
+/// use plux_rs::variable::Variable;
+///
+/// let output: FunctionOutput = Ok(None);
+/// assert!(matches!(output.into_variable(), Err(FunctionOutputError::NoValue)));
+///
+/// let output: FunctionOutput = Ok(None);
+/// assert_eq!(output.ok_or_null().unwrap(), Variable::Null);
+/// ```
+pub trait FunctionOutputExt {
+    /// Takes the value returned by the function.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Variable, FunctionOutputError>` containing the value, or an error
+    /// if the function failed or returned no value.
+    fn into_variable(self) -> Result<Variable, FunctionOutputError>;
+
+    /// Takes the value returned by the function, using `Variable::Null` for no value.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Variable, Box<dyn Error + Send + Sync>>` containing the value,
+    /// or the error returned by the function.
+    fn ok_or_null(self) -> Result<Variable, Box<dyn core::error::Error + Send + Sync>>;
+}
+
+impl FunctionOutputExt for FunctionOutput {
+    fn into_variable(self) -> Result<Variable, FunctionOutputError> {
+        self?.ok_or(FunctionOutputError::NoValue)
+    }
+
+    fn ok_or_null(self) -> Result<Variable, Box<dyn core::error::Error + Send + Sync>> {
+        Ok(self?.unwrap_or(Variable::Null))
+    }
+}
 
 /// A dynamic function that can be called at runtime.
 ///
@@ -136,6 +325,7 @@ pub type FunctionOutput = Result<Option<Variable>, Box<dyn std::error::Error + S
 /// # Fields
 ///
 /// * `name` - The function name
+/// * `description` - Optional function description
 /// * `inputs` - List of input arguments
 /// * `output` - Optional output argument
 /// * `ptr` - The function implementation as a boxed closure
@@ -162,6 +352,7 @@ pub type FunctionOutput = Result<Option<Variable>, Box<dyn std::error::Error + S
 /// ```
 pub struct DynamicFunction {
     name: String,
+    description: Option<String>,
     inputs: Vec<Arg>,
     output: Option<Arg>,
     ptr: Box<dyn Fn(&[Variable]) -> FunctionOutput + Send + Sync>,
@@ -209,11 +400,37 @@ impl DynamicFunction {
     {
         Self {
             name: name.into(),
+            description: None,
             inputs,
             output,
             ptr: Box::new(ptr),
         }
     }
+
+    /// Sets the description of the function.
+    ///
+    /// # Parameters
+    ///
+    /// * `description` - The function description (will be converted to String)
+    ///
+    /// # Returns
+    ///
+    /// Returns the DynamicFunction with the description set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::function::{DynamicFunction, Function, FunctionOutput};
+    ///
+    /// let ping = DynamicFunction::new("ping", vec![], None, |_| -> FunctionOutput { Ok(None) })
+    ///     .with_description("Checks that the host is responding");
+    ///
+    /// assert_eq!(ping.description().as_deref(), Some("Checks that the host is responding"));
+    /// ```
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
 }
 
 impl Function for DynamicFunction {
@@ -223,6 +440,10 @@ impl Function for DynamicFunction {
         self.name.clone()
     }
 
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
     fn inputs(&self) -> Vec<Arg> {
         self.inputs.clone()
     }
@@ -236,6 +457,235 @@ impl Function for DynamicFunction {
     }
 }
 
+/// A dynamic function that owns mutable state.
+///
+/// StatefulFunction wraps an `FnMut` closure, so the function can change the values it
+/// captures, such as a counter. Calls through `call_stateful` use the exclusive access
+/// to the function directly, while calls through `call` lock the closure first.
+///
+/// # Fields
+///
+/// * `name` - The function name
+/// * `description` - Optional function description
+/// * `inputs` - List of input arguments
+/// * `output` - Optional output argument
+/// * `ptr` - The function implementation as a boxed closure behind a mutex
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::function::{Arg, Function, FunctionOutput, StatefulFunction};
+/// use plux_rs::variable::VariableType;
+///
+/// let mut count = 0;
+/// let mut counter = StatefulFunction::new(
+///     "next",
+///     vec![],
+///     Some(Arg::new("count", VariableType::I32)),
+///     move |_| -> FunctionOutput {
+///         count += 1;
+///         Ok(Some(count.into()))
+///     },
+/// );
+///
+/// assert_eq!(counter.call_stateful(&[]).unwrap(), Some(1.into()));
+/// assert_eq!(counter.call(&[]).unwrap(), Some(2.into()));
+/// ```
+#[cfg(feature = "std")]
+pub struct StatefulFunction {
+    name: String,
+    description: Option<String>,
+    inputs: Vec<Arg>,
+    output: Option<Arg>,
+    ptr: Mutex<StatefulPtr>,
+}
+
+#[cfg(feature = "std")]
+type StatefulPtr = Box<dyn FnMut(&[Variable]) -> FunctionOutput + Send>;
+
+#[cfg(feature = "std")]
+impl StatefulFunction {
+    /// Creates a new stateful function.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The function name (will be converted to String)
+    /// * `inputs` - Vector of input arguments
+    /// * `output` - Optional output argument (None for void functions)
+    /// * `ptr` - The function implementation as a closure that may mutate its captures
+    ///
+    /// # Returns
+    ///
+    /// Returns a new StatefulFunction instance.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `S` - Type that can be converted into String (for the name)
+    /// * `F` - Function type that takes &[Variable] and returns FunctionOutput
+    pub fn new<S, F>(name: S, inputs: Vec<Arg>, output: Option<Arg>, ptr: F) -> Self
+    where
+        S: Into<String>,
+        F: FnMut(&[Variable]) -> FunctionOutput + Send + 'static,
+    {
+        Self {
+            name: name.into(),
+            description: None,
+            inputs,
+            output,
+            ptr: Mutex::new(Box::new(ptr)),
+        }
+    }
+
+    /// Sets the description of the function.
+    ///
+    /// # Parameters
+    ///
+    /// * `description` - The function description (will be converted to String)
+    ///
+    /// # Returns
+    ///
+    /// Returns the StatefulFunction with the description set.
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl Function for StatefulFunction {
+    type Output = FunctionOutput;
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    fn inputs(&self) -> Vec<Arg> {
+        self.inputs.clone()
+    }
+
+    fn output(&self) -> Option<Arg> {
+        self.output.clone()
+    }
+
+    fn call(&self, args: &[Variable]) -> Self::Output {
+        // A panic in a previous call does not invalidate the closure itself
+        let mut ptr = self.ptr.lock().unwrap_or_else(PoisonError::into_inner);
+        (ptr)(args)
+    }
+
+    fn call_stateful(&mut self, args: &[Variable]) -> Self::Output {
+        let ptr = self.ptr.get_mut().unwrap_or_else(PoisonError::into_inner);
+        (ptr)(args)
+    }
+}
+
+/// A function that post-processes the output of another function.
+///
+/// MapOutputFunction wraps an existing function and converts each of its outputs
+/// with a closure, keeping the name, description and signature of the wrapped function.
+/// This allows a function to be registered in a registry with another output type,
+/// or to inspect its results, without rewriting it.
+///
+/// # Type Parameters
+///
+/// * `O1` - Output type of the wrapped function
+/// * `O2` - Output type produced by the closure
+///
+/// # Fields
+///
+/// * `function` - The wrapped function
+/// * `map` - The closure converting the outputs
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use plux_rs::function::{DynamicFunction, Function, FunctionOutput, MapOutputFunction};
+///
+/// let answer = DynamicFunction::new("answer", vec![], None, |_| -> FunctionOutput {
+///     Ok(Some(42.into()))
+/// });
+///
+/// // Discard errors, keeping only the value
+/// let answer = MapOutputFunction::new(Arc::new(answer), |output: FunctionOutput| {
+///     output.ok().flatten()
+/// });
+///
+/// assert_eq!(answer.name(), "answer");
+/// assert_eq!(answer.call(&[]), Some(42.into()));
+/// ```
+pub struct MapOutputFunction<O1: Send + Sync, O2> {
+    function: Arc<dyn Function<Output = O1>>,
+    map: Box<dyn Fn(O1) -> O2 + Send + Sync>,
+}
+
+impl<O1: Send + Sync, O2: Send + Sync> MapOutputFunction<O1, O2> {
+    /// Creates a new function mapping the outputs of another function.
+    ///
+    /// # Parameters
+    ///
+    /// * `function` - The function to wrap
+    /// * `map` - Closure converting each output of the wrapped function
+    ///
+    /// # Returns
+    ///
+    /// Returns a new MapOutputFunction instance.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - Function type that takes `O1` and returns `O2`
+    pub fn new<F>(function: Arc<dyn Function<Output = O1>>, map: F) -> Self
+    where
+        F: Fn(O1) -> O2 + Send + Sync + 'static,
+    {
+        Self {
+            function,
+            map: Box::new(map),
+        }
+    }
+
+    /// Gets the wrapped function.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the wrapped function.
+    pub fn inner(&self) -> &Arc<dyn Function<Output = O1>> {
+        &self.function
+    }
+}
+
+impl<O1: Send + Sync, O2: Send + Sync> Function for MapOutputFunction<O1, O2> {
+    type Output = O2;
+
+    fn name(&self) -> String {
+        self.function.name()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.function.description()
+    }
+
+    fn inputs(&self) -> Vec<Arg> {
+        self.function.inputs()
+    }
+
+    fn output(&self) -> Option<Arg> {
+        self.function.output()
+    }
+
+    fn call(&self, args: &[Variable]) -> Self::Output {
+        (self.map)(self.function.call(args))
+    }
+
+    fn call_mut(&self, args: &mut [Variable]) -> Self::Output {
+        (self.map)(self.function.call_mut(args))
+    }
+}
+
 #[test]
 fn function_call() {
     use crate::variable::VariableType;
@@ -267,6 +717,155 @@ fn function_call() {
     assert_eq!(c.unwrap(), Some(3.into()));
 }
 
+#[test]
+fn checked_call() {
+    use super::ArgConstraint;
+    use crate::variable::VariableType;
+
+    let func = DynamicFunction::new(
+        "concat",
+        vec![
+            Arg::new("a", VariableType::String),
+            Arg::new("b", VariableType::Let),
+        ],
+        Some(Arg::new("c", VariableType::String)),
+        |args| -> FunctionOutput { Ok(Some(format!("{}{}", args[0], args[1]).into())) },
+    );
+
+    let c = func.call_checked(&["a".into(), 1.into()]);
+    assert_eq!(c.unwrap().unwrap(), Some("a1".into()));
+
+    assert!(matches!(
+        func.call_checked(&["a".into()]),
+        Err(FunctionCallError::ArgumentsCount {
+            expected: 2,
+            actual: 1
+        })
+    ));
+    assert!(matches!(
+        func.call_checked(&[1.into(), 1.into()]),
+        Err(FunctionCallError::ArgumentType { .. })
+    ));
+
+    let func = DynamicFunction::new(
+        "repeat",
+        vec![
+            Arg::new("text", VariableType::String).with_constraint(ArgConstraint::NonEmpty),
+            Arg::new("count", VariableType::I32).with_constraint(ArgConstraint::IntRange(1, 3)),
+        ],
+        Some(Arg::new("result", VariableType::String)),
+        |args| -> FunctionOutput {
+            let count = *args[1].parse_ref::<i32>() as usize;
+            Ok(Some(args[0].parse_ref::<String>().repeat(count).into()))
+        },
+    );
+
+    let result = func.call_checked(&["ab".into(), 2.into()]);
+    assert_eq!(result.unwrap().unwrap(), Some("abab".into()));
+
+    match func.call_checked(&["ab".into(), 4.into()]) {
+        Err(FunctionCallError::ArgumentConstraint { name, constraint }) => {
+            assert_eq!(name, "count");
+            assert_eq!(constraint, ArgConstraint::IntRange(1, 3));
+        }
+        _ => panic!("Expected a constraint error"),
+    }
+    assert!(matches!(
+        func.call_checked(&["".into(), 1.into()]),
+        Err(FunctionCallError::ArgumentConstraint { .. })
+    ));
+}
+
+#[test]
+fn function_description() {
+    use crate::variable::VariableType;
+
+    let func = DynamicFunction::new(
+        "neg",
+        vec![Arg::new("x", VariableType::I32)],
+        Some(Arg::new("y", VariableType::I32)),
+        |args| -> FunctionOutput { Ok(Some((-args[0].parse_ref::<i32>()).into())) },
+    )
+    .with_description("Negates a number");
+
+    assert_eq!(func.description(), Some("Negates a number".to_string()));
+
+    let func: Box<dyn Function<Output = FunctionOutput>> = Box::new(func);
+    assert_eq!(
+        func.to_string(),
+        "# Negates a number\nneg(x: I32) -> y(I32)"
+    );
+}
+
+#[test]
+fn display() {
+    use crate::variable::VariableType;
+
+    let func: Box<dyn Function<Output = FunctionOutput>> = Box::new(DynamicFunction::new(
+        "add",
+        vec![
+            Arg::new("a", VariableType::I32),
+            Arg::new("b", VariableType::I32),
+        ],
+        Some(Arg::new("c", VariableType::I32)),
+        |_| -> FunctionOutput { Ok(None) },
+    ));
+    assert_eq!(func.to_string(), "add(a: I32, b: I32) -> c(I32)");
+    assert_eq!(format!("{func:?}"), func.to_string());
+
+    let func: Box<dyn Function<Output = FunctionOutput>> = Box::new(
+        DynamicFunction::new(
+            "log",
+            vec![
+                Arg::new("message", VariableType::String),
+                Arg::new("level", VariableType::U8).optional(),
+            ],
+            None,
+            |_| -> FunctionOutput { Ok(None) },
+        )
+        .with_description("Writes a message to the log"),
+    );
+    assert_eq!(
+        func.to_string(),
+        "# Writes a message to the log\nlog(message: String, level?: U8) -> void"
+    );
+
+    let func: Box<dyn Function<Output = FunctionOutput>> = Box::new(DynamicFunction::new(
+        "tick",
+        vec![],
+        None,
+        |_| -> FunctionOutput { Ok(None) },
+    ));
+    assert_eq!(func.to_string(), "tick() -> void");
+}
+
+#[test]
+fn function_output_ext() {
+    let output: FunctionOutput = Ok(Some(1.into()));
+    assert_eq!(output.into_variable().unwrap(), Variable::I32(1));
+
+    let output: FunctionOutput = Ok(None);
+    assert!(matches!(
+        output.into_variable(),
+        Err(FunctionOutputError::NoValue)
+    ));
+
+    let output: FunctionOutput = Err("failed".into());
+    assert!(matches!(
+        output.into_variable(),
+        Err(FunctionOutputError::CallFailed(e)) if e.to_string() == "failed"
+    ));
+
+    let output: FunctionOutput = Ok(Some("text".into()));
+    assert_eq!(output.ok_or_null().unwrap(), Variable::from("text"));
+
+    let output: FunctionOutput = Ok(None);
+    assert_eq!(output.ok_or_null().unwrap(), Variable::Null);
+
+    let output: FunctionOutput = Err("failed".into());
+    assert_eq!(output.ok_or_null().unwrap_err().to_string(), "failed");
+}
+
 #[test]
 fn parallel_call() {
     use crate::variable::VariableType;
@@ -308,3 +907,70 @@ fn parallel_call() {
         handle.join().unwrap();
     }
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn stateful_call() {
+    use crate::variable::VariableType;
+
+    let mut total = 0;
+    let mut func = StatefulFunction::new(
+        "accumulate",
+        vec![Arg::new("value", VariableType::I32)],
+        Some(Arg::new("total", VariableType::I32)),
+        move |args| -> FunctionOutput {
+            total += args[0].parse_ref::<i32>();
+            Ok(Some(total.into()))
+        },
+    );
+
+    assert_eq!(func.call_stateful(&[2.into()]).unwrap(), Some(2.into()));
+    assert_eq!(func.call(&[3.into()]).unwrap(), Some(5.into()));
+
+    // Functions without state behave the same with both methods
+    let mut func = DynamicFunction::new("one", vec![], None, |_| -> FunctionOutput {
+        Ok(Some(1.into()))
+    });
+    assert_eq!(func.call_stateful(&[]).unwrap(), func.call(&[]).unwrap());
+}
+
+#[test]
+fn map_output() {
+    use crate::variable::VariableType;
+
+    let func = DynamicFunction::new(
+        "div",
+        vec![
+            Arg::new("a", VariableType::I32),
+            Arg::new("b", VariableType::I32),
+        ],
+        Some(Arg::new("c", VariableType::I32)),
+        |args| -> FunctionOutput {
+            match args[1].parse_ref::<i32>() {
+                0 => Err("Division by zero".into()),
+                b => Ok(Some((args[0].parse_ref::<i32>() / b).into())),
+            }
+        },
+    )
+    .with_description("Divides two numbers");
+
+    let func = MapOutputFunction::new(Arc::new(func), |output: FunctionOutput| {
+        output.map_err(|e| e.to_string())
+    });
+
+    assert_eq!(func.name(), "div");
+    assert_eq!(func.description().as_deref(), Some("Divides two numbers"));
+    assert_eq!(func.inputs().len(), 2);
+    assert_eq!(func.call(&[6.into(), 3.into()]), Ok(Some(2.into())));
+    assert_eq!(
+        func.call(&[6.into(), 0.into()]),
+        Err("Division by zero".to_string())
+    );
+
+    // The mapped function can be registered as a trait object of the new output type
+    let func: Arc<dyn Function<Output = Result<Option<Variable>, String>>> = Arc::new(func);
+    assert_eq!(
+        func.to_string(),
+        "# Divides two numbers\ndiv(a: I32, b: I32) -> c(I32)"
+    );
+}
@@ -48,6 +48,39 @@ macro_rules! function_call {
 	};
 }
 
+/// Macro for convenient request calling with automatic argument conversion.
+///
+/// This macro is the request counterpart of `function_call!`. It converts each argument
+/// to a Variable via `.into()` and calls `call_request` on the given plugin.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use plux_rs::{request_call, Loader, StdInfo, function::FunctionOutput};
+///
+/// let loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+/// // Register managers and load plugins...
+///
+/// let plugin = loader.get_plugins_by_id("my_plugin")[0];
+///
+/// // Call with arguments
+/// let result = request_call!(plugin, "echo", "Hello world");
+///
+/// // Call without arguments
+/// let result = request_call!(plugin, "main");
+/// ```
+#[macro_export]
+macro_rules! request_call {
+	($plugin: expr, $name: expr, $($args:expr), +) => {
+        // Call a request with multiple arguments
+		$plugin.call_request($name, &[$($args.into()), +])
+	};
+	($plugin: expr, $name: expr) => {
+        // Call a request with no arguments
+		$plugin.call_request($name, &[])
+	};
+}
+
 #[test]
 fn run() {
     use crate::{
@@ -1,8 +1,13 @@
-use std::fmt::Display;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::variable::VariableType;
+use crate::{function::Function, utils::RegisterRequestError, variable::VariableType};
 
 /// Represents a function request that plugins must implement.
 ///
@@ -15,6 +20,8 @@ use crate::variable::VariableType;
 /// * `name` - The name of the requested function
 /// * `inputs` - List of input parameter types
 /// * `output` - Optional output type (None for void functions)
+/// * `optional_inputs` - Number of trailing inputs that plugins may omit
+/// * `rest` - Element type of the variadic rest parameter, if the request has one
 ///
 /// # Examples
 ///
@@ -36,7 +43,7 @@ use crate::variable::VariableType;
 ///     None
 /// );
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Request {
     /// The name of the requested function
     pub name: String,
@@ -44,6 +51,12 @@ pub struct Request {
     pub inputs: Vec<VariableType>,
     /// Optional output type (None for void functions)
     pub output: Option<VariableType>,
+    /// Number of trailing inputs that plugins may omit
+    #[serde(default)]
+    pub optional_inputs: usize,
+    /// Element type of the variadic rest parameter, if the request has one
+    #[serde(default)]
+    pub rest: Option<VariableType>,
 }
 
 impl Request {
@@ -84,19 +97,220 @@ impl Request {
             name: name.into(),
             inputs,
             output,
+            optional_inputs: 0,
+            rest: None,
+        }
+    }
+
+    /// Creates a new function request that takes any number of trailing arguments.
+    ///
+    /// The trailing arguments are passed to the function as a single `Variable::List`
+    /// after the fixed inputs, so a plugin implements the request with a function whose
+    /// last input is a `List`, such as a `Vec<&T>` parameter of the `#[function]` macro.
+    /// `VariableType::List` does not carry an element type, so the element type `rest`
+    /// describes the interface but is not checked when the function is registered.
+    ///
+    /// Managers of scripting languages are expected to map the rest list to the native
+    /// variadic arguments of the language. For example, a Lua manager passes the elements
+    /// of the list as trailing values, which the plugin receives with `...`:
+    ///
+    /// ```lua
+    /// function log(level, ...)
+    ///     local messages = { ... }
+    /// end
+    /// ```
+    ///
+    /// Optional inputs cannot be combined with a rest parameter, the fixed inputs of a
+    /// variadic request are always required.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The function name (will be converted to String)
+    /// * `fixed` - Vector of the fixed input parameter types
+    /// * `rest` - Element type of the trailing arguments
+    /// * `output` - Optional output type (None for void functions)
+    ///
+    /// # Returns
+    ///
+    /// Returns a new Request instance.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `S` - Type that can be converted into String
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::function::Request;
+    /// use plux_rs::variable::VariableType;
+    ///
+    /// // log(level, messages...)
+    /// let request = Request::new_variadic(
+    ///     "log",
+    ///     vec![VariableType::String],
+    ///     VariableType::String,
+    ///     None
+    /// );
+    ///
+    /// assert_eq!(request.to_string(), "log(String, ...String) -> void");
+    /// ```
+    pub fn new_variadic<S: Into<String>>(
+        name: S,
+        fixed: Vec<VariableType>,
+        rest: VariableType,
+        output: Option<VariableType>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            inputs: fixed,
+            output,
+            optional_inputs: 0,
+            rest: Some(rest),
+        }
+    }
+
+    /// Marks the trailing inputs of the request as optional.
+    ///
+    /// Plugins implementing the request may omit optional inputs from their functions.
+    /// Variadic requests do not support optional inputs, for them the count stays zero.
+    ///
+    /// # Parameters
+    ///
+    /// * `count` - Number of trailing inputs that plugins may omit
+    ///
+    /// # Returns
+    ///
+    /// Returns the Request with the optional inputs set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::function::Request;
+    /// use plux_rs::variable::VariableType;
+    ///
+    /// // The separator may be omitted by plugins
+    /// let request = Request::new(
+    ///     "join",
+    ///     vec![VariableType::List, VariableType::String],
+    ///     Some(VariableType::String)
+    /// ).with_optional_inputs(1);
+    /// ```
+    pub fn with_optional_inputs(mut self, count: usize) -> Self {
+        self.optional_inputs = match self.rest {
+            Some(_) => 0,
+            None => count.min(self.inputs.len()),
+        };
+        self
+    }
+
+    /// Checks whether a function satisfies the request.
+    ///
+    /// The function must have the same name as the request, and its signature must match
+    /// the one a plugin would need to register the function with
+    /// `LoadPluginContext::register_request`.
+    ///
+    /// # Parameters
+    ///
+    /// * `function` - The function to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the function can implement the request, `false` otherwise.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `O` - Output type of the function
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::function::{Arg, DynamicFunction, FunctionOutput, Request};
+    /// use plux_rs::variable::VariableType;
+    ///
+    /// let request = Request::new("double", vec![VariableType::I32], Some(VariableType::I32));
+    ///
+    /// let double = DynamicFunction::new(
+    ///     "double",
+    ///     vec![Arg::new("x", VariableType::I32)],
+    ///     Some(Arg::new("result", VariableType::I32)),
+    ///     |args| -> FunctionOutput { Ok(Some((args[0].parse_ref::<i32>() * 2).into())) },
+    /// );
+    ///
+    /// assert!(request.matches(&double));
+    /// ```
+    pub fn matches<O: Send + Sync>(&self, function: &dyn Function<Output = O>) -> bool {
+        self.name == function.name() && self.check_signature(function).is_ok()
+    }
+
+    /// Checks whether the signature of a function matches the request.
+    ///
+    /// Types are matched with `VariableType::is_compatible_with`, so `Let` acts as a wildcard.
+    /// The function may omit optional inputs of the request and declare extra
+    /// optional arguments of its own. Arguments at the positions of optional inputs
+    /// must be optional too, since callers may leave them out. For a variadic request the function must declare
+    /// the fixed inputs followed by exactly one `List` input for the rest arguments.
+    /// The name of the function is not checked.
+    ///
+    /// # Parameters
+    ///
+    /// * `function` - The function to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), RegisterRequestError>` with the first mismatch found.
+    pub(crate) fn check_signature<O: Send + Sync>(
+        &self,
+        function: &dyn Function<Output = O>,
+    ) -> Result<(), RegisterRequestError> {
+        // Check arguments positionally, the rest arguments are passed as a trailing list.
+        let expected_inputs: Vec<_> = match self.rest {
+            Some(_) => self.inputs.iter().chain([&VariableType::List]).collect(),
+            None => self.inputs.iter().collect(),
+        };
+        let inputs = function.inputs();
+        let required = expected_inputs.len().saturating_sub(self.optional_inputs);
+        if let Some(index) = (0..expected_inputs.len().max(inputs.len())).find(|&i| {
+            match (expected_inputs.get(i), inputs.get(i)) {
+                (Some(expected), Some(arg)) => {
+                    !expected.is_compatible_with(&arg.ty) || (i >= required && !arg.optional)
+                }
+                (Some(_), None) => i < required,
+                (None, Some(arg)) => self.rest.is_some() || !arg.optional,
+                (None, None) => false,
+            }
+        }) {
+            return Err(RegisterRequestError::ArgumentsIncorrectly(index));
+        }
+
+        let is_output_compatible = match (self.output, function.output()) {
+            (Some(expected), Some(arg)) => expected.is_compatible_with(&arg.ty),
+            (None, None) => true,
+            _ => false,
+        };
+
+        match is_output_compatible {
+            true => Ok(()),
+            false => Err(RegisterRequestError::OutputIncorrectly),
         }
     }
 }
 
 impl Display for Request {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "{}({}) -> {}",
             self.name,
             self.inputs
                 .iter()
-                .map(|x| format!("{x}"))
+                .enumerate()
+                .map(
+                    |(i, x)| match i >= self.inputs.len().saturating_sub(self.optional_inputs) {
+                        true => format!("{x}?"),
+                        false => format!("{x}"),
+                    }
+                )
+                .chain(self.rest.map(|x| format!("...{x}")))
                 .collect::<Vec<_>>()
                 .join(", "),
             match self.output {
@@ -106,3 +320,155 @@ impl Display for Request {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        function::{Arg, DynamicFunction, FunctionOutput, Request},
+        utils::RegisterRequestError,
+        variable::VariableType,
+    };
+
+    fn function(name: &str, inputs: Vec<Arg>, output: Option<Arg>) -> DynamicFunction {
+        DynamicFunction::new(name, inputs, output, |_| -> FunctionOutput { Ok(None) })
+    }
+
+    #[test]
+    fn matches() {
+        let request = Request::new("add", vec![VariableType::I32; 2], Some(VariableType::I32));
+        let inputs = vec![
+            Arg::new("a", VariableType::I32),
+            Arg::new("b", VariableType::I32),
+        ];
+        let output = Some(Arg::new("c", VariableType::I32));
+
+        assert!(request.matches(&function("add", inputs.clone(), output.clone())));
+        assert!(!request.matches(&function("sub", inputs.clone(), output.clone())));
+        assert!(!request.matches(&function("add", inputs.clone(), None)));
+        assert!(!request.matches(&function("add", inputs[..1].to_vec(), output.clone())));
+
+        // Let acts as a wildcard
+        let inputs = vec![
+            Arg::new("a", VariableType::Let),
+            Arg::new("b", VariableType::I32),
+        ];
+        assert!(request.matches(&function("add", inputs, output)));
+    }
+
+    #[test]
+    fn matches_optional_inputs() {
+        let request = Request::new(
+            "join",
+            vec![VariableType::List, VariableType::String],
+            Some(VariableType::String),
+        )
+        .with_optional_inputs(1);
+        let output = Some(Arg::new("result", VariableType::String));
+
+        let inputs = vec![Arg::new("list", VariableType::List)];
+        assert!(request.matches(&function("join", inputs.clone(), output.clone())));
+
+        // An argument at an optional position can be left out by the caller
+        let mut required = inputs.clone();
+        required.push(Arg::new("separator", VariableType::String));
+        assert!(matches!(
+            request.check_signature(&function("join", required, output.clone())),
+            Err(RegisterRequestError::ArgumentsIncorrectly(1))
+        ));
+
+        let mut extra = inputs.clone();
+        extra.push(Arg::new("separator", VariableType::String).optional());
+        extra.push(Arg::new("limit", VariableType::I32).optional());
+        assert!(request.matches(&function("join", extra.clone(), output.clone())));
+
+        extra.push(Arg::new("required", VariableType::I32));
+        assert!(!request.matches(&function("join", extra, output)));
+    }
+
+    #[test]
+    fn matches_variadic() {
+        let request = Request::new_variadic(
+            "log",
+            vec![VariableType::String],
+            VariableType::String,
+            None,
+        );
+        let inputs = vec![
+            Arg::new("level", VariableType::String),
+            Arg::new("messages", VariableType::List),
+        ];
+
+        assert!(request.matches(&function("log", inputs.clone(), None)));
+        assert!(!request.matches(&function("log", inputs[..1].to_vec(), None)));
+
+        let result = request.check_signature(&function(
+            "log",
+            vec![
+                Arg::new("level", VariableType::String),
+                Arg::new("messages", VariableType::String),
+            ],
+            None,
+        ));
+        assert!(matches!(
+            result,
+            Err(RegisterRequestError::ArgumentsIncorrectly(1))
+        ));
+
+        // Extra optional arguments would be ambiguous with the rest arguments
+        let mut extra = inputs.clone();
+        extra.push(Arg::new("limit", VariableType::I32).optional());
+        assert!(!request.matches(&function("log", extra, None)));
+
+        assert_eq!(request.with_optional_inputs(1).optional_inputs, 0);
+    }
+
+    #[test]
+    fn display() {
+        let request = Request::new(
+            "echo",
+            vec![VariableType::String],
+            Some(VariableType::String),
+        );
+        assert_eq!(request.to_string(), "echo(String) -> String");
+
+        let request = Request::new("log", vec![VariableType::String, VariableType::I32], None)
+            .with_optional_inputs(1);
+        assert_eq!(request.to_string(), "log(String, I32?) -> void");
+
+        let request = Request::new_variadic(
+            "sum",
+            vec![VariableType::String],
+            VariableType::I32,
+            Some(VariableType::I64),
+        );
+        assert_eq!(request.to_string(), "sum(String, ...I32) -> I64");
+
+        let request = Request::new_variadic("print", vec![], VariableType::String, None);
+        assert_eq!(request.to_string(), "print(...String) -> void");
+    }
+
+    #[test]
+    fn check_signature() {
+        let request = Request::new("log", vec![VariableType::String], None);
+
+        let result = request.check_signature(&function(
+            "log",
+            vec![Arg::new("message", VariableType::I32)],
+            None,
+        ));
+        assert!(matches!(
+            result,
+            Err(RegisterRequestError::ArgumentsIncorrectly(0))
+        ));
+
+        let result = request.check_signature(&function(
+            "log",
+            vec![Arg::new("message", VariableType::String)],
+            Some(Arg::new("result", VariableType::Bool)),
+        ));
+        assert!(matches!(
+            result,
+            Err(RegisterRequestError::OutputIncorrectly)
+        ));
+    }
+}
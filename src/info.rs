@@ -2,6 +2,8 @@ use std::{fmt::Display, path::PathBuf};
 
 use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "json")]
+use serde_json::{Map, Value};
 
 use crate::{Bundle, Plugin};
 
@@ -20,7 +22,7 @@ use crate::{Bundle, Plugin};
 /// * `bundle` - Bundle metadata (id, version, format)
 /// * `info` - Dependency and configuration information
 pub struct PluginInfo<I: Info> {
-    /// Filesystem path to the plugin
+    /// Canonical filesystem path to the plugin
     pub path: PathBuf,
     /// Bundle metadata for the plugin
     pub bundle: Bundle,
@@ -39,6 +41,10 @@ pub struct PluginInfo<I: Info> {
 /// * `depends` - Returns the list of required dependencies
 /// * `optional_depends` - Returns the list of optional dependencies
 ///
+/// # Provided Methods
+///
+/// * `provides` - Returns the features this plugin provides to its dependents
+///
 /// # Example
 ///
 /// ```rust
@@ -77,6 +83,18 @@ pub trait Info: Send + Sync {
     ///
     /// Returns a reference to a vector of optional dependencies.
     fn optional_depends(&self) -> &Vec<Depend>;
+
+    /// Returns the features this plugin provides.
+    ///
+    /// Plugins depending on this one may require some of these features with
+    /// `Depend::features`. Default implementation provides no features.
+    ///
+    /// # Returns
+    ///
+    /// Returns a slice of feature names.
+    fn provides(&self) -> &[String] {
+        &[]
+    }
 }
 
 /// Represents a dependency on another plugin.
@@ -88,6 +106,7 @@ pub trait Info: Send + Sync {
 ///
 /// * `id` - The identifier of the required plugin
 /// * `version` - Version requirement specifying acceptable versions
+/// * `features` - Features the required plugin must provide
 ///
 /// # Examples
 ///
@@ -107,6 +126,9 @@ pub struct Depend {
     pub id: String,
     /// Version requirement for the dependency
     pub version: VersionReq,
+    /// Features the required plugin must provide, see `Info::provides`
+    #[serde(default)]
+    pub features: Vec<String>,
 }
 
 /// Standard implementation of the Info trait.
@@ -138,6 +160,50 @@ pub struct StdInfo {
     pub optional_depends: Vec<Depend>,
 }
 
+/// Implementation of the Info trait carrying arbitrary metadata.
+///
+/// JsonInfo keeps the same dependency lists as `StdInfo` plus a map of extra fields,
+/// such as the author, description or capabilities of the plugin. When deserialized,
+/// every field other than `depends` and `optional_depends` is collected into `meta`,
+/// so managers can parse it directly from a JSON plugin manifest.
+///
+/// # Fields
+///
+/// * `depends` - List of plugins required for this plugin to function
+/// * `optional_depends` - List of plugins that enhance functionality but are not required
+/// * `meta` - Extra metadata fields of the plugin
+///
+/// # Examples
+///
+/// ```rust
+/// use plux_rs::JsonInfo;
+///
+/// let info: JsonInfo = serde_json::from_str(
+///     r#"{
+///         "depends": [{ "id": "core", "version": "1.0" }],
+///         "author": "Bleyn",
+///         "capabilities": ["draw"]
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(info.depends[0].id, "core");
+/// assert_eq!(info.get_meta("author"), Some(&serde_json::json!("Bleyn")));
+/// ```
+#[cfg(feature = "json")]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonInfo {
+    /// Required dependencies for this plugin
+    #[serde(default)]
+    pub depends: Vec<Depend>,
+    /// Optional dependencies that enhance functionality
+    #[serde(default)]
+    pub optional_depends: Vec<Depend>,
+    /// Extra metadata fields of the plugin
+    #[serde(flatten)]
+    pub meta: Map<String, Value>,
+}
+
 impl Depend {
     /// Creates a new dependency specification.
     ///
@@ -159,7 +225,44 @@ impl Depend {
     /// let dependency = Depend::new("logger".to_string(), VersionReq::parse("1.0").unwrap());
     /// ```
     pub const fn new(name: String, version: VersionReq) -> Self {
-        Self { id: name, version }
+        Self {
+            id: name,
+            version,
+            features: vec![],
+        }
+    }
+
+    /// Sets the features the required plugin must provide.
+    ///
+    /// When the dependency is resolved, the plugin it resolves to must list all of
+    /// these features in `Info::provides`, otherwise loading fails with
+    /// `LoadPluginError::MissingFeature`.
+    ///
+    /// # Parameters
+    ///
+    /// * `features` - Names of the required features
+    ///
+    /// # Returns
+    ///
+    /// Returns the Depend with the features set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::Depend;
+    /// use semver::VersionReq;
+    ///
+    /// let dependency = Depend::new("logger".to_string(), VersionReq::parse("1.0").unwrap())
+    ///     .with_features(["json"]);
+    /// assert_eq!(dependency.features, vec!["json".to_string()]);
+    /// ```
+    pub fn with_features<F, S>(mut self, features: F) -> Self
+    where
+        F: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.features = features.into_iter().map(Into::into).collect();
+        self
     }
 }
 
@@ -209,6 +312,48 @@ impl StdInfo {
             optional_depends: vec![],
         }
     }
+
+    /// Adds a required dependency.
+    ///
+    /// # Parameters
+    ///
+    /// * `depend` - The dependency to add
+    ///
+    /// # Returns
+    ///
+    /// Returns the StdInfo with the dependency added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::{Depend, StdInfo};
+    /// use semver::VersionReq;
+    ///
+    /// let info = StdInfo::new()
+    ///     .with_depend(Depend::new("core".to_string(), VersionReq::parse("1.0").unwrap()))
+    ///     .with_optional_depend(Depend::new("ui".to_string(), VersionReq::parse("2.*").unwrap()));
+    ///
+    /// assert_eq!(info.depends.len(), 1);
+    /// assert_eq!(info.optional_depends.len(), 1);
+    /// ```
+    pub fn with_depend(mut self, depend: Depend) -> Self {
+        self.depends.push(depend);
+        self
+    }
+
+    /// Adds an optional dependency.
+    ///
+    /// # Parameters
+    ///
+    /// * `depend` - The dependency to add
+    ///
+    /// # Returns
+    ///
+    /// Returns the StdInfo with the optional dependency added.
+    pub fn with_optional_depend(mut self, depend: Depend) -> Self {
+        self.optional_depends.push(depend);
+        self
+    }
 }
 
 impl Info for StdInfo {
@@ -221,6 +366,98 @@ impl Info for StdInfo {
     }
 }
 
+#[cfg(feature = "json")]
+impl JsonInfo {
+    /// Creates a new JsonInfo instance with no dependencies and no metadata.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new JsonInfo with empty dependency lists and metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a required dependency.
+    ///
+    /// # Parameters
+    ///
+    /// * `depend` - The dependency to add
+    ///
+    /// # Returns
+    ///
+    /// Returns the JsonInfo with the dependency added.
+    pub fn with_depend(mut self, depend: Depend) -> Self {
+        self.depends.push(depend);
+        self
+    }
+
+    /// Adds an optional dependency.
+    ///
+    /// # Parameters
+    ///
+    /// * `depend` - The dependency to add
+    ///
+    /// # Returns
+    ///
+    /// Returns the JsonInfo with the optional dependency added.
+    pub fn with_optional_depend(mut self, depend: Depend) -> Self {
+        self.optional_depends.push(depend);
+        self
+    }
+
+    /// Adds a metadata field, replacing the previous value with the same key.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - Name of the field
+    /// * `value` - Value of the field
+    ///
+    /// # Returns
+    ///
+    /// Returns the JsonInfo with the field added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::JsonInfo;
+    ///
+    /// let info = JsonInfo::new().with_meta("author", "Bleyn");
+    /// assert_eq!(info.get_meta("author").and_then(|v| v.as_str()), Some("Bleyn"));
+    /// ```
+    pub fn with_meta<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        self.meta.insert(key.into(), value.into());
+        self
+    }
+
+    /// Gets a metadata field by key.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - Name of the field
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Value>` containing the field value if it exists.
+    pub fn get_meta(&self, key: &str) -> Option<&Value> {
+        self.meta.get(key)
+    }
+}
+
+#[cfg(feature = "json")]
+impl Info for JsonInfo {
+    fn depends(&self) -> &Vec<Depend> {
+        &self.depends
+    }
+
+    fn optional_depends(&self) -> &Vec<Depend> {
+        &self.optional_depends
+    }
+}
+
 impl Display for StdInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -240,3 +477,36 @@ impl Display for StdInfo {
         )
     }
 }
+
+#[cfg(feature = "json")]
+#[test]
+fn json_info() {
+    use serde_json::json;
+
+    let info: JsonInfo = serde_json::from_value(json!({
+        "depends": [{ "id": "brush", "version": "1.0" }],
+        "author": "Bleyn",
+        "capabilities": ["draw", "erase"],
+    }))
+    .unwrap();
+
+    assert_eq!(
+        info.depends(),
+        &vec![Depend::new("brush".into(), "1.0".parse().unwrap())]
+    );
+    assert!(info.optional_depends().is_empty());
+    assert_eq!(info.get_meta("author"), Some(&json!("Bleyn")));
+    assert_eq!(
+        info.get_meta("capabilities"),
+        Some(&json!(["draw", "erase"]))
+    );
+    assert_eq!(info.get_meta("license"), None);
+
+    // Metadata is flattened back next to the dependencies
+    let value = serde_json::to_value(&info).unwrap();
+    assert_eq!(value["author"], json!("Bleyn"));
+    assert_eq!(serde_json::from_value::<JsonInfo>(value).unwrap(), info);
+
+    let info = JsonInfo::new().with_meta("version", 2);
+    assert_eq!(info.get_meta("version").and_then(Value::as_u64), Some(2));
+}
@@ -45,7 +45,14 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## `no_std` Support
+//!
+//! With `default-features = false` the crate builds without `std`, using only `alloc`.
+//! In this mode only the type system is available: the `variable` and `function` modules
+//! along with the errors they use. The loader, managers and plugins require the `std` feature.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![doc(html_logo_url = "https://example.com/logo.png")]
 #![doc(html_favicon_url = "https://example.com/favicon.ico")]
@@ -54,6 +61,7 @@
 ///
 /// This module provides the context types that are passed to plugin managers
 /// during various lifecycle events such as registration and loading.
+#[cfg(feature = "std")]
 pub mod context;
 
 /// Function and request definitions for the plugin system.
@@ -74,23 +82,71 @@ pub mod utils;
 /// the data types that can be passed between plugins and the host application.
 pub mod variable;
 
+/// Plugin bundle identification.
+///
+/// This module defines the Bundle type, which identifies a plugin by its ID, version
+/// and format, along with serde helpers for writing bundles as filenames.
+#[cfg(feature = "std")]
+pub mod bundle;
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 mod api;
-mod bundle;
+#[cfg(feature = "async")]
+mod async_manager;
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "std")]
+mod event;
+#[cfg(feature = "std")]
 mod info;
+#[cfg(feature = "std")]
 mod loader;
+#[cfg(feature = "std")]
 mod manager;
+#[cfg(feature = "std")]
 mod plugin;
+#[cfg(feature = "std")]
+mod policy;
+#[cfg(feature = "std")]
+mod schema;
+#[cfg(feature = "std")]
+mod sync_loader;
+#[cfg(feature = "watch")]
+mod watch;
 
+#[cfg(feature = "std")]
 pub use api::*;
+#[cfg(feature = "async")]
+pub use async_manager::*;
+#[cfg(feature = "std")]
 pub use bundle::*;
+#[cfg(feature = "config")]
+pub use config::*;
+#[cfg(feature = "std")]
 pub use context::*;
+#[cfg(feature = "std")]
+pub use event::*;
+#[cfg(feature = "std")]
 pub use info::*;
+#[cfg(feature = "std")]
 pub use loader::*;
+#[cfg(feature = "std")]
 pub use manager::*;
+#[cfg(feature = "std")]
 pub use plugin::*;
+#[cfg(feature = "std")]
+pub use policy::*;
+#[cfg(feature = "std")]
+pub use schema::*;
+#[cfg(feature = "std")]
+pub use sync_loader::*;
+#[cfg(feature = "watch")]
+pub use watch::{WatchHandle, WatchReport};
 
+use alloc::{sync::Arc, vec::Vec};
 use function::{Function, Request};
-use std::sync::Arc;
 
 /// Registry of functions that can be called by plugins.
 /// This type alias represents a collection of functions exposed by the host application
@@ -175,8 +231,16 @@ pub type Requests = Vec<Request>;
 /// ### Notes
 ///
 /// - The first parameter can be used for context (use `_` if not needed)
-/// - Supported parameter types: primitive types, `&T` and `Vec<&T>`
+/// - Supported parameter types: primitive types, `&T`, `&mut T` and `Vec<&T>`
+/// - Raw binary data is passed as `&[u8]` and returned as `Vec<u8>` (`Variable::Bytes`)
+/// - Fallible functions may return `Result<T, E>`, where `Err(e)` becomes the function error
+/// - Optional parameters are declared as `Option<&T>`, where `Variable::Null` becomes `None`
+///   (they must come after all required parameters)
+/// - Parameters declared as `&mut T` are modified in place when called with `Function::call_mut`
 /// - The function will be available to plugins under its Rust name by default
+/// - Use `#[function(name = "...", description = "...")]` to override the name and add a description
+/// - Use `#[function(export = "...")]` to set an identifier-safe registry name
+///   while keeping the Rust constructor name (cannot be combined with `name`)
 #[cfg(feature = "derive")]
 pub use plux_codegen::function;
 
@@ -184,15 +248,32 @@ pub use plux_codegen::function;
 /// This module provides convenient access to the most commonly used types when
 /// implementing plugins.
 pub mod prelude {
+    #[cfg(feature = "std")]
     pub use crate::LoaderContext;
+    #[cfg(feature = "std")]
     pub use crate::api::*;
+    #[cfg(feature = "std")]
     pub use crate::bundle::*;
+    #[cfg(feature = "std")]
+    pub use crate::event::*;
     pub use crate::function::*;
+    #[cfg(feature = "std")]
     pub use crate::info::{Depend, Info, StdInfo};
+    #[cfg(feature = "json")]
+    pub use crate::info::JsonInfo;
+    #[cfg(feature = "std")]
     pub use crate::loader::*;
+    #[cfg(feature = "std")]
     pub use crate::plugin::*;
+    #[cfg(feature = "std")]
+    pub use crate::policy::*;
+    #[cfg(feature = "std")]
+    pub use crate::schema::*;
+    #[cfg(feature = "std")]
+    pub use crate::sync_loader::*;
     pub use crate::utils::*;
     pub use crate::variable::*;
+    pub use crate::{function_call, request_call};
 
     #[cfg(feature = "derive")]
     pub use plux_codegen::*;
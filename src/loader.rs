@@ -1,17 +1,39 @@
-use rayon::prelude::{
-    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
-    IntoParallelRefMutIterator, ParallelIterator,
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    marker::PhantomData,
+    path::Path,
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use rayon::{
+    ThreadPool, ThreadPoolBuilder,
+    prelude::{
+        IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+        IntoParallelRefMutIterator, ParallelIterator,
+    },
 };
-use semver::Version;
+use semver::{Version, VersionReq};
 
 use crate::{
-    Bundle, Info, LoaderContext, Manager, Plugin, PluginInfo, Registry, Requests,
+    Bundle, FunctionSchema, Info, LoadPolicy, LoaderContext, Manager, Plugin, PluginEvent,
+    PluginInfo, PluginInterface, PluginSchema, Registry, Requests,
+    function::fill_optional_args,
     utils::{
-        LoadPluginError, PluginCallRequestError, Ptr, RegisterManagerError, RegisterPluginError,
-        StopLoaderError, UnloadPluginError, UnregisterManagerError, UnregisterPluginError,
+        BuildLoaderError, LoadPluginError, LoadPluginsError, MergeLoaderError,
+        PluginCallFunctionError, PluginCallRequestError, Ptr, RegisterManagerError,
+        RegisterPluginError, ReloadPluginError, StopLoaderError, SwapPluginError,
+        UnloadPluginError, UnregisterManagerError, UnregisterPluginError, logging::warn,
     },
     variable::Variable,
 };
+#[cfg(feature = "watch")]
+use crate::{WatchHandle, WatchReport, utils::WatchError, watch::PluginWatch};
 
 /// Main loader for plugins and managers.
 ///
@@ -31,6 +53,11 @@ use crate::{
 /// * `registry` - Registry of functions available to plugins
 /// * `requests` - Collection of function requests from the host for plugins
 /// * `plugins` - Collection of loaded plugins
+/// * `thread_pool` - Optional thread pool used by the parallel methods
+/// * `load_metrics` - Optional load durations of plugins, recorded when enabled
+/// * `default_policy` - Resource policy passed to managers when loading plugins
+/// * `event_senders` - Channels of the subscribers to plugin events
+/// * `watch` - Queued file system events when watching plugin files
 ///
 /// # Example
 ///
@@ -51,6 +78,40 @@ pub struct Loader<'a, O: Send + Sync, I: Info> {
     pub(crate) registry: Registry<O>,
     pub(crate) requests: Requests,
     pub(crate) plugins: Vec<Plugin<'a, O, I>>,
+    pub(crate) thread_pool: Option<Arc<ThreadPool>>,
+    pub(crate) load_metrics: Option<Mutex<HashMap<Bundle, Duration>>>,
+    pub(crate) default_policy: LoadPolicy,
+    pub(crate) event_senders: Mutex<Vec<Sender<PluginEvent>>>,
+    #[cfg(feature = "watch")]
+    pub(crate) watch: Option<PluginWatch>,
+}
+
+/// Builder for configuring a plugin loader.
+///
+/// LoaderBuilder allows setting up a [`Loader`] with a dedicated rayon thread pool
+/// for its parallel (`par_*`) methods. When no thread pool is configured, the parallel
+/// methods run in the global rayon pool.
+///
+/// # Type Parameters
+///
+/// * `'a` - Lifetime parameter for references within the loader
+/// * `O` - Output type for plugin functions (must implement Send + Sync)
+/// * `I` - Plugin information type (must implement Info trait)
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::prelude::*;
+///
+/// let loader: Loader<'_, FunctionOutput, StdInfo> =
+///     Loader::builder().num_threads(2).build().unwrap();
+///
+/// assert_eq!(loader.thread_pool().unwrap().current_num_threads(), 2);
+/// ```
+pub struct LoaderBuilder<'a, O: Send + Sync, I: Info> {
+    thread_pool: Option<Arc<ThreadPool>>,
+    num_threads: Option<usize>,
+    marker: PhantomData<Loader<'a, O, I>>,
 }
 
 impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
@@ -67,9 +128,128 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
             registry: vec![],
             requests: vec![],
             plugins: vec![],
+            thread_pool: None,
+            load_metrics: None,
+            default_policy: LoadPolicy::new(),
+            event_senders: Mutex::new(vec![]),
+            #[cfg(feature = "watch")]
+            watch: None,
         }
     }
 
+    /// Creates a new plugin loader instance with pre-allocated storage.
+    ///
+    /// Works like `new`, but reserves space for the given number of plugins and managers
+    /// to avoid reallocations when registering many of them at once.
+    ///
+    /// # Parameters
+    ///
+    /// * `plugins` - Number of plugins to reserve space for
+    /// * `managers` - Number of managers to reserve space for
+    ///
+    /// # Returns
+    ///
+    /// Returns a new Loader instance ready for configuration.
+    pub fn with_capacity(plugins: usize, managers: usize) -> Self {
+        let mut loader = Self::new();
+        loader.managers.reserve(managers);
+        loader.plugins.reserve(plugins);
+        loader
+    }
+
+    /// Creates a builder for configuring a plugin loader.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new LoaderBuilder with the default configuration.
+    pub const fn builder() -> LoaderBuilder<'a, O, I> {
+        LoaderBuilder::new()
+    }
+
+    /// Gets the thread pool used by the parallel methods.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&ThreadPool>` containing the thread pool if one was configured,
+    /// or `None` if the global rayon pool is used.
+    pub fn thread_pool(&self) -> Option<&ThreadPool> {
+        self.thread_pool.as_deref()
+    }
+
+    /// Gets the resource policy passed to managers when loading plugins.
+    ///
+    /// The policy is configured with `LoaderContext::set_default_policy`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the default load policy.
+    pub const fn default_policy(&self) -> &LoadPolicy {
+        &self.default_policy
+    }
+
+    /// Enables recording of plugin load durations.
+    ///
+    /// Once enabled, the wall-clock duration of every plugin load is recorded
+    /// and can be retrieved with `load_metrics`. Recording is disabled by default.
+    pub fn enable_load_metrics(&mut self) {
+        self.load_metrics.get_or_insert_with(Default::default);
+    }
+
+    /// Gets the recorded plugin load durations.
+    ///
+    /// The duration of a plugin covers the manager's load call and does not include
+    /// loading its dependencies. Reloading a plugin overwrites its previous duration.
+    ///
+    /// # Returns
+    ///
+    /// Returns `HashMap<Bundle, Duration>` containing a snapshot of the load duration
+    /// of each plugin loaded since the metrics were enabled, or an empty map if disabled.
+    pub fn load_metrics(&self) -> HashMap<Bundle, Duration> {
+        self.load_metrics
+            .as_ref()
+            .map(|metrics| metrics.lock().unwrap().clone())
+            .unwrap_or_default()
+    }
+
+    /// Subscribes to the events emitted by plugins.
+    ///
+    /// Each call creates a new channel, and every event emitted by a plugin with `Api::emit`
+    /// after the call is sent to all subscribed channels. Dropping the receiver unsubscribes it.
+    ///
+    /// Events are buffered in the channel until received, so emitting never blocks
+    /// the plugin. Events emitted from the same thread are received in the order they
+    /// were emitted, while events emitted from different threads may be interleaved.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Receiver<PluginEvent>` receiving the events emitted from now on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::prelude::*;
+    ///
+    /// let loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+    /// let events = loader.events();
+    ///
+    /// // No plugin has emitted anything yet
+    /// assert!(events.try_recv().is_err());
+    /// ```
+    pub fn events(&self) -> Receiver<PluginEvent> {
+        let (sender, receiver) = mpsc::channel();
+        self.event_senders.lock().unwrap().push(sender);
+        receiver
+    }
+
+    // Sends an event emitted by a plugin to all subscribers
+    pub(crate) fn emit_event(&self, event: PluginEvent) {
+        // Subscribers whose receiver has been dropped are removed
+        self.event_senders
+            .lock()
+            .unwrap()
+            .retain(|sender| sender.send(event.clone()).is_ok());
+    }
+
     /// Provides access to the loader context for configuration.
     ///
     /// This method creates a context that allows registering managers, functions, and requests
@@ -102,18 +282,75 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         f(LoaderContext::new(self))
     }
 
+    /// Provides access to the loader context for asynchronous configuration.
+    ///
+    /// Works like `context`, but the closure returns a future, so setup code can `.await`
+    /// inside it, e.g. to initialize a manager before registering it. The context is
+    /// `Send`, so the future can be spawned on a multi-threaded runtime when the loader
+    /// is `Send` itself.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - Closure that receives the loader context and returns a future
+    ///
+    /// # Returns
+    ///
+    /// Returns the output of the future returned by the closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use plux_rs::{Loader, StdInfo};
+    ///
+    /// # async fn setup() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut loader = Loader::<'_, (), StdInfo>::new();
+    /// loader
+    ///     .context_async(|mut ctx| async move {
+    ///         // Await the initialization, then register managers, functions, and requests here
+    ///         Ok::<(), Box<dyn std::error::Error>>(())
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn context_async<'b, F, Fut, R>(&'b mut self, f: F) -> R
+    where
+        F: FnOnce(LoaderContext<'a, 'b, O, I>) -> Fut,
+        Fut: Future<Output = R>,
+    {
+        f(LoaderContext::new(self)).await
+    }
+
     /// Stops the loader and cleans up all resources.
     ///
     /// This method unloads all plugins and unregisters all managers in the correct order,
-    /// ensuring proper cleanup of resources.
+    /// ensuring proper cleanup of resources. The teardown is best effort: a failure of one
+    /// plugin or manager does not stop the others from being unloaded and unregistered.
+    ///
+    /// Stopping is idempotent, calling `stop` on a stopped loader does nothing. The loader
+    /// is also stopped when dropped, where errors are logged as warnings instead of panicking,
+    /// or printed to stderr if the `log` feature is disabled.
     ///
     /// # Returns
     ///
     /// Returns `Result<(), StopLoaderError>` indicating success or failure of the shutdown process.
+    /// On failure the error contains every plugin and manager error that occurred.
     pub fn stop(&mut self) -> Result<(), StopLoaderError> {
-        private_loader::stop_plugins(self)?;
-        private_loader::stop_managers(self)?;
-        Ok(())
+        private_loader::stop(self)
+    }
+
+    /// Unloads all plugins while keeping them and the managers registered.
+    ///
+    /// Plugins are unloaded in dependency order, dependents first. Unlike `stop`,
+    /// the loader stays usable and the plugins can be loaded again afterwards.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), StopLoaderError>` indicating success or failure.
+    /// If some plugins fail to unload, the others are still unloaded.
+    pub fn unload_all_plugins(&mut self) -> Result<(), StopLoaderError> {
+        private_loader::unload_all_plugins(self)
     }
 
     /// Registers a plugin manager with the loader.
@@ -128,7 +365,9 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     /// # Returns
     ///
     /// Returns `Result<(), RegisterManagerError>` indicating success or failure.
-    /// Fails if a manager with the same format is already registered.
+    /// Fails if any of the manager formats is already handled by a registered manager
+    /// with the same priority. Managers with different priorities may share a format,
+    /// in which case plugins of that format go to the manager with the highest priority.
     ///
     /// # Type Parameters
     ///
@@ -166,8 +405,9 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
 
     /// Registers multiple plugin managers with the loader.
     ///
-    /// This method registers a collection of managers in sequence.
-    /// Stops at the first error encountered.
+    /// This method registers a collection of managers in sequence. Registration is
+    /// all-or-nothing: if a manager fails to register, the managers already registered
+    /// by this call are unregistered again in reverse order.
     ///
     /// # Parameters
     ///
@@ -176,21 +416,18 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     /// # Returns
     ///
     /// Returns `Result<(), RegisterManagerError>` indicating success or failure.
+    /// On failure the error of the first manager that failed to register is returned.
     pub fn register_managers<M>(&mut self, managers: M) -> Result<(), RegisterManagerError>
     where
         M: IntoIterator<Item = Box<dyn Manager<'a, O, I>>>,
     {
-        managers
-            .into_iter()
-            .try_for_each(|manager| private_loader::register_manager(self, manager))?;
-
-        Ok(())
+        private_loader::register_managers(self, managers)
     }
 
     /// Registers multiple plugin managers in parallel.
     ///
-    /// This method registers a collection of managers concurrently using parallel processing.
-    /// More efficient for large numbers of managers.
+    /// This method collects the managers using parallel processing and then registers
+    /// them in sequence, since each registration mutates the loader.
     ///
     /// # Parameters
     ///
@@ -203,12 +440,11 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     where
         M: IntoParallelIterator<Item = Box<dyn Manager<'a, O, I>>>,
     {
-        let this = Ptr::new(self);
-        managers.into_par_iter().try_for_each(move |manager| {
-            private_loader::register_manager(this.as_mut(), manager)
-        })?;
-
-        Ok(())
+        // Managers are collected in parallel, but registered one by one,
+        // since registration mutates the loader
+        let managers = managers.into_par_iter();
+        let managers: Vec<_> = private_loader::install(&self.thread_pool, || managers.collect());
+        self.register_managers(managers)
     }
 
     /// Unregisters a plugin manager from the loader.
@@ -224,14 +460,32 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Result<(), UnregisterManagerError>` indicating success or failure.
     pub fn unregister_manager(&mut self, format: &str) -> Result<(), UnregisterManagerError> {
+        let index = private_loader::find_manager(&self.managers, format)
+            .ok_or(UnregisterManagerError::NotFound)?;
+
+        private_loader::unregister_manager(self, index)
+    }
+
+    /// Unregisters a plugin manager by its concrete type.
+    ///
+    /// This method removes the manager of type `M` from the loader, first unloading any plugins
+    /// associated with that manager.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), UnregisterManagerError>` indicating success or failure.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - Type of the manager to unregister
+    pub fn unregister_manager_by_type<M>(&mut self) -> Result<(), UnregisterManagerError>
+    where
+        M: Manager<'a, O, I> + 'static,
+    {
         let index = self
             .managers
             .iter()
-            .enumerate()
-            .find_map(|(i, manager)| match manager.format() == format {
-                true => Some(i),
-                false => None,
-            })
+            .position(|manager| manager.as_ref().as_any().is::<M>())
             .ok_or(UnregisterManagerError::NotFound)?;
 
         private_loader::unregister_manager(self, index)
@@ -261,10 +515,52 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         private_loader::forced_unregister_manager(&mut self.managers, index)
     }
 
-    /*
-    TODO: Refactoring example: Add manager search by its type
-    *     Example: let manager = loader.get_manager::<MyManager>();
-    */
+    /// Gets an immutable reference to a manager by its concrete type.
+    ///
+    /// Searches for a registered manager of type `M` and downcasts it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&M>` containing the manager if found.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - Type of the manager to search for
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let manager = loader.get_manager_by_type::<MyManager>();
+    /// ```
+    pub fn get_manager_by_type<M>(&self) -> Option<&M>
+    where
+        M: Manager<'a, O, I> + 'static,
+    {
+        self.managers
+            .iter()
+            .find_map(|m| m.as_ref().as_any().downcast_ref::<M>())
+    }
+
+    /// Gets a mutable reference to a manager by its concrete type.
+    ///
+    /// Searches for a registered manager of type `M` and downcasts it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&mut M>` containing the manager if found.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `M` - Type of the manager to search for
+    pub fn get_manager_mut_by_type<M>(&mut self) -> Option<&mut M>
+    where
+        M: Manager<'a, O, I> + 'static,
+    {
+        self.managers
+            .iter_mut()
+            .find_map(|m| m.as_mut().as_any_mut().downcast_mut::<M>())
+    }
+
     /// Gets an immutable reference to a manager by format.
     ///
     /// Searches for a registered manager that handles the specified format.
@@ -277,7 +573,7 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Option<&Box<dyn Manager<'a, O, I>>>` containing the manager if found.
     pub fn get_manager_ref(&self, format: &str) -> Option<&Box<dyn Manager<'a, O, I>>> {
-        self.managers.iter().find(|m| m.format() == format)
+        private_loader::find_manager(&self.managers, format).map(|index| &self.managers[index])
     }
 
     /// Gets an immutable reference to a manager by format (parallel version).
@@ -292,9 +588,14 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Option<&Box<dyn Manager<'a, O, I>>>` containing the manager if found.
     pub fn par_get_manager_ref(&self, format: &str) -> Option<&Box<dyn Manager<'a, O, I>>> {
-        self.managers
-            .par_iter()
-            .find_first(|m| m.format() == format)
+        private_loader::install(&self.thread_pool, || {
+            self.managers
+                .par_iter()
+                .enumerate()
+                .filter(|(_, m)| private_loader::has_format(m.as_ref(), format))
+                .max_by_key(|(index, m)| (m.priority(), Reverse(*index)))
+                .map(|(_, m)| m)
+        })
     }
 
     /// Gets a mutable reference to a manager by format.
@@ -309,7 +610,7 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Option<&mut Box<dyn Manager<'a, O, I>>>` containing the manager if found.
     pub fn get_manager_mut(&mut self, format: &str) -> Option<&mut Box<dyn Manager<'a, O, I>>> {
-        self.managers.iter_mut().find(|m| m.format() == format)
+        private_loader::find_manager(&self.managers, format).map(|index| &mut self.managers[index])
     }
 
     /// Gets a mutable reference to a manager by format (parallel version).
@@ -324,9 +625,14 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Option<&mut Box<dyn Manager<'a, O, I>>>` containing the manager if found.
     pub fn par_get_manager_mut(&mut self, format: &str) -> Option<&mut Box<dyn Manager<'a, O, I>>> {
-        self.managers
-            .par_iter_mut()
-            .find_first(|m| m.format() == format)
+        private_loader::install(&self.thread_pool, || {
+            self.managers
+                .par_iter_mut()
+                .enumerate()
+                .filter(|(_, m)| private_loader::has_format(m.as_ref(), format))
+                .max_by_key(|(index, m)| (m.priority(), Reverse(*index)))
+                .map(|(_, m)| m)
+        })
     }
 
     //TODO: Add parallel version
@@ -346,6 +652,90 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         private_loader::register_plugin(self, path)
     }
 
+    /// Registers a plugin with the loader asynchronously.
+    ///
+    /// This method awaits the manager's `register_plugin_async` hook, which lets
+    /// asynchronous managers perform I/O during registration.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - Path to the plugin file or directory
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Bundle, RegisterPluginError>` containing the plugin bundle on success.
+    #[cfg(feature = "async")]
+    pub async fn register_plugin_async(
+        &mut self,
+        path: &str,
+    ) -> Result<Bundle, RegisterPluginError> {
+        private_loader::register_plugin_async(self, path).await
+    }
+
+    /// Registers a plugin from an in-memory ZIP archive.
+    ///
+    /// This method extracts the archive to a temporary directory managed by the loader
+    /// and registers the plugin from it. The temporary directory is removed when the
+    /// plugin is unregistered.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Bundle filename of the plugin (e.g., "my_plugin-v1.0.0.lua")
+    /// * `data` - ZIP archive containing the plugin files
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Bundle, RegisterPluginError>` containing the plugin bundle on success.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use plux_rs::{Loader, StdInfo};
+    ///
+    /// let mut loader = Loader::<'_, (), StdInfo>::new();
+    /// // Configure loader with managers...
+    ///
+    /// let data = include_bytes!("../tests/bundles/plugin_b-v1.0.0.vpl");
+    /// let bundle = loader.register_plugin_from_bytes("plugin_b-v1.0.0.vpl", data)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "archive")]
+    pub fn register_plugin_from_bytes(
+        &mut self,
+        name: &str,
+        data: &[u8],
+    ) -> Result<Bundle, RegisterPluginError> {
+        private_loader::register_plugin_from_bytes(self, name, data)
+    }
+
+    /// Registers a virtual plugin with the loader.
+    ///
+    /// Unlike `register_plugin`, the plugin information is constructed by the caller,
+    /// so nothing is read from the filesystem. This is useful for generated plugins that
+    /// have no files on disk. The manager handling `format` is notified through
+    /// `Manager::register_virtual_plugin` instead of `Manager::register_plugin`.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - Format of the manager that will handle this plugin
+    /// * `info` - Plugin information to register
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Bundle, RegisterPluginError>` containing the plugin bundle on success.
+    /// Fails if the bundle format differs from `format`, no manager handles the format or
+    /// a plugin with the same ID and version is already registered.
+    ///
+    /// Virtual plugins cannot be reloaded, since there are no files to register them
+    /// again from, and they are not watched by `watch_plugins`.
+    pub fn register_virtual_plugin(
+        &mut self,
+        format: &str,
+        info: PluginInfo<I>,
+    ) -> Result<Bundle, RegisterPluginError> {
+        private_loader::register_virtual_plugin(self, format, info)
+    }
+
     /// Forcefully registers a plugin, bypassing safety checks.
     ///
     /// This unsafe method allows registering a plugin without checking for duplicates.
@@ -369,7 +759,11 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         manager: &mut Box<dyn Manager<'a, O, I>>,
         plugin_info: PluginInfo<I>,
     ) -> Result<Bundle, RegisterPluginError> {
-        private_loader::forced_register_plugin(&mut self.plugins, Ptr::new(manager), plugin_info)
+        private_loader::forced_register_plugin(
+            &mut self.plugins,
+            Ptr::new(&mut **manager),
+            plugin_info,
+        )
     }
 
     /// Registers multiple plugins with the loader.
@@ -398,9 +792,52 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Registers all plugins found in a directory.
+    ///
+    /// Plugins are directories named like `id-vX.Y.Z.format`, so every subdirectory whose
+    /// extension matches the format of a registered manager is registered as a plugin.
+    /// Other entries are skipped, and if `recursive` is set, other subdirectories are
+    /// searched for plugins as well. Plugins are registered in the order of their paths.
+    ///
+    /// # Parameters
+    ///
+    /// * `dir` - Directory to search for plugins
+    /// * `recursive` - Whether to search subdirectories that are not plugins
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Bundle>, RegisterPluginError>` containing the plugin bundles on success.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::path::Path;
+    /// use plux_rs::{Loader, StdInfo};
+    ///
+    /// let mut loader = Loader::<'_, (), StdInfo>::new();
+    /// // Configure loader with managers...
+    ///
+    /// let bundles = loader.register_plugins_from_dir(Path::new("plugins"), false)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn register_plugins_from_dir(
+        &mut self,
+        dir: &Path,
+        recursive: bool,
+    ) -> Result<Vec<Bundle>, RegisterPluginError> {
+        let mut paths = vec![];
+        private_loader::find_plugin_paths(self, dir, recursive, &mut paths)?;
+
+        paths
+            .iter()
+            .map(|path| private_loader::register_plugin(self, &path.to_string_lossy()))
+            .collect::<Result<Vec<_>, _>>()
+    }
+
     /// Registers multiple plugins with the loader in parallel.
     ///
-    /// This method registers multiple plugins from the specified paths concurrently.
+    /// This method checks the plugin paths concurrently, then the managers register
+    /// the plugins in sequence, since each registration mutates the loader.
     ///
     /// # Parameters
     ///
@@ -421,12 +858,20 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     where
         P: IntoParallelIterator<Item = &'b str>,
     {
-        let this = Ptr::new(self);
+        // Paths are checked in parallel, while the managers register the plugins
+        // one by one, since registration mutates the loader
+        let paths = paths.into_par_iter();
+        let this = &*self;
+        let checked = private_loader::install(&self.thread_pool, || {
+            paths
+                .map(|path| private_loader::check_plugin_path(this, path))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
 
-        paths
-            .into_par_iter()
-            .map(move |path| private_loader::register_plugin(this.as_mut(), path))
-            .collect::<Result<Vec<_>, _>>()
+        checked
+            .into_iter()
+            .map(|(path, bundle)| private_loader::register_checked_plugin(self, path, bundle))
+            .collect()
     }
 
     /// Unregisters a plugin from the loader.
@@ -493,30 +938,85 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         &mut self,
         bundle: &Bundle,
     ) -> Result<(), UnregisterPluginError> {
-        let index = self
-            .plugins
-            .par_iter()
-            .position_first(|plugin| *plugin == *bundle)
-            .ok_or(UnregisterPluginError::NotFound)?;
+        let index = private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .position_first(|plugin| *plugin == *bundle)
+        })
+        .ok_or(UnregisterPluginError::NotFound)?;
         private_loader::unregister_plugin(&mut self.plugins, index)
     }
 
-    /// Forcefully unregisters a plugin, bypassing safety checks.
+    /// Unregisters several plugins from the loader by bundle.
     ///
-    /// This unsafe method allows unregistering a plugin without checking if it exists.
-    /// Use with caution as it may lead to undefined behavior.
+    /// The plugins are unloaded and unregistered in dependency order, dependents first,
+    /// so plugins that depend on each other can be passed in any order.
     ///
     /// # Parameters
     ///
-    /// * `index` - Index of the plugin to unregister
+    /// * `bundles` - Bundles of the plugins to unregister
     ///
     /// # Returns
     ///
     /// Returns `Result<(), UnregisterPluginError>` indicating success or failure.
+    /// Fails without changes if any of the plugins is not registered.
+    pub fn unregister_plugins(&mut self, bundles: &[Bundle]) -> Result<(), UnregisterPluginError> {
+        private_loader::unregister_plugins(&mut self.plugins, bundles)
+    }
+
+    /// Unregisters all versions of a plugin matching a version requirement.
     ///
-    /// # Safety
+    /// The matching plugins are unloaded and unregistered in dependency order, like
+    /// with `unregister_plugins`.
     ///
-    /// This method is unsafe because it bypasses existence checks that prevent
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    /// * `req` - Version requirement the plugin versions must satisfy
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Bundle>, UnregisterPluginError>` containing the bundles of the
+    /// unregistered plugins, which is empty if no plugin matches.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use plux_rs::prelude::*;
+    /// use semver::VersionReq;
+    ///
+    /// let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+    /// // Unregister all 1.x versions of the plugin
+    /// let bundles = loader
+    ///     .unregister_plugins_matching("brush", &VersionReq::parse("1.*").unwrap())
+    ///     .unwrap();
+    /// ```
+    pub fn unregister_plugins_matching(
+        &mut self,
+        id: &str,
+        req: &VersionReq,
+    ) -> Result<Vec<Bundle>, UnregisterPluginError> {
+        let bundles = private_loader::matching_bundles(&self.plugins, id, req);
+        private_loader::unregister_plugins(&mut self.plugins, &bundles)?;
+        Ok(bundles)
+    }
+
+    /// Forcefully unregisters a plugin, bypassing safety checks.
+    ///
+    /// This unsafe method allows unregistering a plugin without checking if it exists.
+    /// Use with caution as it may lead to undefined behavior.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - Index of the plugin to unregister
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), UnregisterPluginError>` indicating success or failure.
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe because it bypasses existence checks that prevent
     /// accessing invalid memory or indices.
     pub unsafe fn forced_unregister_plugin(
         &mut self,
@@ -563,11 +1063,12 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         id: &str,
         version: &Version,
     ) -> Result<(), UnloadPluginError> {
-        let index = self
-            .plugins
-            .par_iter()
-            .position_first(|plugin| *plugin == (id, version))
-            .ok_or(UnloadPluginError::NotFound)?;
+        let index = private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .position_first(|plugin| *plugin == (id, version))
+        })
+        .ok_or(UnloadPluginError::NotFound)?;
         private_loader::unload_plugin(&mut self.plugins, index)
     }
 
@@ -591,6 +1092,46 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         private_loader::unload_plugin(&mut self.plugins, index)
     }
 
+    /// Unloads several plugins from the execution environment by bundle.
+    ///
+    /// The plugins are unloaded in dependency order, dependents first,
+    /// so plugins that depend on each other can be passed in any order.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundles` - Bundles of the plugins to unload
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), UnloadPluginError>` indicating success or failure.
+    /// Fails without changes if any of the plugins is not registered.
+    pub fn unload_plugins(&mut self, bundles: &[Bundle]) -> Result<(), UnloadPluginError> {
+        private_loader::unload_plugins_by_bundles(&mut self.plugins, bundles).map(|_| ())
+    }
+
+    /// Unloads all versions of a plugin matching a version requirement.
+    ///
+    /// The matching plugins are unloaded in dependency order, like with `unload_plugins`.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    /// * `req` - Version requirement the plugin versions must satisfy
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Bundle>, UnloadPluginError>` containing the bundles of the
+    /// matching plugins, which is empty if no plugin matches.
+    pub fn unload_plugins_matching(
+        &mut self,
+        id: &str,
+        req: &VersionReq,
+    ) -> Result<Vec<Bundle>, UnloadPluginError> {
+        let bundles = private_loader::matching_bundles(&self.plugins, id, req);
+        private_loader::unload_plugins_by_bundles(&mut self.plugins, &bundles)?;
+        Ok(bundles)
+    }
+
     /// Unloads a plugin from the execution environment by bundle (parallel version).
     ///
     /// This method unloads a plugin by bundle information using parallel processing.
@@ -606,11 +1147,12 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         &mut self,
         bundle: &Bundle,
     ) -> Result<(), UnloadPluginError> {
-        let index = self
-            .plugins
-            .par_iter()
-            .position_first(|plugin| *plugin == *bundle)
-            .ok_or(UnloadPluginError::NotFound)?;
+        let index = private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .position_first(|plugin| *plugin == *bundle)
+        })
+        .ok_or(UnloadPluginError::NotFound)?;
         private_loader::unload_plugin(&mut self.plugins, index)
     }
 
@@ -651,6 +1193,66 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         self.plugins.iter().find(|plugin| **plugin == (id, version))
     }
 
+    /// Gets the highest loaded version of a plugin matching a version requirement.
+    ///
+    /// This is useful when only a dependency specification is at hand instead of an
+    /// exact version.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    /// * `req` - Version requirement the plugin must match
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Plugin<'a, O, I>>` containing the highest matching loaded plugin if found.
+    pub fn get_plugin_matching(&self, id: &str, req: &VersionReq) -> Option<&Plugin<'a, O, I>> {
+        self.plugins
+            .iter()
+            .filter(|plugin| {
+                plugin.is_load
+                    && plugin.info.bundle.id == id
+                    && req.matches(&plugin.info.bundle.version)
+            })
+            .max_by(|a, b| a.info.bundle.version.cmp(&b.info.bundle.version))
+    }
+
+    /// Gets the highest registered version of a plugin.
+    ///
+    /// This is the version-agnostic counterpart of `get_plugin`, for when any version
+    /// of the plugin will do.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Plugin<'a, O, I>>` containing the highest version of the plugin if found.
+    pub fn get_latest_plugin(&self, id: &str) -> Option<&Plugin<'a, O, I>> {
+        private_loader::find_latest(&self.plugins, id).map(|index| &self.plugins[index])
+    }
+
+    /// Gets the highest registered version of a plugin (parallel version).
+    ///
+    /// Searches for the highest registered version of the plugin using parallel processing.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Plugin<'a, O, I>>` containing the highest version of the plugin if found.
+    pub fn par_get_latest_plugin(&self, id: &str) -> Option<&Plugin<'a, O, I>> {
+        private_loader::install(&self.thread_pool, || {
+            self.plugins.par_iter().find_first(|plugin| {
+                plugin.info.bundle.id == id
+                    && private_loader::is_latest(&self.plugins, &plugin.info.bundle)
+            })
+        })
+    }
+
     /// Gets an immutable reference to a plugin by ID and version (parallel version).
     ///
     /// Searches for a registered plugin matching the specified ID and version using parallel processing.
@@ -664,9 +1266,11 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Option<&Plugin<'a, O, I>>` containing the plugin if found.
     pub fn par_get_plugin(&self, id: &str, version: &Version) -> Option<&Plugin<'a, O, I>> {
-        self.plugins
-            .par_iter()
-            .find_first(|plugin| **plugin == (id, version))
+        private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .find_first(|plugin| **plugin == (id, version))
+        })
     }
 
     /// Gets an immutable reference to a plugin by bundle.
@@ -696,9 +1300,29 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Option<&Plugin<'a, O, I>>` containing the plugin if found.
     pub fn par_get_plugin_by_bundle(&self, bundle: &Bundle) -> Option<&Plugin<'a, O, I>> {
-        self.plugins
-            .par_iter()
-            .find_first(|plugin| *plugin == bundle)
+        private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .find_first(|plugin| *plugin == bundle)
+        })
+    }
+
+    /// Gets an immutable reference to a plugin by its path.
+    ///
+    /// Plugin paths are stored canonicalized, so the given path is canonicalized
+    /// before the comparison and may be relative or contain symlinks. A path that
+    /// no longer exists, such as one from a file removal event, is compared as is.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - Path of the plugin directory
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&Plugin<'a, O, I>>` containing the plugin if found.
+    pub fn get_plugin_by_path(&self, path: &Path) -> Option<&Plugin<'a, O, I>> {
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        self.plugins.iter().find(|plugin| plugin.info.path == path)
     }
 
     /// Gets a mutable reference to a plugin by ID and version.
@@ -719,6 +1343,38 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
             .find(|plugin| **plugin == (id, version))
     }
 
+    /// Gets a mutable reference to the highest registered version of a plugin.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&mut Plugin<'a, O, I>>` containing the highest version of the plugin if found.
+    pub fn get_latest_plugin_mut(&mut self, id: &str) -> Option<&mut Plugin<'a, O, I>> {
+        private_loader::find_latest(&self.plugins, id).map(|index| &mut self.plugins[index])
+    }
+
+    /// Gets a mutable reference to the highest registered version of a plugin (parallel version).
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<&mut Plugin<'a, O, I>>` containing the highest version of the plugin if found.
+    pub fn par_get_latest_plugin_mut(&mut self, id: &str) -> Option<&mut Plugin<'a, O, I>> {
+        let index = private_loader::install(&self.thread_pool, || {
+            self.plugins.par_iter().position_first(|plugin| {
+                plugin.info.bundle.id == id
+                    && private_loader::is_latest(&self.plugins, &plugin.info.bundle)
+            })
+        })?;
+        self.plugins.get_mut(index)
+    }
+
     /// Gets a mutable reference to a plugin by ID and version (parallel version).
     ///
     /// This method searches for a registered plugin matching the specified ID and version
@@ -737,9 +1393,11 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         id: &str,
         version: &Version,
     ) -> Option<&mut Plugin<'a, O, I>> {
-        self.plugins
-            .par_iter_mut()
-            .find_first(|plugin| **plugin == (id, version))
+        private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter_mut()
+                .find_first(|plugin| **plugin == (id, version))
+        })
     }
 
     /// Gets a mutable reference to a plugin by bundle.
@@ -773,9 +1431,56 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         &mut self,
         bundle: &Bundle,
     ) -> Option<&mut Plugin<'a, O, I>> {
-        self.plugins
-            .par_iter_mut()
-            .find_first(|plugin| *plugin == bundle)
+        private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter_mut()
+                .find_first(|plugin| *plugin == bundle)
+        })
+    }
+
+    /// Checks if a plugin is loaded by bundle.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Plugin bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the plugin is registered and loaded, `false` otherwise.
+    pub fn is_loaded(&self, bundle: &Bundle) -> bool {
+        self.get_plugin_by_bundle(bundle)
+            .is_some_and(|plugin| plugin.is_load)
+    }
+
+    /// Returns the memory used by a plugin.
+    ///
+    /// The value is reported by the manager of the plugin, see `Manager::plugin_memory`.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Plugin bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<usize>` containing the memory usage in bytes, or `None` if the plugin
+    /// is not registered or its manager does not report memory usage.
+    pub fn plugin_memory(&self, bundle: &Bundle) -> Option<usize> {
+        self.get_plugin_by_bundle(bundle)?.memory_usage()
+    }
+
+    /// Checks if a plugin is loaded by ID and version.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - Plugin identifier
+    /// * `version` - Plugin version
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the plugin is registered and loaded, `false` otherwise.
+    pub fn is_loaded_by_id(&self, id: &str, version: &Version) -> bool {
+        self.get_plugin(id, version)
+            .is_some_and(|plugin| plugin.is_load)
     }
 
     /// Gets all plugins with the specified ID.
@@ -808,12 +1513,165 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Vec<&Plugin<'a, O, I>>` containing all matching plugins.
     pub fn par_get_plugins_by_id(&self, id: &str) -> Vec<&Plugin<'a, O, I>> {
+        private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .filter(|plugin| plugin.info.bundle.id == id)
+                .collect()
+        })
+    }
+
+    /// Gets all plugins matching a predicate.
+    ///
+    /// This is a generalization of `get_plugins_by_id` for queries by format,
+    /// loaded state, dependencies or any other property of a plugin.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - Function returning `true` for the plugins to include
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'a, O, I>>` containing all matching plugins.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - Type of the predicate
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let loaded_lua_plugins =
+    ///     loader.find_plugins(|plugin| plugin.is_load() && plugin.bundle().format == "lua");
+    /// ```
+    pub fn find_plugins<F>(&self, predicate: F) -> Vec<&Plugin<'a, O, I>>
+    where
+        F: Fn(&Plugin<'a, O, I>) -> bool,
+    {
         self.plugins
-            .par_iter()
-            .filter(|plugin| plugin.info.bundle.id == id)
+            .iter()
+            .filter(|plugin| predicate(plugin))
+            .collect()
+    }
+
+    /// Gets all plugins matching a predicate (parallel version).
+    ///
+    /// Works like `find_plugins`, but evaluates the predicate using parallel processing.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - Function returning `true` for the plugins to include
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'a, O, I>>` containing all matching plugins.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - Type of the predicate (must implement Send + Sync)
+    pub fn par_find_plugins<F>(&self, predicate: F) -> Vec<&Plugin<'a, O, I>>
+    where
+        F: Fn(&Plugin<'a, O, I>) -> bool + Send + Sync,
+    {
+        private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .filter(|plugin| predicate(plugin))
+                .collect()
+        })
+    }
+
+    /// Gets all loaded plugins that use a plugin as a dependency.
+    ///
+    /// A loaded plugin uses the bundle if one of its required or optional dependencies
+    /// resolves to it, i.e. the bundle is the highest registered version matching the
+    /// dependency. These are the plugins that prevent the bundle from being unloaded
+    /// with `UnloadPluginError::CurrentlyUsesDepend`.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Bundle of the plugin the dependents of which are requested
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'a, O, I>>` containing the direct dependents of the plugin.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// for plugin in loader.dependents_of(&bundle) {
+    ///     println!("{} is used by {}", bundle, plugin.info().bundle);
+    /// }
+    /// ```
+    pub fn dependents_of(&self, bundle: &Bundle) -> Vec<&Plugin<'a, O, I>> {
+        self.plugins
+            .iter()
+            .filter(|plugin| private_loader::is_dependent(&self.plugins, plugin, bundle))
             .collect()
     }
 
+    /// Gets all loaded plugins that use a plugin as a dependency (parallel version).
+    ///
+    /// Works like `dependents_of`, but checks the plugins using parallel processing.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Bundle of the plugin the dependents of which are requested
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'a, O, I>>` containing the direct dependents of the plugin.
+    pub fn par_dependents_of(&self, bundle: &Bundle) -> Vec<&Plugin<'a, O, I>> {
+        private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .filter(|plugin| private_loader::is_dependent(&self.plugins, plugin, bundle))
+                .collect()
+        })
+    }
+
+    /// Checks that plugins can be loaded, without loading them.
+    ///
+    /// This method walks the dependencies of the plugins the same way loading does and
+    /// reports the problems found in the registered metadata: missing plugins and
+    /// dependencies, dependencies without the required features and circular dependencies.
+    /// Unlike loading, it does not stop at the first problem and collects all of them.
+    ///
+    /// No plugin code is run and the managers are not called, so problems that only show
+    /// up while loading, such as a manager error or a missing function request, are not
+    /// detected. Plugins that are already loaded are considered valid.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundles` - Bundles of the plugins to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), Vec<LoadPluginError>>` containing all problems found.
+    /// Problems in dependencies are wrapped in `LoadPluginError::LoadDependency`,
+    /// as they are when loading, and each plugin is checked only once.
+    pub fn validate_plugins(&self, bundles: &[Bundle]) -> Result<(), Vec<LoadPluginError>> {
+        let mut errors = vec![];
+        let mut validated = vec![];
+
+        for bundle in bundles {
+            match self.plugins.iter().position(|plugin| *plugin == *bundle) {
+                Some(index) => errors.extend(private_loader::validate_plugin(
+                    &self.plugins,
+                    index,
+                    &mut vec![],
+                    &mut validated,
+                )),
+                None => errors.push(LoadPluginError::NotFound),
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
     /// Gets mutable references to all plugins with the specified ID.
     ///
     /// Returns mutable references to all versions of plugins matching the given ID.
@@ -844,10 +1702,12 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// Returns `Vec<&mut Plugin<'a, O, I>>` containing all matching plugins.
     pub fn par_get_plugins_by_id_mut(&mut self, id: &str) -> Vec<&mut Plugin<'a, O, I>> {
-        self.plugins
-            .par_iter_mut()
-            .filter(|plugin| plugin.info.bundle.id == id)
-            .collect()
+        private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter_mut()
+                .filter(|plugin| plugin.info.bundle.id == id)
+                .collect()
+        })
     }
 
     //TODO: Add functions for tracking loading and unloading
@@ -864,34 +1724,254 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         &self.plugins
     }
 
-    /// Gets a reference to the function registry.
-    ///
-    /// Returns the registry containing all functions available to plugins.
+    /// Gets the number of registered plugins.
     ///
     /// # Returns
     ///
-    /// Returns `&Registry<O>` containing the function registry.
-    pub const fn get_registry(&self) -> &Registry<O> {
-        &self.registry
+    /// Returns the number of plugins registered in the loader, loaded or not.
+    pub const fn plugin_count(&self) -> usize {
+        self.plugins.len()
     }
 
-    /// Gets a reference to the function requests.
-    ///
-    /// Returns a set of queries that plugins implement for the host.
+    /// Gets the number of loaded plugins.
     ///
     /// # Returns
     ///
-    /// Returns `&Requests` containing the function requests.
-    pub const fn get_requests(&self) -> &Requests {
-        &self.requests
+    /// Returns the number of registered plugins that are currently loaded.
+    pub fn loaded_plugin_count(&self) -> usize {
+        self.plugins.iter().filter(|plugin| plugin.is_load).count()
     }
 
-    /// Calls a function request across all eligible plugins.
+    /// Gets the number of registered managers.
     ///
-    /// This method calls the specified function request on all plugins that have the highest
-    /// version for their ID (to avoid calling multiple versions of the same plugin).
+    /// # Returns
     ///
-    /// # Parameters
+    /// Returns the number of managers registered in the loader.
+    pub const fn manager_count(&self) -> usize {
+        self.managers.len()
+    }
+
+    /// Gets the plugin formats handled by the registered managers.
+    ///
+    /// Together with `Bundle::from_filename` this allows checking a plugin path
+    /// before registering it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&'static str>` containing every format once, in manager registration order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let bundle = Bundle::from_filename("my_plugin-v1.0.0.lua")?;
+    /// if !loader.registered_formats().contains(&bundle.format.as_str()) {
+    ///     println!("No manager handles '{}' plugins", bundle.format);
+    /// }
+    /// ```
+    pub fn registered_formats(&self) -> Vec<&'static str> {
+        let mut formats = vec![];
+        for format in self.managers.iter().flat_map(|manager| manager.formats()) {
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+        formats
+    }
+
+    /// Gets the loaded plugins in dependency order.
+    ///
+    /// Every plugin in the returned list appears after all of its dependencies,
+    /// so the list can be walked forwards to load and backwards to unload.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<&Plugin<'a, O, I>>` containing the loaded plugins sorted by dependencies.
+    pub fn plugins_in_load_order(&self) -> Vec<&Plugin<'a, O, I>> {
+        let loaded_plugins = self
+            .plugins
+            .iter()
+            .enumerate()
+            .filter_map(|(index, plugin)| plugin.is_load.then_some(index))
+            .collect();
+
+        private_loader::sort_plugins(&self.plugins, loaded_plugins)
+            .into_iter()
+            .rev()
+            .map(|index| &self.plugins[index])
+            .collect()
+    }
+
+    /// Builds the dependency graph of registered plugins.
+    ///
+    /// Each dependency is resolved to the highest registered version matching its requirement.
+    /// Unresolved optional dependencies are omitted, while unresolved required dependencies
+    /// are included as placeholder bundles with the lowest version allowed by the requirement
+    /// and an empty format.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Vec<(Bundle, Vec<Bundle>)>` containing each registered plugin
+    /// with the bundles of its dependencies.
+    pub fn dependency_graph(&self) -> Vec<(Bundle, Vec<Bundle>)> {
+        self.plugins
+            .iter()
+            .map(|plugin| {
+                let info = &plugin.info.info;
+                let depends = info
+                    .depends()
+                    .iter()
+                    .map(|d| (true, d))
+                    .chain(info.optional_depends().iter().map(|d| (false, d)))
+                    .filter_map(|(is_depend, depend)| {
+                        match private_loader::find_depend(&self.plugins, depend) {
+                            Some(index) => Some(self.plugins[index].info.bundle.clone()),
+                            None if is_depend => Some(Bundle {
+                                id: depend.id.clone(),
+                                version: depend.version.comparators.first().map_or(
+                                    Version::new(0, 0, 0),
+                                    |c| {
+                                        Version::new(
+                                            c.major,
+                                            c.minor.unwrap_or(0),
+                                            c.patch.unwrap_or(0),
+                                        )
+                                    },
+                                ),
+                                format: String::new(),
+                            }),
+                            None => None,
+                        }
+                    })
+                    .collect();
+
+                (plugin.info.bundle.clone(), depends)
+            })
+            .collect()
+    }
+
+    /// Gets a reference to the function registry.
+    ///
+    /// Returns the registry containing all functions available to plugins.
+    ///
+    /// # Returns
+    ///
+    /// Returns `&Registry<O>` containing the function registry.
+    pub const fn get_registry(&self) -> &Registry<O> {
+        &self.registry
+    }
+
+    /// Returns the signatures of all functions in the registry.
+    ///
+    /// This is the host-side counterpart of `Plugin::function_signatures`. Each signature
+    /// is formatted with the `Display` implementation of `dyn Function`,
+    /// e.g. `add(a: I32, b: I32) -> output(I32)`, preceded by a `# description` line
+    /// if the function has a description.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of formatted function signatures in registration order.
+    pub fn registry_signatures(&self) -> Vec<String> {
+        self.registry
+            .iter()
+            .map(|function| function.to_string())
+            .collect()
+    }
+
+    /// Calls a function from the registry by name.
+    ///
+    /// This method searches the registry of functions available to plugins and executes
+    /// the function matching the provided name.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<O, PluginCallFunctionError>` containing the function result on success,
+    /// or an error if the function is not found.
+    pub fn call_function(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<O, PluginCallFunctionError> {
+        self.registry
+            .iter()
+            .find_map(|function| match function.name() == name {
+                true => Some(function.call(args)),
+                false => None,
+            })
+            .ok_or(PluginCallFunctionError::NotFound)
+    }
+
+    /// Gets a reference to the function requests.
+    ///
+    /// Returns a set of queries that plugins implement for the host.
+    ///
+    /// # Returns
+    ///
+    /// Returns `&Requests` containing the function requests.
+    pub const fn get_requests(&self) -> &Requests {
+        &self.requests
+    }
+
+    /// Returns the signatures of all function requests.
+    ///
+    /// Each signature is formatted with the `Display` implementation of `Request`,
+    /// e.g. `join(List, String?) -> String`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of formatted request signatures in registration order.
+    pub fn request_signatures(&self) -> Vec<String> {
+        self.requests
+            .iter()
+            .map(|request| request.to_string())
+            .collect()
+    }
+
+    /// Exports the interface of all loaded plugins.
+    ///
+    /// For every loaded plugin the schema contains its ID and version, the functions
+    /// registered by the plugin and the functions it implements for the host requests.
+    /// Unlike `Plugin::function_signatures`, the schema keeps the argument names and
+    /// types in a structured form, so it can be serialized for code generators.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `PluginSchema` with the loaded plugins in registration order.
+    pub fn export_schema(&self) -> PluginSchema {
+        PluginSchema {
+            plugins: self
+                .plugins
+                .iter()
+                .filter(|plugin| plugin.is_load)
+                .map(|plugin| PluginInterface {
+                    id: plugin.info.bundle.id.clone(),
+                    version: plugin.info.bundle.version.clone(),
+                    functions: plugin
+                        .registry
+                        .iter()
+                        .map(|function| FunctionSchema::from(function.as_ref()))
+                        .collect(),
+                    requests: plugin
+                        .requests
+                        .iter()
+                        .map(|function| FunctionSchema::from(function.as_ref()))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Calls a function request across all eligible plugins.
+    ///
+    /// This method calls the specified function request on all plugins that have the highest
+    /// version for their ID (to avoid calling multiple versions of the same plugin).
+    /// Disabled plugins are skipped, so the highest enabled version is called instead.
+    ///
+    /// # Parameters
     ///
     /// * `name` - Name of the function request to call
     /// * `args` - Arguments to pass to the function
@@ -907,16 +1987,65 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ) -> Result<Vec<O>, PluginCallRequestError> {
         self.plugins
             .iter()
-            .filter_map(|plugin| {
-                let check_version = self.plugins.iter().find(|pl| {
-                    pl.info.bundle.id == plugin.info.bundle.id
-                        && pl.info.bundle.version > plugin.info.bundle.version
-                });
+            .filter(|plugin| private_loader::is_request_target(&self.plugins, plugin))
+            .map(|plugin| plugin.call_request(name, args))
+            .collect()
+    }
 
-                match check_version {
-                    Some(_) => None,
-                    None => Some(plugin.call_request(name, args)),
-                }
+    /// Calls a function request on the first eligible plugin that implements it.
+    ///
+    /// Unlike `call_request`, which calls every eligible plugin, this method goes through
+    /// the plugins in registration order and calls only the first one that has the request.
+    /// Eligible plugins are chosen the same way as in `call_request`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function request to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Option<O>, PluginCallRequestError>` containing the result of the
+    /// first plugin that has the requested function, or `None` if no plugin has it.
+    pub fn call_request_first(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<Option<O>, PluginCallRequestError> {
+        self.plugins
+            .iter()
+            .filter(|plugin| private_loader::is_request_target(&self.plugins, plugin))
+            .find(|plugin| plugin.has_request(name))
+            .map(|plugin| plugin.call_request(name, args))
+            .transpose()
+    }
+
+    /// Calls a function request across all eligible plugins, pairing results with their plugins.
+    ///
+    /// This method works like `call_request`, but each result is returned together with
+    /// the bundle of the plugin that produced it.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function request to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<(Bundle, O)>, PluginCallRequestError>` containing results from all
+    /// eligible plugins that have the requested function, paired with their bundles.
+    pub fn call_request_with_bundles(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<Vec<(Bundle, O)>, PluginCallRequestError> {
+        self.plugins
+            .iter()
+            .filter(|plugin| private_loader::is_request_target(&self.plugins, plugin))
+            .map(|plugin| {
+                plugin
+                    .call_request(name, args)
+                    .map(|output| (plugin.info.bundle.clone(), output))
             })
             .collect()
     }
@@ -925,6 +2054,7 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
     ///
     /// This method calls the specified function request on all plugins that have the highest
     /// version for their ID (to avoid calling multiple versions of the same plugin) using parallel processing.
+    /// Disabled plugins are skipped, so the highest enabled version is called instead.
     ///
     /// # Parameters
     ///
@@ -943,35 +2073,70 @@ impl<'a, O: Send + Sync, I: Info> Loader<'a, O, I> {
         let requests: Vec<_> = self
             .plugins
             .iter()
-            .filter_map(|plugin| {
-                let check_version = self.plugins.iter().find(|pl| {
-                    pl.info.bundle.id == plugin.info.bundle.id
-                        && pl.info.bundle.version > plugin.info.bundle.version
-                });
-
-                match check_version {
-                    Some(_) => None,
-                    None => Some(&plugin.requests),
-                }
-            })
+            .filter(|plugin| private_loader::is_request_target(&self.plugins, plugin))
+            .map(|plugin| &plugin.requests)
             .collect();
 
-        requests
-            .into_par_iter()
-            .map(|requests| {
-                requests
-                    .par_iter()
-                    .find_map_first(|request| match request.name() == name {
-                        true => Some(request.call(args)),
-                        false => None,
-                    })
-                    .ok_or(PluginCallRequestError::NotFound)
-            })
-            .collect()
+        private_loader::install(&self.thread_pool, || {
+            requests
+                .into_par_iter()
+                .map(|requests| {
+                    requests
+                        .par_iter()
+                        .find_map_first(|request| match request.name() == name {
+                            true => {
+                                Some(request.call(&fill_optional_args(&request.inputs(), args)))
+                            }
+                            false => None,
+                        })
+                        .ok_or(PluginCallRequestError::NotFound)
+                })
+                .collect()
+        })
     }
 }
 
 impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
+    /// Moves the managers, plugins, functions and requests of another loader into this one.
+    ///
+    /// This allows building subsets of plugins in separate loaders and combining them later.
+    /// Each moved plugin keeps working with its manager, which is now owned by this loader.
+    /// Functions already in the registry and requests with the same signature are not
+    /// duplicated. The thread pool, load policy and event subscribers of `other` are dropped,
+    /// while its load metrics are kept if this loader records metrics.
+    ///
+    /// The `Api` given to a manager when loading a plugin points to the loader that loaded
+    /// it, so the loaded plugins of `other` are unloaded before they are moved and then
+    /// loaded again by this loader. Their dependencies are resolved again among the
+    /// plugins of both loaders.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The loader to merge into this one
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), MergeLoaderError>` indicating success or failure.
+    /// The merge fails without changing this loader if a manager format is already occupied
+    /// by a manager with the same priority, if a plugin is registered in both loaders, if a
+    /// request is declared with different signatures, or if a loaded plugin does not implement
+    /// a request declared by the other loader. On failure `other` is dropped and stopped.
+    /// If a moved plugin fails to load again, the merge is already done, the plugin stays
+    /// registered and `MergeLoaderError::LoadPlugin` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut graphics = Loader::new();
+    /// graphics.context(|mut ctx| ctx.register_manager(LuaManager::new()))?;
+    /// graphics.load_plugin_now("plugins/paint-v1.0.0.lua")?;
+    ///
+    /// loader.merge(graphics)?;
+    /// ```
+    pub fn merge(&mut self, other: Loader<'static, O, I>) -> Result<(), MergeLoaderError> {
+        private_loader::merge(self, other)
+    }
+
     /// Loads a plugin into the execution environment.
     ///
     /// This method loads a plugin by ID and version, making it available for execution.
@@ -1006,11 +2171,12 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
     ///
     /// Returns `Result<(), LoadPluginError>` indicating success or failure.
     pub fn par_load_plugin(&mut self, id: &str, version: &Version) -> Result<(), LoadPluginError> {
-        let index = self
-            .plugins
-            .par_iter()
-            .position_first(|plugin| *plugin == (id, version))
-            .ok_or(LoadPluginError::NotFound)?;
+        let index = private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .position_first(|plugin| *plugin == (id, version))
+        })
+        .ok_or(LoadPluginError::NotFound)?;
         private_loader::load_plugin(self, index)
     }
 
@@ -1034,119 +2200,410 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
         private_loader::load_plugin(self, index)
     }
 
-    /// Loads a plugin into the execution environment by bundle (parallel version).
+    /// Loads a plugin into the execution environment asynchronously.
     ///
-    /// This method loads a plugin by bundle information using parallel processing.
+    /// This method loads a plugin and its dependencies by ID and version, awaiting
+    /// the manager's `load_plugin_async` hook for each of them.
     ///
     /// # Parameters
     ///
-    /// * `bundle` - Plugin bundle information
+    /// * `id` - Plugin identifier
+    /// * `version` - Plugin version
     ///
     /// # Returns
     ///
     /// Returns `Result<(), LoadPluginError>` indicating success or failure.
-    pub fn par_load_plugin_by_bundle(&mut self, bundle: &Bundle) -> Result<(), LoadPluginError> {
+    #[cfg(feature = "async")]
+    pub async fn load_plugin_async(
+        &mut self,
+        id: &str,
+        version: &Version,
+    ) -> Result<(), LoadPluginError> {
         let index = self
             .plugins
-            .par_iter()
-            .position_first(|plugin| *plugin == *bundle)
+            .iter()
+            .position(|plugin| *plugin == (id, version))
             .ok_or(LoadPluginError::NotFound)?;
-        private_loader::load_plugin(self, index)
+        private_loader::load_plugin_async(self, index).await
     }
 
-    /// Forcefully loads a plugin, bypassing safety checks.
+    /// Loads a plugin into the execution environment by bundle asynchronously.
     ///
-    /// This unsafe method allows loading a plugin without checking if it exists.
-    /// Use with caution as it may lead to undefined behavior.
+    /// This method loads a plugin and its dependencies by bundle information, awaiting
+    /// the manager's `load_plugin_async` hook for each of them.
     ///
     /// # Parameters
     ///
-    /// * `index` - Index of the plugin to load
-    /// * `depends` - List of dependencies for this plugin
+    /// * `bundle` - Plugin bundle information
     ///
     /// # Returns
     ///
     /// Returns `Result<(), LoadPluginError>` indicating success or failure.
-    ///
-    /// # Safety
-    ///
-    /// This method is unsafe because it bypasses existence checks that prevent
-    /// accessing invalid memory or indices.
-    pub unsafe fn forced_load_plugin(
+    #[cfg(feature = "async")]
+    pub async fn load_plugin_by_bundle_async(
         &mut self,
-        index: usize,
-        depends: Vec<(Bundle, bool)>,
+        bundle: &Bundle,
     ) -> Result<(), LoadPluginError> {
-        private_loader::forced_load_plugin(self, index, depends)
+        let index = self
+            .plugins
+            .iter()
+            .position(|plugin| *plugin == *bundle)
+            .ok_or(LoadPluginError::NotFound)?;
+        private_loader::load_plugin_async(self, index).await
     }
 
-    /// Loads a plugin immediately from the specified path.
+    /// Loads a plugin into the execution environment by bundle (parallel version).
     ///
-    /// This convenience method registers and loads a plugin in a single operation.
-    /// First registers the plugin, then loads it and all its dependencies.
+    /// This method loads a plugin by bundle information using parallel processing.
     ///
     /// # Parameters
     ///
-    /// * `path` - Path to the plugin file or directory
+    /// * `bundle` - Plugin bundle information
     ///
     /// # Returns
     ///
-    /// Returns `Result<Bundle, (Option<RegisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundle on success, or errors from registration or loading.
+    /// Returns `Result<(), LoadPluginError>` indicating success or failure.
+    pub fn par_load_plugin_by_bundle(&mut self, bundle: &Bundle) -> Result<(), LoadPluginError> {
+        let index = private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .position_first(|plugin| *plugin == *bundle)
+        })
+        .ok_or(LoadPluginError::NotFound)?;
+        private_loader::load_plugin(self, index)
+    }
+
+    /// Calls a function request across all eligible plugins without blocking the executor.
     ///
-    /// # Example
+    /// This method runs `call_request` on tokio's blocking thread pool via
+    /// `tokio::task::spawn_blocking`, so requests that perform I/O do not stall the
+    /// asynchronous host. The returned future must be awaited within a tokio runtime.
     ///
-    /// ```rust,no_run
-    /// use plux_rs::{Loader, StdInfo};
+    /// The eligible plugins are chosen when this method is called, and the future owns
+    /// their request functions instead of borrowing the loader. If the future is dropped
+    /// before completion, the spawned call keeps running in the background.
     ///
-    /// let mut loader = Loader::<'_, (), StdInfo>::new();
-    /// // Configure loader with managers...
+    /// # Parameters
     ///
-    /// let bundle = match loader.load_plugin_now("my_plugin-v1.0.0.cst")  {
-    ///     Ok(bundle) => bundle,
-    ///     Err((Some(e), _)) => return Err(e.into()),
-    ///     Err((None, Some(e))) => return Err(e.into()),
-    ///     Err((None, None)) => return Err("Unknown error".into()),
-    /// };
-    /// 
-    /// println!("Loaded plugin: {}", bundle.id);
-    /// # Ok::<(), Box<dyn std::error::Error>>(())
-    /// ```
-    pub fn load_plugin_now(
-        &mut self,
-        path: &str,
-    ) -> Result<Bundle, (Option<RegisterPluginError>, Option<LoadPluginError>)> {
-        let bundle = private_loader::register_plugin(self, path).map_err(|e| (Some(e), None))?;
-        self.load_plugin_by_bundle(&bundle)
-            .map_err(|e| (None, Some(e)))?;
-        Ok(bundle)
+    /// * `name` - Name of the function request to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns a future resolving to `Result<Vec<O>, PluginCallRequestError>` containing
+    /// results from all eligible plugins that have the requested function.
+    #[cfg(feature = "async")]
+    pub fn call_request_async(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> impl Future<Output = Result<Vec<O>, PluginCallRequestError>> + use<O, I> {
+        let requests = private_loader::request_functions(&self.plugins, name);
+        let args = args.to_vec();
+
+        async move {
+            let call = tokio::task::spawn_blocking(move || {
+                requests
+                    .into_iter()
+                    .map(|(_, request)| private_loader::call_request_function(request, &args))
+                    .collect()
+            });
+            call.await
+                .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+        }
     }
 
-    /// Loads multiple plugins from the specified paths.
+    /// Calls a function request across all eligible plugins with a time limit.
     ///
-    /// This method registers and loads multiple plugins in sequence.
+    /// Each plugin's request runs on its own thread and all of them share the same deadline.
+    /// If a plugin does not respond in time, its thread is abandoned and the call returns
+    /// `PluginCallRequestError::Timeout` with the bundle of that plugin. If a plugin's
+    /// request panics, the panic is resumed on the calling thread.
+    ///
+    /// A thread cannot be interrupted, so an abandoned request keeps running in the
+    /// background until it returns on its own, and may never finish at all. Each thread
+    /// owns a reference to its request function rather than to the plugin, so the plugin
+    /// can still be unloaded or unregistered and the loader dropped in the meantime.
     ///
     /// # Parameters
     ///
-    /// * `paths` - Iterator of paths to plugin files or directories
+    /// * `name` - Name of the function request to call
+    /// * `args` - Arguments to pass to the function
+    /// * `timeout` - Maximum time to wait for the plugins to respond
     ///
     /// # Returns
     ///
-    /// Returns `Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundles on success, or errors from registration or loading.
-    ///
-    /// # Type Parameters
-    ///
-    /// * `'b` - Lifetime of the path references
-    /// * `P` - Type of the iterator containing path references
-    pub fn load_plugins<'b, P>(
-        &mut self,
-        paths: P,
-    ) -> Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<LoadPluginError>)>
+    /// Returns `Result<Vec<O>, PluginCallRequestError>` containing results from all
+    /// eligible plugins that have the requested function.
+    pub fn call_request_timeout(
+        &self,
+        name: &str,
+        args: &[Variable],
+        timeout: Duration,
+    ) -> Result<Vec<O>, PluginCallRequestError> {
+        let deadline = Instant::now() + timeout;
+        let args: Arc<[Variable]> = args.into();
+        let (sender, receiver) = mpsc::channel();
+
+        let (bundles, handles): (Vec<_>, Vec<_>) =
+            private_loader::request_functions(&self.plugins, name)
+                .into_iter()
+                .enumerate()
+                .map(|(index, (bundle, request))| {
+                    let (args, sender) = (args.clone(), sender.clone());
+
+                    let handle = thread::spawn(move || {
+                        let result = private_loader::call_request_function(request, &args);
+                        // The receiver is gone if the call has already timed out
+                        let _ = sender.send((index, result));
+                    });
+                    (bundle, handle)
+                })
+                .unzip();
+        drop(sender);
+
+        let mut outputs: Vec<Option<O>> = bundles.iter().map(|_| None).collect();
+        for _ in 0..bundles.len() {
+            match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok((index, result)) => outputs[index] = Some(result?),
+                Err(_) => {
+                    // A thread that has finished without sending its result has panicked,
+                    // the threads that have not finished yet are the ones that hung
+                    let finished: Vec<_> = handles.iter().map(|h| h.is_finished()).collect();
+                    while let Ok((index, result)) = receiver.try_recv() {
+                        outputs[index] = Some(result?);
+                    }
+
+                    for (index, handle) in handles.into_iter().enumerate() {
+                        if finished[index]
+                            && outputs[index].is_none()
+                            && let Err(panic) = handle.join()
+                        {
+                            std::panic::resume_unwind(panic);
+                        }
+                    }
+
+                    match outputs.iter().position(Option::is_none) {
+                        Some(index) => {
+                            return Err(PluginCallRequestError::Timeout(bundles[index].clone()));
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        Ok(outputs.into_iter().flatten().collect())
+    }
+
+    /// Reloads a plugin by bundle.
+    ///
+    /// This method unloads the plugin together with all loaded plugins that depend on it,
+    /// registers the plugin again from its path, and then loads them back in dependency order.
+    /// Plugins that were not loaded before the reload remain unloaded.
+    ///
+    /// Virtual plugins cannot be reloaded and are rejected with
+    /// `ReloadPluginError::VirtualPlugin` before anything is unloaded.
+    ///
+    /// If the plugin cannot be registered again, e.g. because its files are broken, its
+    /// previous information is registered back through `Manager::register_virtual_plugin`
+    /// and the plugins are loaded back before the error is returned, so the plugin is not lost.
+    ///
+    /// # Parameters
+    ///
+    /// * `bundle` - Plugin bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), ReloadPluginError>` indicating success or failure.
+    pub fn reload_plugin_by_bundle(&mut self, bundle: &Bundle) -> Result<(), ReloadPluginError> {
+        let index = self
+            .plugins
+            .iter()
+            .position(|plugin| *plugin == *bundle)
+            .ok_or(ReloadPluginError::NotFound)?;
+        private_loader::reload_plugin(self, index)
+    }
+
+    /// Starts watching the files of the registered plugins.
+    ///
+    /// The watcher runs on its own thread and only queues the changes. Call
+    /// `process_watch_events` regularly, e.g. once per frame or tick, to reload the changed
+    /// plugins on the thread that owns the loader. Watching stops when the returned handle
+    /// is dropped, calling this method again replaces the previous watch.
+    ///
+    /// Only the plugins registered at the time of the call are watched. Plugins registered
+    /// from archives are not watched, since their files are extracted to a temporary directory,
+    /// and neither are virtual plugins, which have no files at all.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<WatchHandle, WatchError>` containing the handle that keeps the
+    /// watcher running, or an error if the watcher could not be started.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let _watch = loader.watch_plugins()?;
+    ///
+    /// loop {
+    ///     for (bundle, error) in loader.process_watch_events()?.failed {
+    ///         eprintln!("Failed to reload {bundle}: {error}");
+    ///     }
+    ///     // Run the host application
+    /// }
+    /// ```
+    #[cfg(feature = "watch")]
+    pub fn watch_plugins(&mut self) -> Result<WatchHandle, WatchError> {
+        private_loader::watch_plugins(self)
+    }
+
+    /// Reloads the plugins whose files changed since they started being watched.
+    ///
+    /// Changes are debounced: a plugin is reloaded with `reload_plugin_by_bundle` once its
+    /// files have not changed for 200 milliseconds, so saving several files at once results
+    /// in a single reload. Plugins whose changes have not settled yet are reloaded by one of
+    /// the next calls. Does nothing if `watch_plugins` has not been called.
+    ///
+    /// A plugin that fails to reload does not stop the other plugins from being reloaded.
+    /// Its change stays pending, so it is reloaded again by the next call.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<WatchReport, WatchError>` containing the bundles of the reloaded
+    /// plugins and the plugins that failed to reload, or an error reported by the watcher.
+    #[cfg(feature = "watch")]
+    pub fn process_watch_events(&mut self) -> Result<WatchReport, WatchError> {
+        private_loader::process_watch_events(self)
+    }
+
+    /// Replaces a plugin with another version of it.
+    ///
+    /// This method registers the new version from `new_path`. If the old version is loaded,
+    /// the new version is loaded too, and all loaded plugins that depend on the old version
+    /// are reloaded so that they resolve their dependencies against the new one. Finally
+    /// the old version is unregistered. The old version stays available until its dependents
+    /// have moved to the new one.
+    ///
+    /// The new version must not be older than the old one. Dependents only move to the new
+    /// version if it matches their version requirement, otherwise they would keep using the
+    /// old one and it could not be unloaded. If any step before the old version is unloaded
+    /// fails, the new version is unregistered and the dependents are loaded back against the
+    /// old one, so the loader is left as it was before the call.
+    ///
+    /// # Parameters
+    ///
+    /// * `old` - Bundle of the plugin to replace
+    /// * `new_path` - Path to the new version of the plugin
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Bundle, SwapPluginError>` containing the bundle of the new version on success.
+    pub fn swap_plugin(&mut self, old: &Bundle, new_path: &str) -> Result<Bundle, SwapPluginError> {
+        let index = self
+            .plugins
+            .iter()
+            .position(|plugin| *plugin == *old)
+            .ok_or(SwapPluginError::NotFound)?;
+        private_loader::swap_plugin(self, index, new_path)
+    }
+
+    /// Reloads all loaded plugins.
+    ///
+    /// Every loaded plugin is unloaded, dependents first, and then loaded again,
+    /// dependencies first. The plugins stay registered throughout, so unlike
+    /// `reload_plugin_by_bundle` they are not registered again from their paths.
+    /// Plugins that were not loaded remain unloaded.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), ReloadPluginError>` indicating success or failure.
+    pub fn reload_all(&mut self) -> Result<(), ReloadPluginError> {
+        private_loader::reload_all_plugins(self)
+    }
+
+    /// Forcefully loads a plugin, bypassing safety checks.
+    ///
+    /// This unsafe method allows loading a plugin without checking if it exists.
+    /// Use with caution as it may lead to undefined behavior.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - Index of the plugin to load
+    /// * `depends` - List of dependencies for this plugin
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), LoadPluginError>` indicating success or failure.
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe because it bypasses existence checks that prevent
+    /// accessing invalid memory or indices.
+    pub unsafe fn forced_load_plugin(
+        &mut self,
+        index: usize,
+        depends: Vec<(Bundle, bool)>,
+    ) -> Result<(), LoadPluginError> {
+        private_loader::forced_load_plugin(self, index, depends)
+    }
+
+    /// Loads a plugin immediately from the specified path.
+    ///
+    /// This convenience method registers and loads a plugin in a single operation.
+    /// First registers the plugin, then loads it and all its dependencies.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - Path to the plugin file or directory
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Bundle, LoadPluginsError>`
+    /// containing the plugin bundle on success, or an error from registration or loading.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use plux_rs::{Loader, StdInfo};
+    ///
+    /// let mut loader = Loader::<'_, (), StdInfo>::new();
+    /// // Configure loader with managers...
+    ///
+    /// let bundle = loader.load_plugin_now("my_plugin-v1.0.0.cst")?;
+    ///
+    /// println!("Loaded plugin: {}", bundle.id);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn load_plugin_now(&mut self, path: &str) -> Result<Bundle, LoadPluginsError> {
+        let bundle = private_loader::register_plugin(self, path)?;
+        self.load_plugin_by_bundle(&bundle)?;
+        Ok(bundle)
+    }
+
+    /// Loads multiple plugins from the specified paths.
+    ///
+    /// This method registers and loads multiple plugins in sequence. The plugins are loaded
+    /// between the `Manager::begin_batch` and `Manager::end_batch` calls of all managers.
+    ///
+    /// # Parameters
+    ///
+    /// * `paths` - Iterator of paths to plugin files or directories
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Bundle>, LoadPluginsError>`
+    /// containing the plugin bundles on success, or an error from registration or loading.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `'b` - Lifetime of the path references
+    /// * `P` - Type of the iterator containing path references
+    pub fn load_plugins<'b, P>(&mut self, paths: P) -> Result<Vec<Bundle>, LoadPluginsError>
     where
         P: IntoIterator<Item = &'b str>,
     {
-        let bundles = self.register_plugins(paths).map_err(|e| (Some(e), None))?;
+        let bundles = self.register_plugins(paths)?;
 
         // Find plugins that are not dependencies of other plugins
         let result: Vec<_> = self
@@ -1161,15 +2618,7 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
                         .iter()
                         .chain(pl.info.info.optional_depends().iter())
                         .any(|d| {
-                            *d == plugin.info.bundle
-                                && self
-                                    .plugins
-                                    .iter()
-                                    .find(|p| {
-                                        d.version.matches(&p.info.bundle.version)
-                                            && p.info.bundle.version > plugin.info.bundle.version
-                                    })
-                                    .is_none()
+                            private_loader::is_highest_match(&self.plugins, d, &plugin.info.bundle)
                         })
                 });
 
@@ -1180,16 +2629,16 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
             })
             .collect();
 
-        result.into_iter().try_for_each(|index| {
-            private_loader::load_plugin(self, index).map_err(|e| (None, Some(e)))
-        })?;
+        private_loader::load_plugins_batch(self, result)?;
 
         Ok(bundles)
     }
 
     /// Loads multiple plugins from the specified paths (parallel version).
     ///
-    /// This method registers and loads multiple plugins concurrently using parallel processing.
+    /// This method registers the plugins like `par_register_plugins` and finds the plugins
+    /// to load using parallel processing. The plugins are then loaded in sequence, since
+    /// loading mutates the loader and plugins may share dependencies.
     ///
     /// # Parameters
     ///
@@ -1197,60 +2646,51 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
     ///
     /// # Returns
     ///
-    /// Returns `Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundles on success, or errors from registration or loading.
+    /// Returns `Result<Vec<Bundle>, LoadPluginsError>`
+    /// containing the plugin bundles on success, or an error from registration or loading.
     ///
     /// # Type Parameters
     ///
     /// * `'b` - Lifetime of the path references
     /// * `P` - Type of the parallel iterator containing path references
-    pub fn par_load_plugins<'b, P>(
-        &mut self,
-        paths: P,
-    ) -> Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<LoadPluginError>)>
+    pub fn par_load_plugins<'b, P>(&mut self, paths: P) -> Result<Vec<Bundle>, LoadPluginsError>
     where
         P: IntoParallelIterator<Item = &'b str>,
     {
-        let bundles = self
-            .par_register_plugins(paths)
-            .map_err(|e| (Some(e), None))?;
+        let bundles = self.par_register_plugins(paths)?;
 
         // Find plugins that are not dependencies of other plugins
-        let result: Vec<_> = self
-            .plugins
-            .par_iter()
-            .enumerate()
-            .filter_map(|(index, plugin)| {
-                let find_plugin = self.plugins.iter().find(|pl| {
-                    pl.info
-                        .info
-                        .depends()
-                        .iter()
-                        .chain(pl.info.info.optional_depends().iter())
-                        .any(|d| {
-                            *d == plugin.info.bundle
-                                && self
-                                    .plugins
-                                    .iter()
-                                    .find(|p| {
-                                        d.version.matches(&p.info.bundle.version)
-                                            && p.info.bundle.version > plugin.info.bundle.version
-                                    })
-                                    .is_none()
-                        })
-                });
+        let result: Vec<_> = private_loader::install(&self.thread_pool, || {
+            self.plugins
+                .par_iter()
+                .enumerate()
+                .filter_map(|(index, plugin)| {
+                    let find_plugin = self.plugins.iter().find(|pl| {
+                        pl.info
+                            .info
+                            .depends()
+                            .iter()
+                            .chain(pl.info.info.optional_depends().iter())
+                            .any(|d| {
+                                private_loader::is_highest_match(
+                                    &self.plugins,
+                                    d,
+                                    &plugin.info.bundle,
+                                )
+                            })
+                    });
 
-                match find_plugin {
-                    Some(_) => None,
-                    None => Some(index),
-                }
-            })
-            .collect();
+                    match find_plugin {
+                        Some(_) => None,
+                        None => Some(index),
+                    }
+                })
+                .collect()
+        });
 
-        let this = Ptr::new(self);
-        result.into_par_iter().try_for_each(move |index| {
-            private_loader::load_plugin(this.as_mut(), index).map_err(|e| (None, Some(e)))
-        })?;
+        // Plugins are loaded one by one, since loading mutates the loader
+        // and plugins may share dependencies
+        private_loader::load_plugins_batch(self, result)?;
 
         Ok(bundles)
     }
@@ -1258,7 +2698,8 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
     /// Loads only the plugins that are used (not dependencies of other plugins).
     ///
     /// This method registers and loads only the plugins that are not dependencies of other plugins,
-    /// and automatically unregisters unused plugins.
+    /// and automatically unregisters unused plugins. The plugins are loaded between the
+    /// `Manager::begin_batch` and `Manager::end_batch` calls of all managers.
     ///
     /// # Parameters
     ///
@@ -1266,8 +2707,8 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
     ///
     /// # Returns
     ///
-    /// Returns `Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<UnregisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundles on success, or errors from registration, unregistration, or loading.
+    /// Returns `Result<Vec<Bundle>, LoadPluginsError>`
+    /// containing the plugin bundles on success, or an error from registration, unregistration, or loading.
     ///
     /// # Type Parameters
     ///
@@ -1276,20 +2717,11 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
     pub fn load_only_used_plugins<'b, P>(
         &mut self,
         paths: P,
-    ) -> Result<
-        Vec<Bundle>,
-        (
-            Option<RegisterPluginError>,
-            Option<UnregisterPluginError>,
-            Option<LoadPluginError>,
-        ),
-    >
+    ) -> Result<Vec<Bundle>, LoadPluginsError>
     where
         P: IntoIterator<Item = &'b str>,
     {
-        let mut bundles = self
-            .register_plugins(paths)
-            .map_err(|e| (Some(e), None, None))?;
+        let mut bundles = self.register_plugins(paths)?;
 
         // Find plugins that are not dependencies of other plugins
         let (used, unused): (Vec<_>, Vec<_>) = self
@@ -1304,15 +2736,7 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
                         .iter()
                         .chain(pl.info.info.optional_depends().iter())
                         .any(|d| {
-                            *d == plugin.info.bundle
-                                && self
-                                    .plugins
-                                    .iter()
-                                    .find(|p| {
-                                        d.version.matches(&p.info.bundle.version)
-                                            && p.info.bundle.version > plugin.info.bundle.version
-                                    })
-                                    .is_none()
+                            private_loader::is_highest_match(&self.plugins, d, &plugin.info.bundle)
                         })
                 });
 
@@ -1322,20 +2746,10 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
                 }
             })
             .partition(|index| {
-                let bundle = &self.plugins[*index].info.bundle;
-
-                // Find the highest version
-                self.plugins
-                    .iter()
-                    .find(|pl| {
-                        pl.info.bundle.id == bundle.id && pl.info.bundle.version > bundle.version
-                    })
-                    .is_none()
+                private_loader::is_latest(&self.plugins, &self.plugins[*index].info.bundle)
             });
 
-        used.into_iter().try_for_each(|index| {
-            private_loader::load_plugin(self, index).map_err(|e| (None, None, Some(e)))
-        })?;
+        private_loader::load_plugins_batch(self, used)?;
 
         let mut old_indexs = vec![];
         let mut unused = unused.into_iter();
@@ -1350,8 +2764,7 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
             let bundle = &self.plugins[new_index].info.bundle;
             bundles.retain(|b| *b != *bundle);
 
-            private_loader::unregister_plugin(&mut self.plugins, new_index)
-                .map_err(|e| (None, Some(e), None))?;
+            private_loader::unregister_plugin(&mut self.plugins, new_index)?;
 
             old_indexs.push(index);
         }
@@ -1370,8 +2783,8 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
     ///
     /// # Returns
     ///
-    /// Returns `Result<Vec<Bundle>, (Option<RegisterPluginError>, Option<UnregisterPluginError>, Option<LoadPluginError>)>`
-    /// containing the plugin bundles on success, or errors from registration, unregistration, or loading.
+    /// Returns `Result<Vec<Bundle>, LoadPluginsError>`
+    /// containing the plugin bundles on success, or an error from registration, unregistration, or loading.
     ///
     /// # Type Parameters
     ///
@@ -1380,20 +2793,11 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
     pub fn par_load_only_used_plugins<'b, P>(
         &mut self,
         paths: P,
-    ) -> Result<
-        Vec<Bundle>,
-        (
-            Option<RegisterPluginError>,
-            Option<UnregisterPluginError>,
-            Option<LoadPluginError>,
-        ),
-    >
+    ) -> Result<Vec<Bundle>, LoadPluginsError>
     where
         P: IntoParallelIterator<Item = &'b str>,
     {
-        let bundles = self
-            .par_register_plugins(paths)
-            .map_err(|e| (Some(e), None, None))?;
+        let bundles = self.par_register_plugins(paths)?;
 
         // Find plugins that are not dependencies of other plugins
         let (used, unused): (Vec<_>, Vec<_>) = self
@@ -1408,15 +2812,7 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
                         .iter()
                         .chain(pl.info.info.optional_depends().iter())
                         .any(|d| {
-                            *d == plugin.info.bundle
-                                && self
-                                    .plugins
-                                    .iter()
-                                    .find(|p| {
-                                        d.version.matches(&p.info.bundle.version)
-                                            && p.info.bundle.version > plugin.info.bundle.version
-                                    })
-                                    .is_none()
+                            private_loader::is_highest_match(&self.plugins, d, &plugin.info.bundle)
                         })
                 });
 
@@ -1426,21 +2822,10 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
                 }
             })
             .partition(|index| {
-                let bundle = &self.plugins[*index].info.bundle;
-
-                // Find the highest version
-                self.plugins
-                    .iter()
-                    .find(|pl| {
-                        pl.info.bundle.id == bundle.id && pl.info.bundle.version > bundle.version
-                    })
-                    .is_none()
+                private_loader::is_latest(&self.plugins, &self.plugins[*index].info.bundle)
             });
 
-        let this = Ptr::new(self);
-        used.into_iter().try_for_each(|index| {
-            private_loader::load_plugin(this.as_mut(), index).map_err(|e| (None, None, Some(e)))
-        })?;
+        private_loader::load_plugins_batch(self, used)?;
 
         let mut old_indexs = vec![];
         let mut unused = unused.into_iter();
@@ -1450,8 +2835,7 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
                 .iter()
                 .fold(0, |acc, i| if index > *i { acc + 1 } else { acc });
 
-            private_loader::unregister_plugin(&mut this.as_mut().plugins, index - swap)
-                .map_err(|e| (None, Some(e), None))?;
+            private_loader::unregister_plugin(&mut self.plugins, index - swap)?;
 
             old_indexs.push(index);
         }
@@ -1460,27 +2844,201 @@ impl<O: Send + Sync + 'static, I: Info + 'static> Loader<'static, O, I> {
     }
 }
 
+impl<'a, O: Send + Sync, I: Info> LoaderBuilder<'a, O, I> {
+    /// Creates a new loader builder with the default configuration.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new LoaderBuilder that uses the global rayon pool.
+    pub const fn new() -> Self {
+        Self {
+            thread_pool: None,
+            num_threads: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the thread pool used by the parallel methods of the loader.
+    ///
+    /// The pool can be shared with the host application by passing an `Arc<ThreadPool>`.
+    /// Takes precedence over `num_threads`.
+    ///
+    /// # Parameters
+    ///
+    /// * `pool` - Thread pool for parallel operations
+    ///
+    /// # Returns
+    ///
+    /// Returns the builder for chaining.
+    pub fn thread_pool(mut self, pool: impl Into<Arc<ThreadPool>>) -> Self {
+        self.thread_pool = Some(pool.into());
+        self
+    }
+
+    /// Sets the number of threads of a dedicated thread pool for the loader.
+    ///
+    /// The thread pool is created when the loader is built.
+    ///
+    /// # Parameters
+    ///
+    /// * `num_threads` - Number of threads in the pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the builder for chaining.
+    pub const fn num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Builds the plugin loader.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Loader<'a, O, I>, BuildLoaderError>` containing the loader on success,
+    /// or an error if the thread pool could not be created.
+    pub fn build(self) -> Result<Loader<'a, O, I>, BuildLoaderError> {
+        let thread_pool = match (self.thread_pool, self.num_threads) {
+            (Some(pool), _) => Some(pool),
+            (None, Some(num_threads)) => Some(Arc::new(
+                ThreadPoolBuilder::new().num_threads(num_threads).build()?,
+            )),
+            (None, None) => None,
+        };
+
+        let mut loader = Loader::new();
+        loader.thread_pool = thread_pool;
+        Ok(loader)
+    }
+}
+
+impl<O: Send + Sync, I: Info> Default for LoaderBuilder<'_, O, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<O: Send + Sync, I: Info> Drop for Loader<'_, O, I> {
     fn drop(&mut self) {
-        self.stop().unwrap();
+        // Panicking here would abort the process if the loader is dropped during unwinding
+        if let Err(e) = self.stop() {
+            warn!("Failed to stop the plugin loader: {e}");
+            #[cfg(not(feature = "log"))]
+            eprintln!("Failed to stop the plugin loader: {e}");
+        }
     }
 }
 
 mod private_loader {
-    use std::path::Path;
+    use std::{
+        cmp::Reverse,
+        path::{Path, PathBuf},
+        sync::Arc,
+        time::Instant,
+    };
+    #[cfg(feature = "watch")]
+    use std::{
+        collections::HashMap,
+        sync::{Mutex, mpsc},
+    };
+    #[cfg(feature = "async")]
+    use std::{future::Future, pin::Pin};
+    #[cfg(feature = "archive")]
+    use std::{
+        io::Cursor,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use rayon::ThreadPool;
+    use semver::VersionReq;
 
+    #[cfg(feature = "archive")]
+    use crate::utils::archive::unzip_from_reader;
     use crate::{
         Api, Bundle, Depend, Info, LoadPluginContext, Manager, Plugin, PluginInfo,
         RegisterPluginContext,
+        function::{Function, Request, fill_optional_args},
         utils::{
-            LoadPluginError, Ptr, RegisterManagerError, RegisterPluginError, StopLoaderError,
+            LoadPluginError, MergeLoaderError, PluginCallRequestError, Ptr, RegisterManagerError,
+            RegisterPluginError, ReloadPluginError, StopLoaderError, SwapPluginError,
             UnloadPluginError, UnregisterManagerError, UnregisterPluginError,
+            logging::{debug, trace, warn},
         },
+        variable::Variable,
+    };
+    #[cfg(feature = "watch")]
+    use crate::{
+        WatchHandle, WatchReport,
+        utils::WatchError,
+        watch::{PluginWatch, WATCH_DEBOUNCE},
     };
 
-    pub fn stop_plugins<O: Send + Sync, I: Info>(
+    pub fn install<R, OP>(thread_pool: &Option<Arc<ThreadPool>>, op: OP) -> R
+    where
+        R: Send,
+        OP: FnOnce() -> R + Send,
+    {
+        match thread_pool {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+
+    pub fn stop<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'_, O, I>,
+    ) -> Result<(), StopLoaderError> {
+        // The loader is already stopped, e.g. by an explicit call before the drop
+        if loader.plugins.is_empty() && loader.managers.is_empty() {
+            return Ok(());
+        }
+
+        debug!(
+            "Stopping the loader with {} plugins and {} managers",
+            loader.plugins.len(),
+            loader.managers.len()
+        );
+
+        // Every plugin and manager is detached, even if some of them fail
+        let plugins = stop_plugins(loader);
+        let managers = stop_managers(loader);
+
+        match (plugins.is_empty(), managers.is_empty()) {
+            (true, true) => Ok(()),
+            (false, true) => Err(StopLoaderError::UnregisterPluginFailed(plugins)),
+            (true, false) => Err(StopLoaderError::UnregisterManagerFailed(managers)),
+            (false, false) => Err(StopLoaderError::UnregisterFailed { plugins, managers }),
+        }
+    }
+
+    fn stop_plugins<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'_, O, I>,
+    ) -> Vec<UnregisterPluginError> {
+        let mut errors = unload_plugins(loader);
+
+        while !loader.plugins.is_empty() {
+            if let Err(e) = forced_unregister_plugin(&mut loader.plugins, 0_usize) {
+                warn!("Failed to unregister a plugin while stopping the loader: {e}");
+                errors.push(e);
+            }
+        }
+
+        errors
+    }
+
+    pub fn unload_all_plugins<O: Send + Sync, I: Info>(
         loader: &mut super::Loader<'_, O, I>,
     ) -> Result<(), StopLoaderError> {
+        let errors = unload_plugins(loader);
+
+        match !errors.is_empty() {
+            true => Err(StopLoaderError::UnregisterPluginFailed(errors)),
+            false => Ok(()),
+        }
+    }
+
+    fn unload_plugins<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'_, O, I>,
+    ) -> Vec<UnregisterPluginError> {
         // Sort plugins in order of their dependencies
         let sort_plugins = sort_plugins(
             &loader.plugins,
@@ -1493,50 +3051,32 @@ mod private_loader {
         );
 
         // Unload plugins
-        let errors = sort_plugins
-            .iter()
-            .map(|index| {
-                forced_unload_plugin(&mut loader.plugins, index.clone())
-                    .map_err(|e| UnregisterPluginError::UnloadError(e))
+        sort_plugins
+            .into_iter()
+            .filter_map(|index| {
+                let error = forced_unload_plugin(&mut loader.plugins, index).err()?;
+                warn!(
+                    "Failed to unload plugin {}: {error}",
+                    loader.plugins[index].info.bundle
+                );
+                Some(error)
             })
-            .partition::<Vec<_>, _>(|r| r.is_err())
-            .0;
+            .map(UnregisterPluginError::UnloadError)
+            .collect()
+    }
 
-        if !errors.is_empty() {
-            return Err(StopLoaderError::UnregisterPluginFailed(
-                errors.into_iter().map(|r| r.err().unwrap()).collect(),
-            ));
-        }
-
-        //TODO: Add debug output
-        let errors = (0..loader.plugins.len())
-            .map(|_| forced_unregister_plugin(&mut loader.plugins, 0_usize))
-            .partition::<Vec<_>, _>(|r| r.is_err())
-            .0;
-
-        match !errors.is_empty() {
-            true => Err(StopLoaderError::UnregisterPluginFailed(
-                errors.into_iter().map(|r| r.err().unwrap()).collect(),
-            )),
-            false => Ok(()),
-        }
-    }
-
-    pub fn stop_managers<'a, O: Send + Sync, I: Info>(
+    fn stop_managers<'a, O: Send + Sync, I: Info>(
         loader: &'a mut super::Loader<'_, O, I>,
-    ) -> Result<(), StopLoaderError> {
+    ) -> Vec<UnregisterManagerError> {
         // Detach plugin managers from the loader
         let mut errors = vec![];
         while !loader.managers.is_empty() {
             if let Err(e) = forced_unregister_manager(&mut loader.managers, 0_usize) {
+                warn!("Failed to unregister a manager while stopping the loader: {e}");
                 errors.push(e);
             }
         }
-
-        match !errors.is_empty() {
-            true => Err(StopLoaderError::UnregisterManagerFailed(errors)),
-            false => Ok(()),
-        }
+        errors
     }
 
     /*
@@ -1569,16 +3109,7 @@ mod private_loader {
                     .depends()
                     .iter()
                     .chain(pl.info.info.optional_depends().iter())
-                    .any(|d| {
-                        *d == *bundle
-                            && plugins
-                                .iter()
-                                .find(|p| {
-                                    d.version.matches(&p.info.bundle.version)
-                                        && p.info.bundle.version > bundle.version
-                                })
-                                .is_none()
-                    })
+                    .any(|d| is_highest_match(plugins, d, bundle))
                     .then_some(i)
             });
 
@@ -1612,29 +3143,15 @@ mod private_loader {
             .iter()
             .chain(plugin_info.info.optional_depends().iter());
         'outer: for depend in depends {
-            if !result.iter().any(|inx| {
-                *depend == plugins[*inx].info.bundle
-                    && plugins
-                        .iter()
-                        .find(|p| {
-                            depend.version.matches(&p.info.bundle.version)
-                                && p.info.bundle.version > plugins[*inx].info.bundle.version
-                        })
-                        .is_none()
-            }) {
+            if !result
+                .iter()
+                .any(|inx| is_highest_match(plugins, depend, &plugins[*inx].info.bundle))
+            {
                 let mut plugin = None;
 
                 for index in plugins_set.iter() {
                     let plug_info = &plugins[*index].info;
-                    if *depend == plug_info.bundle
-                        && plugins
-                            .iter()
-                            .find(|p| {
-                                depend.version.matches(&p.info.bundle.version)
-                                    && p.info.bundle.version > plug_info.bundle.version
-                            })
-                            .is_none()
-                    {
+                    if is_highest_match(plugins, depend, &plug_info.bundle) {
                         plugin = Some(index);
                         continue;
                     }
@@ -1656,33 +3173,226 @@ mod private_loader {
         }
     }
 
+    pub fn has_format<O: Send + Sync, I: Info>(
+        manager: &dyn Manager<'_, O, I>,
+        format: &str,
+    ) -> bool {
+        manager.formats().contains(&format)
+    }
+
+    // Managers are resolved by descending priority, then by registration order
+    pub fn find_manager<O: Send + Sync, I: Info>(
+        managers: &[Box<dyn Manager<'_, O, I>>],
+        format: &str,
+    ) -> Option<usize> {
+        managers
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| has_format(m.as_ref(), format))
+            .max_by_key(|(index, m)| (m.priority(), Reverse(*index)))
+            .map(|(index, _)| index)
+    }
+
+    // Requests are called on the highest enabled version of every plugin
+    pub fn is_request_target<O: Send + Sync, I: Info>(
+        plugins: &[Plugin<'_, O, I>],
+        plugin: &Plugin<'_, O, I>,
+    ) -> bool {
+        plugin.is_enabled
+            && !plugins.iter().any(|pl| {
+                pl.is_enabled
+                    && pl.info.bundle.id == plugin.info.bundle.id
+                    && pl.info.bundle.version > plugin.info.bundle.version
+            })
+    }
+
+    // Clones the requests of the plugins targeted by a request call, so that they can
+    // be called without borrowing the loader
+    pub fn request_functions<O: Send + Sync, I: Info>(
+        plugins: &[Plugin<'_, O, I>],
+        name: &str,
+    ) -> Vec<(Bundle, Option<Arc<dyn Function<Output = O>>>)> {
+        plugins
+            .iter()
+            .filter(|plugin| is_request_target(plugins, plugin))
+            .map(|plugin| {
+                let request = plugin.requests.iter().find(|r| r.name() == name);
+                (plugin.info.bundle.clone(), request.cloned())
+            })
+            .collect()
+    }
+
+    pub fn call_request_function<O: Send + Sync>(
+        request: Option<Arc<dyn Function<Output = O>>>,
+        args: &[Variable],
+    ) -> Result<O, PluginCallRequestError> {
+        request
+            .map(|request| request.call(&fill_optional_args(&request.inputs(), args)))
+            .ok_or(PluginCallRequestError::NotFound)
+    }
+
     pub fn forced_register_manager<'a, O: Send + Sync, I: Info>(
         loader: &mut super::Loader<'a, O, I>,
         mut manager: Box<dyn Manager<'a, O, I>>,
     ) -> Result<(), RegisterManagerError> {
         manager.as_mut().register_manager()?;
+        debug!(
+            "Registered manager for formats {:?} with priority {}",
+            manager.formats(),
+            manager.priority()
+        );
         loader.managers.push(manager);
         Ok(())
     }
 
+    // Finds a format of the manager that is occupied by another manager of the same
+    // priority, such a format would be ambiguous
+    fn find_occupied_format<'a, O: Send + Sync, I: Info>(
+        managers: &[Box<dyn Manager<'a, O, I>>],
+        manager: &dyn Manager<'a, O, I>,
+    ) -> Option<&'static str> {
+        let formats = manager.formats();
+        let priority = manager.priority();
+        managers
+            .iter()
+            .filter(|m| m.priority() == priority)
+            .flat_map(|m| m.formats())
+            .find(|format| formats.contains(format))
+    }
+
     pub fn register_manager<'a, O: Send + Sync, I: Info>(
         loader: &mut super::Loader<'a, O, I>,
         manager: Box<dyn Manager<'a, O, I>>,
     ) -> Result<(), RegisterManagerError> {
-        if let Some(_) = loader.managers.iter().find(|m| manager == **m) {
+        if let Some(format) = find_occupied_format(&loader.managers, manager.as_ref()) {
             return Err(RegisterManagerError::AlreadyOccupiedFormat(
-                manager.format().to_string(),
+                format.to_string(),
             ));
         }
 
         forced_register_manager(loader, manager)
     }
 
+    pub fn merge<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        mut other: super::Loader<'static, O, I>,
+    ) -> Result<(), MergeLoaderError> {
+        // Check for conflicts before anything is moved
+        for manager in other.managers.iter() {
+            if let Some(format) = find_occupied_format(&loader.managers, manager.as_ref()) {
+                return Err(MergeLoaderError::AlreadyOccupiedFormat(format.to_string()));
+            }
+        }
+
+        if let Some(plugin) = other
+            .plugins
+            .iter()
+            .find(|plugin| loader.get_plugin_by_bundle(&plugin.info.bundle).is_some())
+        {
+            let bundle = &plugin.info.bundle;
+            return Err(MergeLoaderError::AlreadyExistsIDAndVersion(
+                bundle.id.clone(),
+                bundle.version.clone(),
+            ));
+        }
+
+        let mut requests = loader.requests.clone();
+        for request in other.requests.iter() {
+            match requests.iter().find(|req| req.name == request.name) {
+                Some(req) if req != request => {
+                    return Err(MergeLoaderError::ConflictingRequest(request.name.clone()));
+                }
+                Some(_) => (),
+                None => requests.push(request.clone()),
+            }
+        }
+
+        // Loaded plugins must implement the requests of both loaders
+        for plugin in loader.plugins.iter().chain(other.plugins.iter()) {
+            if !plugin.is_load {
+                continue;
+            }
+
+            let not_found_requests: Vec<_> = requests
+                .iter()
+                .filter(|req| !plugin.requests.iter().any(|r| r.name() == req.name))
+                .cloned()
+                .collect();
+            if !not_found_requests.is_empty() {
+                return Err(MergeLoaderError::RequestsNotFound(
+                    plugin.info.bundle.clone(),
+                    not_found_requests,
+                ));
+            }
+        }
+
+        // The `Api` of a loaded plugin points to `other`, so it is loaded again after the move
+        let loaded: Vec<_> = other
+            .plugins
+            .iter()
+            .enumerate()
+            .filter_map(|(index, plugin)| plugin.is_load.then_some(index))
+            .collect();
+        for index in sort_plugins(&other.plugins, loaded.clone()) {
+            forced_unload_plugin(&mut other.plugins, index)?;
+        }
+
+        // Plugins point to the boxed managers, which do not move along with the boxes
+        let offset = loader.plugins.len();
+        loader.managers.append(&mut other.managers);
+        loader.plugins.append(&mut other.plugins);
+
+        for function in other.registry.drain(..) {
+            if !loader
+                .registry
+                .iter()
+                .any(|f| f.as_ref() == function.as_ref())
+            {
+                loader.registry.push(function);
+            }
+        }
+        loader.requests = requests;
+
+        if let (Some(metrics), Some(other_metrics)) =
+            (&loader.load_metrics, other.load_metrics.take())
+        {
+            metrics
+                .lock()
+                .unwrap()
+                .extend(other_metrics.into_inner().unwrap());
+        }
+
+        let loaded = loaded.into_iter().map(|index| offset + index).collect();
+        Ok(load_plugins_batch(loader, loaded)?)
+    }
+
+    pub fn register_managers<'a, O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'a, O, I>,
+        managers: impl IntoIterator<Item = Box<dyn Manager<'a, O, I>>>,
+    ) -> Result<(), RegisterManagerError> {
+        let registered = loader.managers.len();
+        for manager in managers {
+            if let Err(e) = register_manager(loader, manager) {
+                // Roll back the managers registered so far, newest first
+                while loader.managers.len() > registered {
+                    let index = loader.managers.len() - 1;
+                    if let Err(e) = forced_unregister_manager(&mut loader.managers, index) {
+                        warn!("Failed to unregister a manager while rolling back: {e}");
+                    }
+                }
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
     pub fn forced_unregister_manager<O: Send + Sync, I: Info>(
         managers: &mut Vec<Box<dyn Manager<'_, O, I>>>,
         index: usize,
     ) -> Result<(), UnregisterManagerError> {
-        match managers.remove(index).unregister_manager() {
+        let mut manager = managers.remove(index);
+        debug!("Unregistering manager for formats {:?}", manager.formats());
+        match manager.unregister_manager() {
             Ok(_) => Ok(()),
             Err(e) => Err(UnregisterManagerError::UnregisterManagerByManager(e)),
         }
@@ -1737,23 +3447,31 @@ mod private_loader {
 
     pub fn forced_register_plugin<'a, O: Send + Sync, I: Info>(
         plugins: &mut Vec<Plugin<'a, O, I>>,
-        manager: Ptr<'a, Box<dyn Manager<'a, O, I>>>,
+        manager: Ptr<'a, dyn Manager<'a, O, I> + 'static>,
         plugin_info: PluginInfo<I>,
     ) -> Result<Bundle, RegisterPluginError> {
         let bundle = plugin_info.bundle.clone();
+        debug!(
+            "Registered plugin {bundle} from {}",
+            plugin_info.path.display()
+        );
         plugins.push(Plugin::<'a>::new(manager, plugin_info));
         Ok(bundle)
     }
 
-    pub fn register_plugin<'a, O: Send + Sync, I: Info>(
-        loader: &mut super::Loader<'a, O, I>,
+    // Check the plugin path and make sure the plugin is not registered yet
+    pub fn check_plugin_path<O: Send + Sync, I: Info>(
+        loader: &super::Loader<'_, O, I>,
         path: &str,
-    ) -> Result<Bundle, RegisterPluginError> {
+    ) -> Result<(PathBuf, Bundle), RegisterPluginError> {
         let path = Path::new(path).to_path_buf();
 
-        if !path.is_dir() {
+        if !path.exists() {
             return Err(RegisterPluginError::NotFound);
         }
+        if !path.is_dir() {
+            return Err(RegisterPluginError::NotADirectory(path));
+        }
 
         if let None = path.extension() {
             return Err(RegisterPluginError::UnknownManagerFormat("".to_string()));
@@ -1761,13 +3479,72 @@ mod private_loader {
 
         let bundle = Bundle::from_filename(path.file_name().unwrap())?;
 
-        // Check if such a plugin already exists
-        if loader.get_plugin_by_bundle(&bundle).is_some() {
-            return Err(RegisterPluginError::AlreadyExistsIDAndVersion(
+        // Store an absolute path without symlinks, so it does not depend on how it was spelled
+        let path = std::fs::canonicalize(&path).map_err(RegisterPluginError::Canonicalize)?;
+
+        check_plugin_exists(loader, &bundle)?;
+        Ok((path, bundle))
+    }
+
+    fn check_plugin_exists<O: Send + Sync, I: Info>(
+        loader: &super::Loader<'_, O, I>,
+        bundle: &Bundle,
+    ) -> Result<(), RegisterPluginError> {
+        match loader.get_plugin_by_bundle(bundle) {
+            Some(_) => Err(RegisterPluginError::AlreadyExistsIDAndVersion(
                 bundle.id.clone(),
                 bundle.version.clone(),
-            ));
+            )),
+            None => Ok(()),
         }
+    }
+
+    pub fn find_plugin_paths<O: Send + Sync, I: Info>(
+        loader: &super::Loader<'_, O, I>,
+        dir: &Path,
+        recursive: bool,
+        result: &mut Vec<PathBuf>,
+    ) -> Result<(), RegisterPluginError> {
+        let mut paths = std::fs::read_dir(dir)
+            .and_then(|entries| {
+                entries
+                    .map(|entry| entry.map(|entry| entry.path()))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(RegisterPluginError::ReadDirectoryFailed)?;
+        paths.sort();
+
+        for path in paths.into_iter().filter(|path| path.is_dir()) {
+            let is_plugin = path
+                .extension()
+                .and_then(|format| format.to_str())
+                .is_some_and(|format| loader.get_manager_ref(format).is_some());
+
+            if is_plugin {
+                result.push(path);
+            } else if recursive {
+                find_plugin_paths(loader, &path, recursive, result)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn register_plugin<'a, O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'a, O, I>,
+        path: &str,
+    ) -> Result<Bundle, RegisterPluginError> {
+        let (path, bundle) = check_plugin_path(loader, path)?;
+        register_checked_plugin(loader, path, bundle)
+    }
+
+    // Registers a plugin whose path has already been checked by `check_plugin_path`
+    pub fn register_checked_plugin<'a, O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'a, O, I>,
+        path: PathBuf,
+        bundle: Bundle,
+    ) -> Result<Bundle, RegisterPluginError> {
+        // The loader may have changed since the path was checked
+        check_plugin_exists(loader, &bundle)?;
 
         // Looking for a suitable manager
         let plugin_format = bundle.format.clone();
@@ -1775,25 +3552,152 @@ mod private_loader {
             .get_manager_mut(plugin_format.as_str())
             .ok_or(RegisterPluginError::UnknownManagerFormat(plugin_format))?;
 
-        // Manager registers plugin
-        let info = manager.register_plugin(RegisterPluginContext {
+        // Manager verifies and registers plugin
+        let context = RegisterPluginContext {
             path: &path,
             bundle: &bundle,
-        })?;
+        };
+        manager.verify_plugin(&context)?;
+        let info = manager.register_plugin(context)?;
         let plugin_info = PluginInfo { path, bundle, info };
 
         // Register plugin
-        let manager = Ptr::<'a>::new(manager);
+        let manager = Ptr::<'a, _>::new(&mut **manager);
         forced_register_plugin(&mut loader.plugins, manager, plugin_info)
     }
 
+    pub fn register_virtual_plugin<'a, O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'a, O, I>,
+        format: &str,
+        plugin_info: PluginInfo<I>,
+    ) -> Result<Bundle, RegisterPluginError> {
+        check_plugin_exists(loader, &plugin_info.bundle)?;
+
+        if plugin_info.bundle.format != format {
+            return Err(RegisterPluginError::MismatchedFormat {
+                expected: format.to_string(),
+                found: plugin_info.bundle.format.clone(),
+            });
+        }
+
+        // Looking for a suitable manager
+        let manager = loader
+            .get_manager_mut(format)
+            .ok_or_else(|| RegisterPluginError::UnknownManagerFormat(format.to_string()))?;
+        manager.register_virtual_plugin(&plugin_info)?;
+
+        // Register plugin
+        let manager = Ptr::<'a, _>::new(&mut **manager);
+        let bundle = forced_register_plugin(&mut loader.plugins, manager, plugin_info)?;
+        loader.plugins.last_mut().unwrap().is_virtual = true;
+        Ok(bundle)
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn register_plugin_async<'a, O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'a, O, I>,
+        path: &str,
+    ) -> Result<Bundle, RegisterPluginError> {
+        let (path, bundle) = check_plugin_path(loader, path)?;
+
+        // Looking for a suitable manager
+        let plugin_format = bundle.format.clone();
+        let manager = loader
+            .get_manager_mut(plugin_format.as_str())
+            .ok_or(RegisterPluginError::UnknownManagerFormat(plugin_format))?;
+
+        // Manager verifies and registers plugin
+        let context = RegisterPluginContext {
+            path: &path,
+            bundle: &bundle,
+        };
+        manager.verify_plugin_async(&context).await?;
+        let info = manager.register_plugin_async(context).await?;
+        let plugin_info = PluginInfo { path, bundle, info };
+
+        // Register plugin
+        let manager = Ptr::<'a, _>::new(&mut **manager);
+        forced_register_plugin(&mut loader.plugins, manager, plugin_info)
+    }
+
+    #[cfg(feature = "archive")]
+    pub fn register_plugin_from_bytes<'a, O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'a, O, I>,
+        name: &str,
+        data: &[u8],
+    ) -> Result<Bundle, RegisterPluginError> {
+        static TEMP_DIR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let bundle = Bundle::from_filename(name)?;
+
+        // Check if such a plugin already exists
+        if loader.get_plugin_by_bundle(&bundle).is_some() {
+            return Err(RegisterPluginError::AlreadyExistsIDAndVersion(
+                bundle.id.clone(),
+                bundle.version.clone(),
+            ));
+        }
+
+        // Extract the archive to a unique temporary directory
+        let temp_dir = std::env::temp_dir().join(format!(
+            "plux-{}-{}",
+            std::process::id(),
+            TEMP_DIR_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let path = temp_dir.join(name);
+
+        let result = unzip_from_reader(Cursor::new(data), &path)
+            .map_err(RegisterPluginError::from)
+            .and_then(|_| register_plugin(loader, &path.to_string_lossy()));
+
+        match result {
+            Ok(bundle) => {
+                let plugin = loader
+                    .plugins
+                    .iter_mut()
+                    .find(|plugin| **plugin == bundle)
+                    .unwrap();
+                plugin.temp_dir = Some(temp_dir);
+                Ok(bundle)
+            }
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&temp_dir);
+                Err(e)
+            }
+        }
+    }
+
     pub fn forced_unregister_plugin<O: Send + Sync, I: Info>(
         plugins: &mut Vec<Plugin<'_, O, I>>,
         index: usize,
     ) -> Result<(), UnregisterPluginError> {
+        take_plugin(plugins, index).map(|_| ())
+    }
+
+    // Unregisters the plugin and returns its information
+    fn take_plugin<O: Send + Sync, I: Info>(
+        plugins: &mut Vec<Plugin<'_, O, I>>,
+        index: usize,
+    ) -> Result<PluginInfo<I>, UnregisterPluginError> {
         let plugin = plugins.remove(index);
-        plugin.manager.as_mut().unregister_plugin(&plugin)?;
-        Ok(())
+        debug!("Unregistering plugin {}", plugin.info.bundle);
+        let result = plugin
+            .manager
+            .as_mut()
+            .unregister_plugin(&plugin)
+            .map_err(UnregisterPluginError::from);
+
+        // The plugin is removed even if its manager fails, so its files are removed too
+        #[cfg(feature = "archive")]
+        let result = match &plugin.temp_dir {
+            Some(temp_dir) => result.and(
+                std::fs::remove_dir_all(temp_dir)
+                    .map_err(UnregisterPluginError::RemoveTempDirFailed),
+            ),
+            None => result,
+        };
+
+        result.map(|_| plugin.info)
     }
 
     pub fn unregister_plugin<'a, O: Send + Sync, I: Info>(
@@ -1804,17 +3708,32 @@ mod private_loader {
         forced_unregister_plugin(plugins, index)
     }
 
-    pub fn forced_load_plugin<O: Send + Sync, I: Info>(
-        loader: *mut super::Loader<'static, O, I>,
-        index: usize,
-        depends: Vec<(Bundle, bool)>,
-    ) -> Result<(), LoadPluginError> {
-        let manager = Ptr::new(unsafe { &*loader }.plugins[index].manager.as_ptr());
+    pub fn unregister_plugins<O: Send + Sync, I: Info>(
+        plugins: &mut Vec<Plugin<'_, O, I>>,
+        bundles: &[Bundle],
+    ) -> Result<(), UnregisterPluginError> {
+        if !bundles
+            .iter()
+            .all(|bundle| plugins.iter().any(|plugin| *plugin == *bundle))
+        {
+            return Err(UnregisterPluginError::NotFound);
+        }
 
-        // Get plugin and its dependencies
-        let plugin = &mut unsafe { &mut *loader }.plugins[index];
+        let bundles = unload_plugins_by_bundles(plugins, bundles)?;
 
-        // Split dependencies
+        // Unregister plugins, dependents first
+        for bundle in bundles.iter() {
+            let index = plugins
+                .iter()
+                .position(|plugin| *plugin == *bundle)
+                .ok_or(UnregisterPluginError::NotFound)?;
+            forced_unregister_plugin(plugins, index)?;
+        }
+        Ok(())
+    }
+
+    // Split dependencies into required and optional ones
+    fn split_depends(depends: Vec<(Bundle, bool)>) -> (Vec<Bundle>, Vec<Bundle>) {
         let mut deps = vec![];
         let mut opt_deps = vec![];
 
@@ -1824,23 +3743,240 @@ mod private_loader {
                 false => opt_deps.push(bundle),
             }
         }
+        (deps, opt_deps)
+    }
+
+    fn finish_load_plugin<O: Send + Sync, I: Info>(
+        loader: &super::Loader<'static, O, I>,
+        index: usize,
+        timer: Option<Instant>,
+    ) {
+        if let (Some(timer), Some(metrics)) = (timer, &loader.load_metrics) {
+            metrics
+                .lock()
+                .unwrap()
+                .insert(loader.plugins[index].info.bundle.clone(), timer.elapsed());
+        }
+    }
+
+    pub fn forced_load_plugin<O: Send + Sync, I: Info>(
+        loader: *mut super::Loader<'static, O, I>,
+        index: usize,
+        depends: Vec<(Bundle, bool)>,
+    ) -> Result<(), LoadPluginError> {
+        let manager = Ptr::new(unsafe { &*loader }.plugins[index].manager.as_ptr());
+
+        // Get plugin and its dependencies
+        let plugin = &mut unsafe { &mut *loader }.plugins[index];
+        let (deps, opt_deps) = split_depends(depends);
+        let (resolved_depends, resolved_optional_depends) = (deps.clone(), opt_deps.clone());
 
         // Load plugin
         let bundle = plugin.info.bundle.clone();
+        let timer = unsafe { &*loader }
+            .load_metrics
+            .is_some()
+            .then(Instant::now);
 
         manager.as_mut().load_plugin(
-            LoadPluginContext::new(plugin, &unsafe { &*loader }.requests),
+            LoadPluginContext::new(
+                plugin,
+                &unsafe { &*loader }.requests,
+                &unsafe { &*loader }.default_policy,
+            ),
             Api::new(Ptr::new(loader), bundle, deps, opt_deps),
         )?;
 
         plugin.is_load = true;
+        plugin.resolved_depends = resolved_depends;
+        plugin.resolved_optional_depends = resolved_optional_depends;
+        debug!("Loaded plugin {}", plugin.info.bundle);
+        finish_load_plugin(unsafe { &*loader }, index, timer);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    async fn forced_load_plugin_async<O: Send + Sync, I: Info>(
+        loader: *mut super::Loader<'static, O, I>,
+        index: usize,
+        depends: Vec<(Bundle, bool)>,
+    ) -> Result<(), LoadPluginError> {
+        let manager = Ptr::new(unsafe { &*loader }.plugins[index].manager.as_ptr());
+
+        // Get plugin and its dependencies
+        let plugin = &mut unsafe { &mut *loader }.plugins[index];
+        let (deps, opt_deps) = split_depends(depends);
+        let (resolved_depends, resolved_optional_depends) = (deps.clone(), opt_deps.clone());
+
+        // Load plugin
+        let bundle = plugin.info.bundle.clone();
+        let timer = unsafe { &*loader }
+            .load_metrics
+            .is_some()
+            .then(Instant::now);
+
+        manager
+            .as_mut()
+            .load_plugin_async(
+                LoadPluginContext::new(
+                    plugin,
+                    &unsafe { &*loader }.requests,
+                    &unsafe { &*loader }.default_policy,
+                ),
+                Api::new(Ptr::new(loader), bundle, deps, opt_deps),
+            )
+            .await?;
+
+        plugin.is_load = true;
+        plugin.resolved_depends = resolved_depends;
+        plugin.resolved_optional_depends = resolved_optional_depends;
+        debug!("Loaded plugin {}", plugin.info.bundle);
+        finish_load_plugin(unsafe { &*loader }, index, timer);
+
+        Ok(())
+    }
+
+    // Checks if no higher version of the plugin is registered
+    pub fn is_latest<O: Send + Sync, I: Info>(
+        plugins: &[Plugin<'_, O, I>],
+        bundle: &Bundle,
+    ) -> bool {
+        !plugins
+            .iter()
+            .any(|pl| pl.info.bundle.id == bundle.id && pl.info.bundle.version > bundle.version)
+    }
+
+    // Find the highest registered version of the plugin
+    pub fn find_latest<O: Send + Sync, I: Info>(
+        plugins: &[Plugin<'_, O, I>],
+        id: &str,
+    ) -> Option<usize> {
+        plugins.iter().position(|plugin| {
+            plugin.info.bundle.id == id && is_latest(plugins, &plugin.info.bundle)
+        })
+    }
+
+    // Checks if the bundle is the highest registered version matching the dependency
+    pub fn is_highest_match<O: Send + Sync, I: Info>(
+        plugins: &[Plugin<'_, O, I>],
+        depend: &Depend,
+        bundle: &Bundle,
+    ) -> bool {
+        *depend == *bundle
+            && !plugins
+                .iter()
+                .any(|p| *depend == p.info.bundle && p.info.bundle.version > bundle.version)
+    }
+
+    // Find the highest registered version of the plugin matching the dependency
+    pub fn find_depend<O: Send + Sync, I: Info>(
+        plugins: &Vec<Plugin<'_, O, I>>,
+        depend: &Depend,
+    ) -> Option<usize> {
+        plugins
+            .iter()
+            .position(|plugin| is_highest_match(plugins, depend, &plugin.info.bundle))
+    }
 
+    // First feature required by the dependency that the plugin does not provide
+    fn missing_feature<O: Send + Sync, I: Info>(
+        plugin: &Plugin<'_, O, I>,
+        depend: &Depend,
+    ) -> Option<String> {
+        let provides = plugin.info.info.provides();
+        depend
+            .features
+            .iter()
+            .find(|feature| !provides.contains(feature))
+            .cloned()
+    }
+
+    // Required and optional dependencies of the plugin, in declaration order
+    fn plugin_depends<O: Send + Sync, I: Info>(plugin: &Plugin<'_, O, I>) -> Vec<(bool, Depend)> {
+        let info = &plugin.info.info;
+        info.depends()
+            .clone()
+            .into_iter()
+            .map(|d| (true, d))
+            .chain(
+                info.optional_depends()
+                    .clone()
+                    .into_iter()
+                    .map(|d| (false, d)),
+            )
+            .collect()
+    }
+
+    fn map_depend_error(depend: Depend, error: LoadPluginError) -> LoadPluginError {
+        match error {
+            LoadPluginError::CircularDependency(_) => error,
+            e => LoadPluginError::LoadDependency {
+                depend: depend,
+                error: Box::new(e),
+            },
+        }
+    }
+
+    // Collects the problems that would prevent the plugin from loading, without loading it
+    pub fn validate_plugin<O: Send + Sync, I: Info>(
+        plugins: &Vec<Plugin<'_, O, I>>,
+        index: usize,
+        chain: &mut Vec<Bundle>,
+        validated: &mut Vec<usize>,
+    ) -> Vec<LoadPluginError> {
+        let plugin = &plugins[index];
+        if plugin.is_load || validated.contains(&index) {
+            return vec![];
+        }
+
+        if let Err(e) = enter_chain(chain, plugin.info.bundle.clone()) {
+            return vec![e];
+        }
+
+        let mut errors = vec![];
+        let mut not_found_depends = vec![];
+        for (is_depend, depend) in plugin_depends(plugin) {
+            match find_depend(plugins, &depend) {
+                Some(i) => match missing_feature(&plugins[i], &depend) {
+                    Some(feature) if is_depend => {
+                        errors.push(LoadPluginError::MissingFeature { depend, feature })
+                    }
+                    Some(_) => {}
+                    None => errors.extend(
+                        validate_plugin(plugins, i, chain, validated)
+                            .into_iter()
+                            .map(|e| map_depend_error(depend.clone(), e)),
+                    ),
+                },
+                None if is_depend => not_found_depends.push(depend),
+                None => {}
+            }
+        }
+        chain.pop();
+        validated.push(index);
+
+        if !not_found_depends.is_empty() {
+            errors.push(LoadPluginError::NotFoundDependencies(not_found_depends));
+        }
+        errors
+    }
+
+    // Check that the plugin is not already being loaded down the chain
+    fn enter_chain(chain: &mut Vec<Bundle>, bundle: Bundle) -> Result<(), LoadPluginError> {
+        if let Some(position) = chain.iter().position(|b| *b == bundle) {
+            let mut cycle = chain[position..].to_vec();
+            cycle.push(bundle);
+            return Err(LoadPluginError::CircularDependency(cycle));
+        }
+        chain.push(bundle);
         Ok(())
     }
 
     fn load_depends<'a, O, I, IT>(
         loader: &'a mut super::Loader<'static, O, I>,
         depends_iter: IT,
+        chain: &mut Vec<Bundle>,
     ) -> Result<(Vec<(Bundle, bool)>, Vec<Depend>), LoadPluginError>
     where
         O: Send + Sync,
@@ -1851,24 +3987,71 @@ mod private_loader {
         let mut not_found_depends = vec![];
 
         for (is_depend, depend) in depends_iter.into_iter() {
-            if let Some((index, plugin)) = loader.plugins.iter().enumerate().find(|(_, plugin)| {
-                depend == plugin.info.bundle
-                    && loader
-                        .plugins
-                        .iter()
-                        .find(|p| {
-                            depend.version.matches(&p.info.bundle.version)
-                                && p.info.bundle.version > plugin.info.bundle.version
-                        })
-                        .is_none()
-            }) {
-                found_depends.push((plugin.info.bundle.clone(), is_depend));
-                load_plugin(loader, index).map_err(|e| LoadPluginError::LoadDependency {
-                    depend: depend,
-                    error: Box::new(e),
-                })?;
+            if let Some(index) = find_depend(&loader.plugins, &depend) {
+                if let Some(feature) = missing_feature(&loader.plugins[index], &depend) {
+                    match is_depend {
+                        true => return Err(LoadPluginError::MissingFeature { depend, feature }),
+                        false => {
+                            debug!(
+                                "Skipped optional dependency {depend}: missing feature `{feature}`"
+                            );
+                            continue;
+                        }
+                    }
+                }
+
+                trace!(
+                    "Resolved dependency {depend} to {}",
+                    loader.plugins[index].info.bundle
+                );
+                found_depends.push((loader.plugins[index].info.bundle.clone(), is_depend));
+                load_plugin_chain(loader, index, chain).map_err(|e| map_depend_error(depend, e))?;
+            } else if is_depend {
+                warn!("Dependency {depend} is not registered");
+                not_found_depends.push(depend);
+            } else {
+                trace!("Optional dependency {depend} is not registered");
+            }
+        }
+        Ok((found_depends, not_found_depends))
+    }
+
+    #[cfg(feature = "async")]
+    async fn load_depends_async<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        depends: Vec<(bool, Depend)>,
+        chain: &mut Vec<Bundle>,
+    ) -> Result<(Vec<(Bundle, bool)>, Vec<Depend>), LoadPluginError> {
+        let mut found_depends = vec![];
+        let mut not_found_depends = vec![];
+
+        for (is_depend, depend) in depends {
+            if let Some(index) = find_depend(&loader.plugins, &depend) {
+                if let Some(feature) = missing_feature(&loader.plugins[index], &depend) {
+                    match is_depend {
+                        true => return Err(LoadPluginError::MissingFeature { depend, feature }),
+                        false => {
+                            debug!(
+                                "Skipped optional dependency {depend}: missing feature `{feature}`"
+                            );
+                            continue;
+                        }
+                    }
+                }
+
+                trace!(
+                    "Resolved dependency {depend} to {}",
+                    loader.plugins[index].info.bundle
+                );
+                found_depends.push((loader.plugins[index].info.bundle.clone(), is_depend));
+                load_plugin_chain_async(loader, index, chain)
+                    .await
+                    .map_err(|e| map_depend_error(depend, e))?;
             } else if is_depend {
+                warn!("Dependency {depend} is not registered");
                 not_found_depends.push(depend);
+            } else {
+                trace!("Optional dependency {depend} is not registered");
             }
         }
         Ok((found_depends, not_found_depends))
@@ -1877,42 +4060,58 @@ mod private_loader {
     fn check_requests<O: Send + Sync, I: Info>(
         loader: &mut super::Loader<'static, O, I>,
         index: usize,
-    ) -> Vec<String> {
-        let mut plugin_requests = loader.plugins[index].requests.iter();
+    ) -> Vec<Request> {
+        // Request names are unique, so each request is implemented by at most one function
+        let plugin_requests = &loader.plugins[index].requests;
         loader
             .requests
             .iter()
-            .filter_map(|req| match plugin_requests.any(|r| r.name() == req.name) {
-                true => None,
-                false => Some(req.name.clone()),
-            })
+            .filter(|req| !plugin_requests.iter().any(|r| r.name() == req.name))
+            .cloned()
             .collect()
     }
 
     pub fn load_plugin<O: Send + Sync, I: Info>(
         loader: &mut super::Loader<'static, O, I>,
         index: usize,
+    ) -> Result<(), LoadPluginError> {
+        load_plugin_chain(loader, index, &mut vec![])
+    }
+
+    // Loads the plugins between the `begin_batch` and `end_batch` calls of all managers
+    pub fn load_plugins_batch<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        indexes: Vec<usize>,
+    ) -> Result<(), LoadPluginError> {
+        loader
+            .managers
+            .iter_mut()
+            .for_each(|manager| manager.begin_batch());
+        let result = indexes
+            .into_iter()
+            .try_for_each(|index| load_plugin(loader, index));
+        loader
+            .managers
+            .iter_mut()
+            .for_each(|manager| manager.end_batch());
+        result
+    }
+
+    fn load_plugin_chain<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        index: usize,
+        chain: &mut Vec<Bundle>,
     ) -> Result<(), LoadPluginError> {
         if loader.plugins[index].is_load {
             return Ok(());
         }
 
+        enter_chain(chain, loader.plugins[index].info.bundle.clone())?;
+
         // Load dependencies
-        let info = &loader.plugins[index].info;
-        let depends_iter = info
-            .info
-            .depends()
-            .clone()
-            .into_iter()
-            .map(|d| (true, d))
-            .chain(
-                info.info
-                    .optional_depends()
-                    .clone()
-                    .into_iter()
-                    .map(|d| (false, d)),
-            );
-        let (found_depends, not_found_depends) = load_depends(loader, depends_iter)?;
+        let depends = plugin_depends(&loader.plugins[index]);
+        let (found_depends, not_found_depends) = load_depends(loader, depends, chain)?;
+        chain.pop();
 
         if !not_found_depends.is_empty() {
             return Err(LoadPluginError::NotFoundDependencies(not_found_depends));
@@ -1925,13 +4124,385 @@ mod private_loader {
         let not_found_requests = check_requests(loader, index);
 
         if !not_found_requests.is_empty() {
-            loader.plugins[index].is_load = false;
+            loader.plugins[index].unload_state();
             return Err(LoadPluginError::RequestsNotFound(not_found_requests));
         }
 
         Ok(())
     }
 
+    #[cfg(feature = "async")]
+    pub async fn load_plugin_async<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        index: usize,
+    ) -> Result<(), LoadPluginError> {
+        load_plugin_chain_async(loader, index, &mut vec![]).await
+    }
+
+    #[cfg(feature = "async")]
+    fn load_plugin_chain_async<'f, O: Send + Sync, I: Info>(
+        loader: &'f mut super::Loader<'static, O, I>,
+        index: usize,
+        chain: &'f mut Vec<Bundle>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), LoadPluginError>> + 'f>> {
+        Box::pin(async move {
+            if loader.plugins[index].is_load {
+                return Ok(());
+            }
+
+            enter_chain(chain, loader.plugins[index].info.bundle.clone())?;
+
+            // Load dependencies
+            let depends = plugin_depends(&loader.plugins[index]);
+            let (found_depends, not_found_depends) =
+                load_depends_async(loader, depends, chain).await?;
+            chain.pop();
+
+            if !not_found_depends.is_empty() {
+                return Err(LoadPluginError::NotFoundDependencies(not_found_depends));
+            }
+
+            // Load plugin
+            forced_load_plugin_async(loader, index, found_depends).await?;
+
+            // Check for requested functions
+            let not_found_requests = check_requests(loader, index);
+
+            if !not_found_requests.is_empty() {
+                loader.plugins[index].unload_state();
+                return Err(LoadPluginError::RequestsNotFound(not_found_requests));
+            }
+
+            Ok(())
+        })
+    }
+
+    fn collect_dependents<O: Send + Sync, I: Info>(
+        plugins: &Vec<Plugin<'_, O, I>>,
+        index: usize,
+        result: &mut Vec<usize>,
+    ) {
+        let bundle = &plugins[index].info.bundle;
+
+        for (i, plug) in plugins.iter().enumerate() {
+            if !result.contains(&i) && is_dependent(plugins, plug, bundle) {
+                result.push(i);
+                collect_dependents(plugins, i, result);
+            }
+        }
+    }
+
+    // Checks if a loaded plugin uses the bundle as a dependency
+    pub fn is_dependent<O: Send + Sync, I: Info>(
+        plugins: &[Plugin<'_, O, I>],
+        plugin: &Plugin<'_, O, I>,
+        bundle: &Bundle,
+    ) -> bool {
+        plugin.is_load
+            && plugin
+                .info
+                .info
+                .depends()
+                .iter()
+                .chain(plugin.info.info.optional_depends().iter())
+                .any(|depend| is_highest_match(plugins, depend, bundle))
+    }
+
+    #[cfg(feature = "watch")]
+    pub fn watch_plugins<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+    ) -> Result<WatchHandle, WatchError> {
+        use notify::{RecursiveMode, Watcher};
+
+        // The watcher thread only queues the events, they are applied by `process_watch_events`
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = sender.send((Instant::now(), event));
+        })?;
+
+        for plugin in loader.plugins.iter() {
+            if plugin.is_virtual {
+                continue;
+            }
+            #[cfg(feature = "archive")]
+            if plugin.temp_dir.is_some() {
+                continue;
+            }
+
+            watcher.watch(&plugin.info.path, RecursiveMode::Recursive)?;
+        }
+
+        loader.watch = Some(PluginWatch {
+            events: Mutex::new(events),
+            pending: HashMap::new(),
+        });
+        Ok(WatchHandle { _watcher: watcher })
+    }
+
+    #[cfg(feature = "watch")]
+    pub fn process_watch_events<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+    ) -> Result<WatchReport, WatchError> {
+        let Some(watch) = loader.watch.as_mut() else {
+            return Ok(WatchReport::default());
+        };
+
+        // Remember the time of the last change of every plugin
+        let events = watch.events.get_mut().unwrap();
+        while let Ok((time, event)) = events.try_recv() {
+            let event = event?;
+            if event.kind.is_access() {
+                continue;
+            }
+
+            for path in event.paths {
+                if let Some(plugin) = loader
+                    .plugins
+                    .iter()
+                    .find(|plugin| path.starts_with(&plugin.info.path))
+                {
+                    let last_change = watch
+                        .pending
+                        .entry(plugin.info.bundle.clone())
+                        .or_insert(time);
+                    *last_change = time.max(*last_change);
+                }
+            }
+        }
+
+        let mut settled: Vec<_> = watch
+            .pending
+            .iter()
+            .filter(|(_, time)| time.elapsed() >= WATCH_DEBOUNCE)
+            .map(|(bundle, _)| bundle.clone())
+            .collect();
+        settled.sort();
+
+        let mut report = WatchReport::default();
+        for bundle in settled {
+            // The plugin may have been unregistered since it changed
+            if loader.get_plugin_by_bundle(&bundle).is_some() {
+                // On failure the change stays pending, so the next call tries again
+                if let Err(error) = loader.reload_plugin_by_bundle(&bundle) {
+                    warn!("Failed to reload plugin {bundle}: {error}");
+                    report.failed.push((bundle, error));
+                    continue;
+                }
+                report.reloaded.push(bundle.clone());
+            }
+
+            if let Some(watch) = loader.watch.as_mut() {
+                watch.pending.remove(&bundle);
+            }
+        }
+        Ok(report)
+    }
+
+    pub fn reload_plugin<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        index: usize,
+    ) -> Result<(), ReloadPluginError> {
+        if loader.plugins[index].is_virtual {
+            return Err(ReloadPluginError::VirtualPlugin);
+        }
+
+        // Sort the plugin and its loaded dependents in order of their dependencies
+        let mut plugins_set = vec![index];
+        collect_dependents(&loader.plugins, index, &mut plugins_set);
+        let sort_plugins = sort_plugins(&loader.plugins, plugins_set);
+
+        let loaded_bundles = sort_plugins
+            .iter()
+            .filter(|i| loader.plugins[**i].is_load)
+            .map(|i| loader.plugins[*i].info.bundle.clone())
+            .collect::<Vec<_>>();
+
+        // Unload plugins
+        for index in sort_plugins.iter() {
+            unload_plugin(&mut loader.plugins, *index)?;
+        }
+
+        // Register the plugin again, keeping its temporary directory
+        let path = loader.plugins[index].info.path.to_string_lossy().to_string();
+        #[cfg(feature = "archive")]
+        let temp_dir = loader.plugins[index].temp_dir.take();
+        let manager = Ptr::new(loader.plugins[index].manager.as_ptr());
+        let info = take_plugin(&mut loader.plugins, index)?;
+        let registered = register_plugin(loader, &path);
+        #[cfg_attr(not(feature = "archive"), allow(unused_variables))]
+        let bundle = match &registered {
+            Ok(bundle) => bundle.clone(),
+            // Register the previous information back, so the plugin is not lost
+            Err(e) => {
+                warn!(
+                    "Failed to register plugin {} again, restoring it: {e}",
+                    info.bundle
+                );
+                manager
+                    .as_mut()
+                    .register_virtual_plugin(&info)
+                    .map_err(RegisterPluginError::from)?;
+                forced_register_plugin(&mut loader.plugins, manager, info)?
+            }
+        };
+
+        #[cfg(feature = "archive")]
+        if let Some(plugin) = loader.plugins.iter_mut().find(|plugin| **plugin == bundle) {
+            plugin.temp_dir = temp_dir;
+        }
+
+        // Load plugins back, dependencies first
+        for bundle in loaded_bundles.iter().rev() {
+            let index = loader
+                .plugins
+                .iter()
+                .position(|plugin| *plugin == *bundle)
+                .ok_or(ReloadPluginError::NotFound)?;
+            load_plugin(loader, index)?;
+        }
+
+        registered?;
+        Ok(())
+    }
+
+    pub fn swap_plugin<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        index: usize,
+        new_path: &str,
+    ) -> Result<Bundle, SwapPluginError> {
+        let old = loader.plugins[index].info.bundle.clone();
+        let is_load = loader.plugins[index].is_load;
+
+        // Dependents are collected before the new version is registered, since they
+        // resolve their dependencies to the highest matching version
+        let mut dependents = vec![];
+        collect_dependents(&loader.plugins, index, &mut dependents);
+        let dependents = sort_plugins(&loader.plugins, dependents)
+            .into_iter()
+            .map(|index| loader.plugins[index].info.bundle.clone())
+            .collect::<Vec<_>>();
+
+        // Register and load the new version, leaving the old one untouched on failure
+        let new = register_plugin(loader, new_path)?;
+        let new_index = loader.plugins.len() - 1;
+        if new.id != old.id {
+            forced_unregister_plugin(&mut loader.plugins, new_index)?;
+            return Err(SwapPluginError::DifferentPlugin { old, new });
+        }
+        if new.version < old.version {
+            forced_unregister_plugin(&mut loader.plugins, new_index)?;
+            return Err(SwapPluginError::OlderVersion { old, new });
+        }
+        if is_load && let Err(e) = load_plugin(loader, new_index) {
+            forced_unregister_plugin(&mut loader.plugins, new_index)?;
+            return Err(e.into());
+        }
+        debug!("Swapping plugin {old} for {new}");
+
+        if let Err(e) = swap_dependents(loader, &dependents, &old) {
+            warn!("Failed to swap plugin {old} for {new}, restoring it: {e}");
+            restore_dependents(loader, &dependents, &old, &new, is_load);
+            return Err(e);
+        }
+
+        // Unregister the old version, which is no longer used
+        let index = find_plugin(loader, &old).ok_or(SwapPluginError::NotFound)?;
+        forced_unregister_plugin(&mut loader.plugins, index)?;
+
+        Ok(new)
+    }
+
+    fn find_plugin<O: Send + Sync, I: Info>(
+        loader: &super::Loader<'_, O, I>,
+        bundle: &Bundle,
+    ) -> Option<usize> {
+        loader.plugins.iter().position(|plugin| *plugin == *bundle)
+    }
+
+    // Reloads the dependents against the new version and unloads the old one
+    fn swap_dependents<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        dependents: &[Bundle],
+        old: &Bundle,
+    ) -> Result<(), SwapPluginError> {
+        // Dependents first
+        for bundle in dependents.iter() {
+            if let Some(index) = find_plugin(loader, bundle) {
+                forced_unload_plugin(&mut loader.plugins, index)?;
+            }
+        }
+        for bundle in dependents.iter().rev() {
+            if let Some(index) = find_plugin(loader, bundle) {
+                load_plugin(loader, index)?;
+            }
+        }
+
+        // Fails if a dependent still uses the old version
+        let index = find_plugin(loader, old).ok_or(SwapPluginError::NotFound)?;
+        unload_plugin(&mut loader.plugins, index)?;
+        Ok(())
+    }
+
+    // Unregisters the new version and loads the dependents back against the old one
+    fn restore_dependents<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+        dependents: &[Bundle],
+        old: &Bundle,
+        new: &Bundle,
+        is_load: bool,
+    ) {
+        for bundle in dependents.iter() {
+            if let Some(index) = find_plugin(loader, bundle)
+                && let Err(e) = forced_unload_plugin(&mut loader.plugins, index)
+            {
+                warn!("Failed to unload plugin {bundle} while restoring: {e}");
+            }
+        }
+
+        if let Some(index) = find_plugin(loader, new)
+            && let Err(e) = unregister_plugin(&mut loader.plugins, index)
+        {
+            warn!("Failed to unregister plugin {new} while restoring: {e}");
+        }
+
+        let mut bundles = dependents.to_vec();
+        if is_load {
+            bundles.push(old.clone());
+        }
+        for bundle in bundles.iter().rev() {
+            if let Some(index) = find_plugin(loader, bundle)
+                && let Err(e) = load_plugin(loader, index)
+            {
+                warn!("Failed to load plugin {bundle} while restoring: {e}");
+            }
+        }
+    }
+
+    pub fn reload_all_plugins<O: Send + Sync, I: Info>(
+        loader: &mut super::Loader<'static, O, I>,
+    ) -> Result<(), ReloadPluginError> {
+        // Sort the loaded plugins in order of their dependencies
+        let loaded_plugins = loader
+            .plugins
+            .iter()
+            .enumerate()
+            .filter_map(|(index, plugin)| plugin.is_load.then_some(index))
+            .collect();
+        let sort_plugins = sort_plugins(&loader.plugins, loaded_plugins);
+
+        // Unload plugins, dependents first
+        for index in sort_plugins.iter() {
+            forced_unload_plugin(&mut loader.plugins, *index)?;
+        }
+
+        // Load plugins back, dependencies first
+        for index in sort_plugins.iter().rev() {
+            load_plugin(loader, *index)?;
+        }
+
+        Ok(())
+    }
+
     pub fn forced_unload_plugin<O: Send + Sync, I: Info>(
         plugins: &mut Vec<Plugin<'_, O, I>>,
         index: usize,
@@ -1941,13 +4512,49 @@ mod private_loader {
                 .manager
                 .as_mut()
                 .unload_plugin(&plugins[index])?;
+            debug!("Unloaded plugin {}", plugins[index].info.bundle);
         }
 
-        plugins[index].is_load = false;
+        plugins[index].unload_state();
 
         Ok(())
     }
 
+    pub fn matching_bundles<O: Send + Sync, I: Info>(
+        plugins: &[Plugin<'_, O, I>],
+        id: &str,
+        req: &VersionReq,
+    ) -> Vec<Bundle> {
+        plugins
+            .iter()
+            .map(|plugin| &plugin.info.bundle)
+            .filter(|bundle| bundle.id == id && req.matches(&bundle.version))
+            .cloned()
+            .collect()
+    }
+
+    // Unloads the plugins in order of their dependencies and returns their sorted bundles
+    pub fn unload_plugins_by_bundles<O: Send + Sync, I: Info>(
+        plugins: &mut Vec<Plugin<'_, O, I>>,
+        bundles: &[Bundle],
+    ) -> Result<Vec<Bundle>, UnloadPluginError> {
+        let plugins_set = bundles
+            .iter()
+            .map(|bundle| plugins.iter().position(|plugin| *plugin == *bundle))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(UnloadPluginError::NotFound)?;
+        let sort_plugins = sort_plugins(plugins, plugins_set);
+
+        for index in sort_plugins.iter() {
+            unload_plugin(plugins, *index)?;
+        }
+
+        Ok(sort_plugins
+            .into_iter()
+            .map(|index| plugins[index].info.bundle.clone())
+            .collect())
+    }
+
     pub fn unload_plugin<'a, O: Send + Sync, I: Info>(
         plugins: &mut Vec<Plugin<'_, O, I>>,
         index: usize,
@@ -1956,33 +4563,15 @@ mod private_loader {
             let bundle = &plugins[index].info.bundle;
 
             // Check that the plugin is not used as a dependency by loaded plugins
-            plugins.iter().try_for_each(|plug| {
-                let plug_info = &plug.info;
-
-                let find_depend = plug_info
-                    .info
-                    .depends()
-                    .iter()
-                    .chain(plug_info.info.optional_depends().iter())
-                    .find(|depend| {
-                        **depend == *bundle
-                            && plugins
-                                .iter()
-                                .find(|p| {
-                                    depend.version.matches(&p.info.bundle.version)
-                                        && p.info.bundle.version > bundle.version
-                                })
-                                .is_none()
-                    })
-                    .is_some();
-                match plug.is_load && find_depend {
-                    true => Err(UnloadPluginError::CurrentlyUsesDepend {
-                        plugin: plug_info.bundle.clone(),
-                        depend: bundle.clone(),
-                    }),
-                    false => Ok(()),
-                }
-            })?;
+            if let Some(plug) = plugins
+                .iter()
+                .find(|plug| is_dependent(plugins, plug, bundle))
+            {
+                return Err(UnloadPluginError::CurrentlyUsesDepend {
+                    plugin: plug.info.bundle.clone(),
+                    depend: bundle.clone(),
+                });
+            }
         }
 
         forced_unload_plugin(plugins, index)
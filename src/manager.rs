@@ -1,5 +1,10 @@
+use std::any::Any;
+
+#[cfg(feature = "async")]
+use crate::ManagerFuture;
 use crate::{
-    Api, Info, Plugin, RegisterPluginContext, context::LoadPluginContext, utils::ManagerResult,
+    Api, Info, Plugin, PluginInfo, RegisterPluginContext, context::LoadPluginContext,
+    utils::ManagerResult,
 };
 
 /// Trait for implementing custom plugin managers.
@@ -34,12 +39,39 @@ use crate::{
 ///     }
 /// }
 /// ```
-pub trait Manager<'a, O: Send + Sync, I: Info>: Send + Sync {
+pub trait Manager<'a, O: Send + Sync, I: Info>: AsAny + Send + Sync {
     /// Returns the file format/extension this manager handles (e.g., "lua", "rs", "wasm").
     ///
     /// This format is used to identify which manager should handle a particular plugin file.
     fn format(&self) -> &'static str;
 
+    /// Returns all file formats/extensions this manager handles.
+    ///
+    /// Override this method when a single manager handles several formats (e.g., "lua" and "luau").
+    /// A plugin is handled by this manager if its format matches any of the returned ones.
+    /// Default implementation returns only `format()`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of formats handled by the manager.
+    fn formats(&self) -> Vec<&'static str> {
+        vec![self.format()]
+    }
+
+    /// Returns the priority of the manager when resolving a plugin format.
+    ///
+    /// If several managers handle the same format, plugins of that format are registered
+    /// by the manager with the highest priority, e.g. a native Lua manager can take over
+    /// from a generic fallback. Managers with equal priority are tried in registration order.
+    /// Default implementation returns `0`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the priority of the manager, higher values win.
+    fn priority(&self) -> i32 {
+        0
+    }
+
     /// Called when the manager is registered with the loader.
     ///
     /// This is the place to perform any initialization required by the manager.
@@ -64,6 +96,23 @@ pub trait Manager<'a, O: Send + Sync, I: Info>: Send + Sync {
         Ok(())
     }
 
+    /// Verifies the integrity of a plugin before it is registered.
+    ///
+    /// This method is called before `register_plugin` and can be used to check checksums,
+    /// signatures or a manifest in the plugin directory. Returning an error aborts the
+    /// registration. Default implementation does nothing and returns Ok(()).
+    ///
+    /// # Parameters
+    ///
+    /// * `context` - Context containing plugin path and bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns `ManagerResult<()>` indicating whether the plugin passed verification.
+    fn verify_plugin(&self, _context: &RegisterPluginContext) -> ManagerResult<()> {
+        Ok(())
+    }
+
     /// Registers a plugin with this manager.
     ///
     /// This method is called when a plugin file matching this manager's format is discovered.
@@ -78,6 +127,26 @@ pub trait Manager<'a, O: Send + Sync, I: Info>: Send + Sync {
     /// Returns `ManagerResult<I>` containing plugin information on success.
     fn register_plugin(&mut self, _context: RegisterPluginContext) -> ManagerResult<I>;
 
+    /// Registers a virtual plugin with this manager.
+    ///
+    /// This method is called by `Loader::register_virtual_plugin` instead of `register_plugin`
+    /// for plugins whose information is constructed in memory, e.g. generated plugins that
+    /// have no files on disk. It is also called with the previous information of a plugin
+    /// that `Loader::reload_plugin_by_bundle` failed to register again from its files.
+    /// Returning an error aborts the registration.
+    /// Default implementation does nothing and returns Ok(()).
+    ///
+    /// # Parameters
+    ///
+    /// * `info` - Information of the plugin being registered
+    ///
+    /// # Returns
+    ///
+    /// Returns `ManagerResult<()>` indicating success or failure of plugin registration.
+    fn register_virtual_plugin(&mut self, _info: &PluginInfo<I>) -> ManagerResult<()> {
+        Ok(())
+    }
+
     /// Unregisters a plugin from this manager.
     ///
     /// This method is called when a plugin is being removed from the system.
@@ -135,6 +204,128 @@ pub trait Manager<'a, O: Send + Sync, I: Info>: Send + Sync {
     fn unload_plugin(&mut self, _plugin: &Plugin<'a, O, I>) -> ManagerResult<()> {
         Ok(())
     }
+
+    /// Called before the loader loads a batch of plugins.
+    ///
+    /// `Loader::load_plugins` and the similar methods call it on every registered manager
+    /// before loading the plugins, and call `end_batch` after the last plugin is loaded or
+    /// loading fails. Managers can use the pair to prepare expensive shared state once per
+    /// batch instead of once per plugin, e.g. to compile the registry bindings.
+    ///
+    /// Default implementation does nothing.
+    fn begin_batch(&mut self) {}
+
+    /// Called after the loader has loaded a batch of plugins.
+    ///
+    /// See `begin_batch`. Default implementation does nothing.
+    fn end_batch(&mut self) {}
+
+    /// Reports the memory used by a plugin.
+    ///
+    /// The loader cannot measure the memory of a plugin runtime, but the manager may know it,
+    /// e.g. a Lua manager can report the memory used by the plugin's Lua state.
+    /// Default implementation returns `None`.
+    ///
+    /// # Parameters
+    ///
+    /// * `plugin` - Reference to the plugin to measure
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<usize>` containing the memory usage in bytes, or `None` if it is unknown.
+    fn plugin_memory(&self, _plugin: &Plugin<'a, O, I>) -> Option<usize> {
+        None
+    }
+
+    /// Verifies the integrity of a plugin asynchronously.
+    ///
+    /// Called by the asynchronous loader methods instead of `verify_plugin`.
+    /// Default implementation returns the result of `verify_plugin` as a ready future.
+    ///
+    /// # Parameters
+    ///
+    /// * `context` - Context containing plugin path and bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ManagerFuture<()>` indicating whether the plugin passed verification.
+    #[cfg(feature = "async")]
+    fn verify_plugin_async<'f>(
+        &'f self,
+        context: &'f RegisterPluginContext<'f>,
+    ) -> ManagerFuture<'f, ()> {
+        let result = self.verify_plugin(context);
+        Box::pin(async move { result })
+    }
+
+    /// Registers a plugin with this manager asynchronously.
+    ///
+    /// Called by the asynchronous loader methods instead of `register_plugin`.
+    /// Default implementation returns the result of `register_plugin` as a ready future.
+    ///
+    /// # Parameters
+    ///
+    /// * `context` - Context containing plugin path and bundle information
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ManagerFuture<I>` resolving to the plugin information.
+    #[cfg(feature = "async")]
+    fn register_plugin_async<'f>(
+        &'f mut self,
+        context: RegisterPluginContext<'f>,
+    ) -> ManagerFuture<'f, I>
+    where
+        I: 'f,
+    {
+        let result = self.register_plugin(context);
+        Box::pin(async move { result })
+    }
+
+    /// Loads a plugin into the execution environment asynchronously.
+    ///
+    /// Called by the asynchronous loader methods instead of `load_plugin`.
+    /// Default implementation returns the result of `load_plugin` as a ready future.
+    ///
+    /// # Parameters
+    ///
+    /// * `context` - Context containing plugin and loader information
+    /// * `api` - API interface for interacting with the host application
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ManagerFuture<()>` indicating success or failure of plugin loading.
+    #[cfg(feature = "async")]
+    fn load_plugin_async<'f>(
+        &'f mut self,
+        context: LoadPluginContext<'a, 'f, O, I>,
+        api: Api<O, I>,
+    ) -> ManagerFuture<'f, ()> {
+        let result = self.load_plugin(context, api);
+        Box::pin(async move { result })
+    }
+}
+
+/// Trait for accessing a value as `dyn Any`.
+///
+/// It is implemented for every `'static` type, so managers get it automatically.
+/// It allows the loader to downcast a registered manager to its concrete type.
+pub trait AsAny {
+    /// Returns the value as `&dyn Any`.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the value as `&mut dyn Any`.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
 }
 
 impl<'a, O: Send + Sync, I: Info> PartialEq for dyn Manager<'a, O, I> {
@@ -1,10 +1,12 @@
-use std::{cmp::Ordering, fmt::Debug, sync::Arc};
+#[cfg(feature = "archive")]
+use std::path::PathBuf;
+use std::{cmp::Ordering, fmt::Debug, path::Path, sync::Arc};
 
 use semver::Version;
 
 use crate::{
     Bundle, Depend, Info, Manager, PluginInfo, Registry,
-    function::Function,
+    function::{Function, fill_optional_args},
     utils::{PluginCallFunctionError, PluginCallRequestError, PluginRegisterFunctionError, Ptr},
     variable::Variable,
 };
@@ -25,14 +27,27 @@ use crate::{
 /// * `manager` - Reference to the manager responsible for this plugin
 /// * `info` - Plugin metadata and configuration
 /// * `is_load` - Whether the plugin is currently loaded and ready for execution
+/// * `is_enabled` - Whether the plugin takes part in request calls
+/// * `is_virtual` - Whether the plugin was registered from in-memory information
+/// * `resolved_depends` - Bundles the required dependencies resolved to when the plugin was loaded
+/// * `resolved_optional_depends` - Bundles the optional dependencies resolved to when the plugin
+///   was loaded
 /// * `requests` - Functions that this plugin must implement at the request of the host
 /// * `registry` - Functions exposed by this plugin to other plugins or the host
+/// * `temp_dir` - Temporary directory removed on unregistration, if the plugin was
+///   registered from an in-memory archive
 pub struct Plugin<'a, O: Send + Sync, I: Info> {
-    pub(crate) manager: Ptr<'a, Box<dyn Manager<'a, O, I>>>,
+    pub(crate) manager: Ptr<'a, dyn Manager<'a, O, I> + 'static>,
     pub(crate) info: PluginInfo<I>,
     pub(crate) is_load: bool,
-    pub(crate) requests: Vec<Box<dyn Function<Output = O>>>,
+    pub(crate) is_enabled: bool,
+    pub(crate) is_virtual: bool,
+    pub(crate) resolved_depends: Vec<Bundle>,
+    pub(crate) resolved_optional_depends: Vec<Bundle>,
+    pub(crate) requests: Vec<Arc<dyn Function<Output = O>>>,
     pub(crate) registry: Registry<O>,
+    #[cfg(feature = "archive")]
+    pub(crate) temp_dir: Option<PathBuf>,
 }
 
 impl<'a, O: Send + Sync, I: Info> Plugin<'a, O, I> {
@@ -49,15 +64,21 @@ impl<'a, O: Send + Sync, I: Info> Plugin<'a, O, I> {
     ///
     /// Returns a new Plugin instance with default unloaded state.
     pub(crate) const fn new(
-        manager: Ptr<'a, Box<dyn Manager<'a, O, I>>>,
+        manager: Ptr<'a, dyn Manager<'a, O, I> + 'static>,
         info: PluginInfo<I>,
     ) -> Self {
         Self {
             manager,
             info,
             is_load: false,
+            is_enabled: true,
+            is_virtual: false,
+            resolved_depends: vec![],
+            resolved_optional_depends: vec![],
             requests: vec![],
             registry: vec![],
+            #[cfg(feature = "archive")]
+            temp_dir: None,
         }
     }
 
@@ -70,6 +91,30 @@ impl<'a, O: Send + Sync, I: Info> Plugin<'a, O, I> {
         &self.info
     }
 
+    /// Returns the bundle of this plugin.
+    ///
+    /// This is a shortcut for `info().bundle`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the plugin's bundle.
+    pub const fn bundle(&self) -> &Bundle {
+        &self.info.bundle
+    }
+
+    /// Returns the path of this plugin.
+    ///
+    /// This is a shortcut for `info().path`. For plugins registered from the filesystem
+    /// the path is canonical, i.e. absolute and with all symlinks resolved, while virtual
+    /// plugins keep the path they were registered with.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the plugin's path.
+    pub fn path(&self) -> &Path {
+        &self.info.path
+    }
+
     /// Checks if the plugin is currently loaded and ready for execution.
     ///
     /// # Returns
@@ -79,6 +124,85 @@ impl<'a, O: Send + Sync, I: Info> Plugin<'a, O, I> {
         self.is_load
     }
 
+    /// Checks if the plugin is enabled.
+    ///
+    /// Plugins are enabled by default.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the plugin is enabled, `false` otherwise.
+    pub const fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Checks if the plugin is virtual.
+    ///
+    /// Virtual plugins are registered by `Loader::register_virtual_plugin` and have
+    /// no files on disk.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the plugin is virtual, `false` otherwise.
+    pub const fn is_virtual(&self) -> bool {
+        self.is_virtual
+    }
+
+    /// Enables or disables the plugin.
+    ///
+    /// Disabled plugins stay registered and loaded, but are skipped by
+    /// `Loader::call_request` and the other request calls of the loader.
+    /// Unlike unloading, this does not involve the manager, so it is cheap to toggle.
+    ///
+    /// # Parameters
+    ///
+    /// * `enabled` - Whether the plugin should be enabled
+    pub const fn set_enabled(&mut self, enabled: bool) {
+        self.is_enabled = enabled;
+    }
+
+    /// Returns the bundles the required dependencies of this plugin resolved to.
+    ///
+    /// Unlike the version requirements in `info()`, these are the concrete plugin
+    /// versions the plugin was loaded against. The list is empty while the plugin is not loaded.
+    ///
+    /// # Returns
+    ///
+    /// Returns a slice of the resolved dependency bundles.
+    pub fn resolved_depends(&self) -> &[Bundle] {
+        &self.resolved_depends
+    }
+
+    /// Returns the bundles the optional dependencies of this plugin resolved to.
+    ///
+    /// Only the optional dependencies that were found when the plugin was loaded are listed.
+    /// The list is empty while the plugin is not loaded.
+    ///
+    /// # Returns
+    ///
+    /// Returns a slice of the resolved optional dependency bundles.
+    pub fn resolved_optional_depends(&self) -> &[Bundle] {
+        &self.resolved_optional_depends
+    }
+
+    /// Returns the memory used by this plugin.
+    ///
+    /// The value is reported by the plugin's manager through `Manager::plugin_memory`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Option<usize>` containing the memory usage in bytes,
+    /// or `None` if the manager does not report it.
+    pub fn memory_usage(&self) -> Option<usize> {
+        self.manager.as_ref().plugin_memory(self)
+    }
+
+    // Resets the state set when the plugin was loaded
+    pub(crate) fn unload_state(&mut self) {
+        self.is_load = false;
+        self.resolved_depends.clear();
+        self.resolved_optional_depends.clear();
+    }
+
     /// Returns the list of function requests this plugin must implement.
     ///
     /// Function requests are functions that this plugin must implement at the request of the host.
@@ -87,7 +211,7 @@ impl<'a, O: Send + Sync, I: Info> Plugin<'a, O, I> {
     /// # Returns
     ///
     /// Returns a reference to the vector of function requests.
-    pub const fn get_requests(&self) -> &Vec<Box<dyn Function<Output = O>>> {
+    pub const fn get_requests(&self) -> &Vec<Arc<dyn Function<Output = O>>> {
         &self.requests
     }
 
@@ -105,16 +229,66 @@ impl<'a, O: Send + Sync, I: Info> Plugin<'a, O, I> {
     ///
     /// Returns `Result<O, PluginCallRequestError>` containing the function result on success,
     /// or an error if the request is not found.
+    /// Missing trailing optional arguments are filled with `Variable::Null`.
     pub fn call_request(&self, name: &str, args: &[Variable]) -> Result<O, PluginCallRequestError> {
         self.requests
             .iter()
             .find_map(|request| match request.name() == name {
-                true => Some(request.call(args)),
+                true => Some(request.call(&fill_optional_args(&request.inputs(), args))),
                 false => None,
             })
             .ok_or(PluginCallRequestError::NotFound)
     }
 
+    /// Calls a function request by name with exclusive access to the plugin.
+    ///
+    /// Unlike `call_request`, the request is invoked through `Function::call_stateful`,
+    /// which allows functions such as `StatefulFunction` to mutate their state without
+    /// interior mutability.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function request to call
+    /// * `args` - Arguments to pass to the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<O, PluginCallRequestError>` containing the function result on success,
+    /// or an error if the request is not found.
+    /// Missing trailing optional arguments are filled with `Variable::Null`.
+    /// While the request is still shared with a call abandoned by
+    /// `Loader::call_request_timeout`, it is invoked through `Function::call` instead.
+    pub fn call_request_mut(
+        &mut self,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<O, PluginCallRequestError> {
+        self.requests
+            .iter_mut()
+            .find(|request| request.name() == name)
+            .map(|request| {
+                let args = fill_optional_args(&request.inputs(), args);
+                match Arc::get_mut(request) {
+                    Some(request) => request.call_stateful(&args),
+                    None => request.call(&args),
+                }
+            })
+            .ok_or(PluginCallRequestError::NotFound)
+    }
+
+    /// Checks if the plugin implements a function request with the given name.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function request
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the request is implemented by the plugin, `false` otherwise.
+    pub fn has_request(&self, name: &str) -> bool {
+        self.requests.iter().any(|request| request.name() == name)
+    }
+
     /// Returns the registry of functions exposed by this plugin.
     ///
     /// The registry contains functions that this plugin makes available to other plugins
@@ -127,6 +301,35 @@ impl<'a, O: Send + Sync, I: Info> Plugin<'a, O, I> {
         &self.registry
     }
 
+    /// Checks if the plugin exposes a function with the given name.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - Name of the function
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the function is in the plugin's registry, `false` otherwise.
+    pub fn has_function(&self, name: &str) -> bool {
+        self.registry.iter().any(|function| function.name() == name)
+    }
+
+    /// Returns the signatures of all functions exposed by this plugin.
+    ///
+    /// Each signature is formatted with the `Display` implementation of `dyn Function`,
+    /// e.g. `add(a: I32, b: I32) -> output(I32)`, preceded by a `# description` line
+    /// if the function has a description.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of formatted function signatures in registration order.
+    pub fn function_signatures(&self) -> Vec<String> {
+        self.registry
+            .iter()
+            .map(|function| function.to_string())
+            .collect()
+    }
+
     /// Registers a new function in this plugin's registry.
     ///
     /// This method adds a function to the plugin's registry, making it available for
@@ -204,7 +407,12 @@ impl<O: Send + Sync, I: Info> PartialEq<Bundle> for Plugin<'_, O, I> {
 }
 
 impl<O: Send + Sync, I: Info> PartialEq<Depend> for Plugin<'_, O, I> {
-    fn eq(&self, Depend { id: name, version }: &Depend) -> bool {
+    fn eq(
+        &self,
+        Depend {
+            id: name, version, ..
+        }: &Depend,
+    ) -> bool {
         self.info.bundle.id == *name && version.matches(&self.info.bundle.version)
     }
 }
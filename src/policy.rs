@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+/// Resource policy for loading plugins.
+///
+/// LoadPolicy describes the limits a manager should apply to the runtime of a plugin,
+/// such as the amount of memory or the time it may use. The loader only carries the
+/// policy to the managers through `LoadPluginContext::policy`, the enforcement is up
+/// to each manager. A limit set to `None` means the manager's own default is used.
+///
+/// # Fields
+///
+/// * `max_memory` - Maximum memory in bytes the plugin runtime may allocate
+/// * `max_instructions` - Maximum number of instructions the plugin may execute per call
+/// * `timeout` - Maximum time a single call into the plugin may take
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use plux_rs::{LoadPolicy, Loader, StdInfo, function::FunctionOutput};
+///
+/// let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+/// loader.context(|mut ctx| {
+///     ctx.set_default_policy(LoadPolicy {
+///         max_memory: Some(64 * 1024 * 1024),
+///         timeout: Some(Duration::from_secs(1)),
+///         ..LoadPolicy::new()
+///     });
+/// });
+///
+/// assert_eq!(loader.default_policy().timeout, Some(Duration::from_secs(1)));
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct LoadPolicy {
+    /// Maximum memory in bytes the plugin runtime may allocate
+    pub max_memory: Option<usize>,
+    /// Maximum number of instructions the plugin may execute per call
+    pub max_instructions: Option<u64>,
+    /// Maximum time a single call into the plugin may take
+    pub timeout: Option<Duration>,
+}
+
+impl LoadPolicy {
+    /// Creates a new policy without any limits.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new LoadPolicy with all limits set to `None`.
+    pub const fn new() -> Self {
+        Self {
+            max_memory: None,
+            max_instructions: None,
+            timeout: None,
+        }
+    }
+}
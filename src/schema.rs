@@ -0,0 +1,81 @@
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::function::{Arg, Function};
+
+/// Interface of the loaded plugins, exported by `Loader::export_schema`.
+///
+/// The schema is serializable, so it can be written as JSON or any other serde format
+/// and used by tools that generate bindings for the plugins.
+///
+/// # Fields
+///
+/// * `plugins` - Interfaces of the loaded plugins in registration order
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::prelude::*;
+///
+/// let loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+/// let schema = loader.export_schema();
+///
+/// assert!(schema.plugins.is_empty());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginSchema {
+    /// Interfaces of the loaded plugins in registration order
+    pub plugins: Vec<PluginInterface>,
+}
+
+/// Interface of a single plugin in a [`PluginSchema`].
+///
+/// # Fields
+///
+/// * `id` - Plugin identifier
+/// * `version` - Plugin version
+/// * `functions` - Functions registered by the plugin
+/// * `requests` - Functions the plugin implements for the host requests
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginInterface {
+    /// Plugin identifier
+    pub id: String,
+    /// Plugin version
+    pub version: Version,
+    /// Functions registered by the plugin
+    pub functions: Vec<FunctionSchema>,
+    /// Functions the plugin implements for the host requests
+    pub requests: Vec<FunctionSchema>,
+}
+
+/// Signature of a function in a [`PluginSchema`].
+///
+/// # Fields
+///
+/// * `name` - Name of the function
+/// * `description` - Description of the function, if it has one
+/// * `inputs` - Input arguments of the function
+/// * `output` - Output argument of the function, `None` for void functions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionSchema {
+    /// Name of the function
+    pub name: String,
+    /// Description of the function, if it has one
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Input arguments of the function
+    pub inputs: Vec<Arg>,
+    /// Output argument of the function, `None` for void functions
+    pub output: Option<Arg>,
+}
+
+impl<O: Send + Sync> From<&dyn Function<Output = O>> for FunctionSchema {
+    fn from(function: &dyn Function<Output = O>) -> Self {
+        Self {
+            name: function.name(),
+            description: function.description(),
+            inputs: function.inputs(),
+            output: function.output(),
+        }
+    }
+}
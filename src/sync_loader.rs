@@ -0,0 +1,193 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::{
+    Bundle, Info, Loader, LoaderContext,
+    utils::{
+        LoadPluginError, LoadPluginsError, PluginCallFunctionError, PluginCallRequestError,
+        RegisterPluginError, ReloadPluginError, StopLoaderError, UnloadPluginError,
+        UnregisterPluginError,
+    },
+    variable::Variable,
+};
+
+/// Cloneable handle for sharing a loader between threads.
+///
+/// `Loader` is `Send + Sync` by itself, but the `Api` handed to managers points to the
+/// loader, so a loader must not be moved once its plugins are loaded. `SyncLoader` keeps
+/// the loader behind an `Arc<Mutex<_>>` at a stable address, and every clone of the handle
+/// locks the same loader. Create the handle before loading plugins.
+///
+/// The common loader methods are forwarded, the rest of the loader is available through
+/// `lock`.
+///
+/// # Type Parameters
+///
+/// * `O` - Output type for plugin functions (must implement Send + Sync)
+/// * `I` - Plugin information type (must implement Info trait)
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::prelude::*;
+///
+/// let loader = SyncLoader::new(Loader::<'_, FunctionOutput, StdInfo>::new());
+///
+/// let handle = loader.clone();
+/// std::thread::spawn(move || {
+///     assert!(handle.lock().get_plugins().is_empty());
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+pub struct SyncLoader<O: Send + Sync + 'static, I: Info + 'static> {
+    loader: Arc<Mutex<Loader<'static, O, I>>>,
+}
+
+impl<O: Send + Sync + 'static, I: Info + 'static> SyncLoader<O, I> {
+    /// Wraps a loader into a shared handle.
+    ///
+    /// The loader must not have loaded plugins yet, as moving it into the handle
+    /// would invalidate the `Api` instances kept by their managers.
+    ///
+    /// # Parameters
+    ///
+    /// * `loader` - The loader to share
+    ///
+    /// # Returns
+    ///
+    /// Returns a new SyncLoader instance.
+    pub fn new(loader: Loader<'static, O, I>) -> Self {
+        Self {
+            loader: Arc::new(Mutex::new(loader)),
+        }
+    }
+
+    /// Locks the loader for exclusive access.
+    ///
+    /// A lock poisoned by a panicking thread is recovered, since the loader keeps
+    /// its state consistent between the manager calls.
+    ///
+    /// # Returns
+    ///
+    /// Returns a guard dereferencing to the loader.
+    pub fn lock(&self) -> MutexGuard<'_, Loader<'static, O, I>> {
+        self.loader
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Provides access to the loader context for configuration.
+    ///
+    /// See `Loader::context`.
+    pub fn context<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(LoaderContext<'static, '_, O, I>) -> R,
+    {
+        self.lock().context(f)
+    }
+
+    /// Registers a plugin with the loader.
+    ///
+    /// See `Loader::register_plugin`.
+    pub fn register_plugin(&self, path: &str) -> Result<Bundle, RegisterPluginError> {
+        self.lock().register_plugin(path)
+    }
+
+    /// Unregisters a plugin from the loader by bundle.
+    ///
+    /// See `Loader::unregister_plugin_by_bundle`.
+    pub fn unregister_plugin_by_bundle(
+        &self,
+        bundle: &Bundle,
+    ) -> Result<(), UnregisterPluginError> {
+        self.lock().unregister_plugin_by_bundle(bundle)
+    }
+
+    /// Loads a plugin by bundle.
+    ///
+    /// See `Loader::load_plugin_by_bundle`.
+    pub fn load_plugin_by_bundle(&self, bundle: &Bundle) -> Result<(), LoadPluginError> {
+        self.lock().load_plugin_by_bundle(bundle)
+    }
+
+    /// Unloads a plugin by bundle.
+    ///
+    /// See `Loader::unload_plugin_by_bundle`.
+    pub fn unload_plugin_by_bundle(&self, bundle: &Bundle) -> Result<(), UnloadPluginError> {
+        self.lock().unload_plugin_by_bundle(bundle)
+    }
+
+    /// Reloads a plugin by bundle.
+    ///
+    /// See `Loader::reload_plugin_by_bundle`.
+    pub fn reload_plugin_by_bundle(&self, bundle: &Bundle) -> Result<(), ReloadPluginError> {
+        self.lock().reload_plugin_by_bundle(bundle)
+    }
+
+    /// Registers and loads a plugin in one step.
+    ///
+    /// See `Loader::load_plugin_now`.
+    pub fn load_plugin_now(&self, path: &str) -> Result<Bundle, LoadPluginsError> {
+        self.lock().load_plugin_now(path)
+    }
+
+    /// Loads multiple plugins from the specified paths.
+    ///
+    /// See `Loader::load_plugins`.
+    pub fn load_plugins<'b, P>(&self, paths: P) -> Result<Vec<Bundle>, LoadPluginsError>
+    where
+        P: IntoIterator<Item = &'b str>,
+    {
+        self.lock().load_plugins(paths)
+    }
+
+    /// Checks whether a plugin is loaded.
+    ///
+    /// See `Loader::is_loaded`.
+    pub fn is_loaded(&self, bundle: &Bundle) -> bool {
+        self.lock().is_loaded(bundle)
+    }
+
+    /// Calls a function from the registry by name.
+    ///
+    /// See `Loader::call_function`.
+    pub fn call_function(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<O, PluginCallFunctionError> {
+        self.lock().call_function(name, args)
+    }
+
+    /// Calls a function request across all eligible plugins.
+    ///
+    /// See `Loader::call_request`.
+    pub fn call_request(
+        &self,
+        name: &str,
+        args: &[Variable],
+    ) -> Result<Vec<O>, PluginCallRequestError> {
+        self.lock().call_request(name, args)
+    }
+
+    /// Stops the loader, unloading all plugins and unregistering all managers.
+    ///
+    /// See `Loader::stop`.
+    pub fn stop(&self) -> Result<(), StopLoaderError> {
+        self.lock().stop()
+    }
+}
+
+impl<O: Send + Sync + 'static, I: Info + 'static> Clone for SyncLoader<O, I> {
+    fn clone(&self) -> Self {
+        Self {
+            loader: self.loader.clone(),
+        }
+    }
+}
+
+impl<O: Send + Sync + 'static, I: Info + 'static> From<Loader<'static, O, I>> for SyncLoader<O, I> {
+    fn from(loader: Loader<'static, O, I>) -> Self {
+        Self::new(loader)
+    }
+}
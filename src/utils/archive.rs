@@ -1,11 +1,11 @@
 use std::{
     ffi::OsStr,
     fs::File,
-    io::{Read, Write},
+    io::{Read, Seek, Write},
     path::Path,
 };
 
-use zip::{ZipArchive, ZipWriter, write::SimpleFileOptions};
+use zip::{ZipArchive, ZipWriter, read::root_dir_common_filter, write::SimpleFileOptions};
 
 use crate::Bundle;
 
@@ -52,7 +52,7 @@ pub fn zip<S, F>(
     path: &S,
     target_path: &str,
     compression_method: zip::CompressionMethod,
-    mut callback: Option<F>,
+    callback: Option<F>,
 ) -> Result<(), BundleZipError>
 where
     S: AsRef<OsStr> + ?Sized,
@@ -69,39 +69,102 @@ where
     match target_path.exists() {
         true if target_path.is_file() => Ok(()),
         true => Err(BundleZipError::ContainSameDirFailed),
-        false => Ok({
+        false => {
             let file =
                 File::create(target_path).map_err(|e| BundleZipError::CreateBundleFailed(e))?;
-            let mut archive = ZipWriter::new(file);
-            let options = SimpleFileOptions::default()
-                .compression_method(compression_method)
-                .unix_permissions(0o755);
-
-            let mut buffer = Vec::new();
-            for entry in walkdir::WalkDir::new(path)
-                .into_iter()
-                .filter_map(|e| e.ok())
-            {
-                let entry_path = entry.path();
-                let name = entry_path.strip_prefix(path).unwrap();
-
-                if entry_path.is_file() {
-                    #[allow(deprecated)]
-                    archive.start_file_from_path(name, options)?;
-                    let mut f = File::open(entry_path)?;
-
-                    f.read_to_end(&mut buffer)?;
-                    archive.write_all(&buffer)?;
-                    buffer.clear();
-                } else if !name.as_os_str().is_empty() {
-                    #[allow(deprecated)]
-                    archive.add_directory_from_path(name, options)?;
-                }
+            zip_to_writer(path, file, compression_method, callback)?;
+            Ok(())
+        }
+    }
+}
 
-                callback.as_mut().map(|callback| callback(name));
-            }
-        }),
+/// Compresses a directory into a ZIP archive written to a writer.
+///
+/// This function works like [`zip`], but writes the archive into any seekable writer
+/// instead of creating a file, e.g. an in-memory buffer or a network response.
+///
+/// # Parameters
+///
+/// * `path` - Path to the directory to compress
+/// * `writer` - Writer that receives the archive
+/// * `compression_method` - Compression method to use (e.g., Stored, Deflated)
+/// * `callback` - Optional callback function called for each processed file/directory
+///
+/// # Returns
+///
+/// Returns `Result<W, BundleZipError>` containing the writer after the archive
+/// has been finished.
+///
+/// # Type Parameters
+///
+/// * `S` - Type that can be converted to OsStr (for the source path)
+/// * `W` - Writer type that supports seeking
+/// * `F` - Callback function type that takes a `&Path`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use plux_rs::utils::archive::zip_to_writer;
+/// use std::{io::Cursor, path::Path};
+/// use zip::CompressionMethod;
+///
+/// // Compress a plugin directory into memory
+/// let data = zip_to_writer(
+///     "path/to/plugin_directory",
+///     Cursor::new(Vec::new()),
+///     CompressionMethod::Deflated,
+///     None::<fn(&Path)>,
+/// )?
+/// .into_inner();
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn zip_to_writer<S, W, F>(
+    path: &S,
+    writer: W,
+    compression_method: zip::CompressionMethod,
+    mut callback: Option<F>,
+) -> Result<W, BundleZipError>
+where
+    S: AsRef<OsStr> + ?Sized,
+    W: Write + Seek,
+    F: FnMut(&Path),
+{
+    let path = Path::new(path);
+
+    if !path.is_dir() {
+        return Err(BundleZipError::MissingBundleFailed);
     }
+
+    let mut archive = ZipWriter::new(writer);
+    let options = SimpleFileOptions::default()
+        .compression_method(compression_method)
+        .unix_permissions(0o755);
+
+    let mut buffer = Vec::new();
+    for entry in walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        let entry_path = entry.path();
+        let name = entry_path.strip_prefix(path).unwrap();
+
+        if entry_path.is_file() {
+            #[allow(deprecated)]
+            archive.start_file_from_path(name, options)?;
+            let mut f = File::open(entry_path)?;
+
+            f.read_to_end(&mut buffer)?;
+            archive.write_all(&buffer)?;
+            buffer.clear();
+        } else if !name.as_os_str().is_empty() {
+            #[allow(deprecated)]
+            archive.add_directory_from_path(name, options)?;
+        }
+
+        callback.as_mut().map(|callback| callback(name));
+    }
+
+    Ok(archive.finish()?)
 }
 
 /// Extracts a ZIP archive to a directory.
@@ -109,6 +172,11 @@ where
 /// This function extracts a ZIP archive and creates a Bundle from the extracted directory.
 /// It's commonly used for unpacking plugin bundles.
 ///
+/// The archive may either contain the bundle files directly (as created by [`zip`]) or
+/// wrap them in a single root directory named after the bundle. A root directory that
+/// is not a bundle name or names a different bundle is rejected before anything is
+/// written to disk.
+///
 /// # Parameters
 ///
 /// * `path` - Path to the ZIP file to extract
@@ -145,17 +213,84 @@ where
         return Err(BundleUnzipError::MissingBundleFailed);
     }
 
+    let file = File::open(path)?;
+    unzip_from_reader(file, &target_path)
+}
+
+/// Extracts a ZIP archive from a reader to a directory.
+///
+/// This function works like [`unzip`], but reads the archive from any seekable reader,
+/// e.g. an in-memory buffer or a downloaded response. The archive is extracted into
+/// `target_path` itself, so its file name must follow the bundle naming convention.
+/// The archive contents are validated against the bundle in the same way as in [`unzip`].
+///
+/// # Parameters
+///
+/// * `reader` - Reader providing the archive
+/// * `target_path` - Bundle directory where the archive will be extracted
+///
+/// # Returns
+///
+/// Returns `Result<Bundle, BundleUnzipError>` containing the bundle information
+/// from the extracted directory on success.
+///
+/// # Type Parameters
+///
+/// * `R` - Reader type that supports seeking
+/// * `S` - Type that can be converted to OsStr (for the target path)
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use plux_rs::utils::archive::unzip_from_reader;
+/// use std::io::Cursor;
+///
+/// // Extract a plugin bundle received over the network
+/// let data: Vec<u8> = vec![];
+/// let bundle = unzip_from_reader(Cursor::new(data), "output/directory/plugin-v1.0.0.lua")?;
+/// println!("Extracted plugin: {}", bundle.id);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn unzip_from_reader<R, S>(reader: R, target_path: &S) -> Result<Bundle, BundleUnzipError>
+where
+    R: Read + Seek,
+    S: AsRef<OsStr> + ?Sized,
+{
+    let target_path = Path::new(target_path);
+    let bundle = Bundle::from_filename(
+        target_path
+            .file_name()
+            .ok_or(BundleUnzipError::NoNameFailed)?,
+    )?;
+
     match target_path.exists() {
         true if target_path.is_dir() => Ok(()),
         true => Err(BundleUnzipError::ContainSameFileFailed),
-        false => Ok({
-            let file = File::open(path)?;
-            let mut archive = ZipArchive::new(file)?;
-            archive.extract(&target_path)?;
-        }),
+        false => {
+            let mut archive = ZipArchive::new(reader)?;
+
+            // Check that a root directory is a bundle matching the target bundle
+            let root_bundle = match archive.root_dir(root_dir_common_filter)? {
+                Some(root_dir) => Some(Bundle::from_filename(
+                    root_dir.file_name().ok_or(BundleUnzipError::NoNameFailed)?,
+                )?),
+                None => None,
+            };
+
+            match root_bundle {
+                Some(found) if found != bundle => Err(BundleUnzipError::MismatchedBundle {
+                    expected: bundle.clone(),
+                    found,
+                }),
+                Some(_) => {
+                    Ok(archive.extract_unwrapped_root_dir(target_path, root_dir_common_filter)?)
+                }
+                None => Ok(archive.extract(target_path)?),
+            }
+        }
     }?;
 
-    Ok(Bundle::from_filename(target_path.file_name().unwrap())?)
+    Ok(bundle)
 }
 
 #[test]
@@ -196,3 +331,73 @@ fn test_unzip() {
 
     std::fs::remove_dir_all(format!("{target_path}/{name}")).unwrap();
 }
+
+#[test]
+fn test_zip_unzip_stream() {
+    let temp_path = "./tests/bundles/temp";
+    if !Path::new(temp_path).exists() {
+        std::fs::create_dir_all(temp_path).unwrap();
+    }
+
+    let name = "plugin_a-v1.0.0.vpl";
+    let path = format!("./tests/bundles/{name}");
+
+    let data = zip_to_writer(
+        &path,
+        std::io::Cursor::new(Vec::new()),
+        zip::CompressionMethod::Deflated,
+        None::<fn(&Path)>,
+    )
+    .unwrap()
+    .into_inner();
+
+    let target_path = format!("{temp_path}/stream/{name}");
+    let bundle = unzip_from_reader(std::io::Cursor::new(data), &target_path).unwrap();
+    assert_eq!(bundle.id, "plugin_a");
+    assert!(Path::new(&target_path).join("config.toml").is_file());
+
+    std::fs::remove_dir_all(format!("{temp_path}/stream")).unwrap();
+}
+
+#[test]
+fn test_unzip_mismatched_bundle() {
+    let temp_path = "./tests/bundles/temp";
+    if !Path::new(temp_path).exists() {
+        std::fs::create_dir_all(temp_path).unwrap();
+    }
+
+    let archive = |root: &str| {
+        let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        writer
+            .add_directory(root, SimpleFileOptions::default())
+            .unwrap();
+        writer
+            .start_file(format!("{root}/config.toml"), SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"depends = []").unwrap();
+        writer.finish().unwrap().into_inner()
+    };
+
+    let target_path = format!("{temp_path}/plugin_c-v1.0.0.vpl");
+
+    let data = archive("plugin_d-v1.0.0.vpl");
+    match unzip_from_reader(std::io::Cursor::new(data), &target_path) {
+        Err(BundleUnzipError::MismatchedBundle { found, .. }) => assert_eq!(found.id, "plugin_d"),
+        _ => assert!(false),
+    }
+    assert!(!Path::new(&target_path).exists());
+
+    let data = archive("plugin_c");
+    match unzip_from_reader(std::io::Cursor::new(data), &target_path) {
+        Err(BundleUnzipError::BundleFromFailed(_)) => assert!(true),
+        _ => assert!(false),
+    }
+    assert!(!Path::new(&target_path).exists());
+
+    let data = archive("plugin_c-v1.0.0.vpl");
+    let bundle = unzip_from_reader(std::io::Cursor::new(data), &target_path).unwrap();
+    assert_eq!(bundle.id, "plugin_c");
+    assert!(Path::new(&target_path).join("config.toml").is_file());
+
+    std::fs::remove_dir_all(&target_path).unwrap();
+}
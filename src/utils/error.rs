@@ -1,16 +1,21 @@
-use semver::Version;
-use std::{
+use alloc::{boxed::Box, string::String};
+use core::{
     error::Error as StdError,
     fmt::{Debug, Display},
 };
+#[cfg(feature = "std")]
+use semver::Version;
 use thiserror::Error;
 
-use crate::{Bundle, Depend};
+#[cfg(feature = "std")]
+use crate::{Bundle, Depend, function::Request};
+use crate::{function::ArgConstraint, variable::VariableType};
 
 /// Errors that can occur when parsing a bundle from a filename.
 ///
 /// This error type is returned by `Bundle::from_filename()` when the filename
 /// doesn't match the expected format `{id}-v{version}.{format}`.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum BundleFromError {
     /// Failed to convert OsStr to UTF-8 string
@@ -30,6 +35,24 @@ pub enum BundleFromError {
     ParseVersion(#[from] semver::Error),
 }
 
+/// Errors that can occur when reading plugin information from a config file.
+///
+/// This error type is returned by `StdInfo::from_toml_file()` and the other config loading helpers.
+#[cfg(feature = "config")]
+#[derive(Error, Debug)]
+pub enum InfoParseError {
+    /// Failed to read the config file
+    #[error("Failed to read the config file")]
+    ReadFailed(#[from] std::io::Error),
+    /// The TOML config is malformed or does not follow the expected layout
+    #[error("Failed to parse the TOML config: {0}")]
+    ParseToml(#[from] toml::de::Error),
+    /// The JSON config is malformed or does not follow the expected layout
+    #[cfg(feature = "json")]
+    #[error("Failed to parse the JSON config: {0}")]
+    ParseJson(#[from] serde_json::Error),
+}
+
 /// Errors that can occur when creating a ZIP archive from a plugin bundle.
 ///
 /// This error type is returned by the `zip` function when creating plugin archives.
@@ -80,11 +103,31 @@ pub enum BundleUnzipError {
     /// Error creating BundleInfo from the extracted files
     #[error("Error creating BundleInfo")]
     BundleFromFailed(#[from] BundleFromError),
+    /// The root directory of the archive belongs to a different bundle
+    #[error("Archive contains bundle `{found}` instead of `{expected}`")]
+    MismatchedBundle {
+        /// The bundle derived from the archive name
+        expected: Bundle,
+        /// The bundle derived from the root directory of the archive
+        found: Bundle,
+    },
+}
+
+/// Errors that can occur when building a plugin loader.
+///
+/// This error type is returned by `LoaderBuilder::build()`.
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+pub enum BuildLoaderError {
+    /// Failed to build the thread pool for parallel operations
+    #[error("Failed to build thread pool")]
+    ThreadPoolBuildFailed(#[from] rayon::ThreadPoolBuildError),
 }
 
 /// Errors that can occur when stopping the plugin loader.
 ///
 /// This error type is returned by `Loader::stop()` when cleanup operations fail.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum StopLoaderError {
     /// Failed to unregister one or more plugins
@@ -93,11 +136,20 @@ pub enum StopLoaderError {
     /// Failed to unregister one or more managers
     #[error("Failed to unregister managers `{0:?}`")]
     UnregisterManagerFailed(Vec<UnregisterManagerError>),
+    /// Failed to unregister both plugins and managers
+    #[error("Failed to unregister plugins `{plugins:?}` and managers `{managers:?}`")]
+    UnregisterFailed {
+        /// Errors of the plugins that failed to unload or unregister
+        plugins: Vec<UnregisterPluginError>,
+        /// Errors of the managers that failed to unregister
+        managers: Vec<UnregisterManagerError>,
+    },
 }
 
 /// Errors that can occur when registering a plugin manager.
 ///
 /// This error type is returned by manager registration operations.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum RegisterManagerError {
     /// A manager with the same format is already registered
@@ -111,6 +163,7 @@ pub enum RegisterManagerError {
 /// Errors that can occur when unregistering a plugin manager.
 ///
 /// This error type is returned by manager unregistration operations.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum UnregisterManagerError {
     /// The manager was not found
@@ -124,31 +177,93 @@ pub enum UnregisterManagerError {
     UnregisterManagerByManager(#[from] Box<dyn StdError + Send + Sync>),
 }
 
+/// Errors that can occur when merging two loaders.
+///
+/// This error type is returned by `Loader::merge()`. The loaders are checked before
+/// anything is moved, so a failed merge leaves the target loader unchanged, unless
+/// a moved plugin fails to load again.
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+pub enum MergeLoaderError {
+    /// A manager format is already handled by a manager with the same priority
+    #[error("Format `{0}` is already occupied")]
+    AlreadyOccupiedFormat(String),
+    /// A plugin with the same ID and version is registered in both loaders
+    #[error("A plugin with ID `{0}` and version `{1}` already exists")]
+    AlreadyExistsIDAndVersion(String, Version),
+    /// Both loaders declare a request with the same name but a different signature
+    #[error("Request `{0}` is declared with different signatures")]
+    ConflictingRequest(String),
+    /// A loaded plugin does not implement the requests declared by the other loader
+    #[error("Plugin `{0}` does not implement the requests `{1:?}`")]
+    RequestsNotFound(Bundle, Vec<Request>),
+    /// Failed to unload a plugin of the other loader before moving it
+    #[error("Failed to unload plugin")]
+    UnloadPlugin(#[from] UnloadPluginError),
+    /// Failed to load a moved plugin again
+    #[error("Failed to load plugin")]
+    LoadPlugin(#[from] LoadPluginError),
+}
+
+/// Errors that can occur when watching plugin files.
+///
+/// This error type is returned by `Loader::watch_plugins()` and `Loader::process_watch_events()`.
+#[cfg(feature = "watch")]
+#[derive(Error, Debug)]
+pub enum WatchError {
+    /// The file watcher failed to start or reported an error
+    #[error("Failed to watch plugin files")]
+    Watch(#[from] notify::Error),
+}
+
 /// Errors that can occur when registering a plugin.
 ///
 /// This error type is returned by plugin registration operations.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum RegisterPluginError {
     /// The plugin was not found
     #[error("Not found plugin")]
     NotFound,
+    /// The plugin path exists but is not a directory, e.g. an archive that was not extracted
+    #[error("The plugin path '{}' is not a directory", .0.display())]
+    NotADirectory(std::path::PathBuf),
     /// Failed to parse bundle information from the filename
     #[error("Failed to bundle from filename")]
     BundleFromFailed(#[from] BundleFromError),
     /// No manager exists for the plugin's format
     #[error("Unknown plugin manager for the format '{0}'")]
     UnknownManagerFormat(String),
+    /// The format of a virtual plugin differs from the format of its manager
+    #[error("The plugin format '{found}' does not match the manager format '{expected}'")]
+    MismatchedFormat {
+        /// The format of the manager
+        expected: String,
+        /// The format of the plugin bundle
+        found: String,
+    },
     /// The plugin manager returned an error during registration
     #[error("Plugin registration error by the manager")]
     RegisterPluginByManager(#[from] Box<dyn StdError + Send + Sync>),
     /// A plugin with the same ID and version already exists
     #[error("A plugin with ID `{0}` and version `{1}` already exists")]
     AlreadyExistsIDAndVersion(String, Version),
+    /// Failed to extract the plugin archive to a temporary directory
+    #[cfg(feature = "archive")]
+    #[error("Failed to extract plugin archive")]
+    ExtractArchiveFailed(#[from] BundleUnzipError),
+    /// Failed to read the directory to search for plugins
+    #[error("Failed to read the plugin directory")]
+    ReadDirectoryFailed(std::io::Error),
+    /// Failed to resolve the canonical path of the plugin
+    #[error("Failed to canonicalize the plugin path")]
+    Canonicalize(std::io::Error),
 }
 
 /// Errors that can occur when unregistering a plugin.
 ///
 /// This error type is returned by plugin unregistration operations.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum UnregisterPluginError {
     /// The plugin was not found
@@ -163,11 +278,16 @@ pub enum UnregisterPluginError {
     /// The plugin manager returned an error during unregistration
     #[error("Plugin unregistration error by the manager")]
     UnregisterPluginByManager(#[from] Box<dyn StdError + Send + Sync>),
+    /// Failed to remove the temporary directory of a plugin registered from an archive
+    #[cfg(feature = "archive")]
+    #[error("Failed to remove temporary plugin directory")]
+    RemoveTempDirFailed(std::io::Error),
 }
 
 /// Errors that can occur when loading a plugin.
 ///
 /// This error type is returned by plugin loading operations.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum LoadPluginError {
     /// The plugin to load was not found
@@ -188,13 +308,110 @@ pub enum LoadPluginError {
     #[error("Plugin load error by the manager")]
     LoadPluginByManager(#[from] Box<dyn StdError + Send + Sync>),
     /// The plugin doesn't implement required function requests
-    #[error("Requests not found: {0:?}")]
-    RequestsNotFound(Vec<String>),
+    #[error("Requests not found: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    RequestsNotFound(Vec<Request>),
+    /// The plugin dependencies form a cycle
+    #[error("Circular dependency detected: {0:?}")]
+    CircularDependency(Vec<Bundle>),
+    /// A required dependency does not provide a feature the plugin requires
+    #[error("Dependency `{depend}` does not provide the feature `{feature}`")]
+    MissingFeature {
+        /// The dependency that lacks the feature
+        depend: Depend,
+        /// The feature that is not provided
+        feature: String,
+    },
+}
+
+/// Errors that can occur when registering and loading several plugins at once.
+///
+/// This error type is returned by `Loader::load_plugin_now()`, `Loader::load_plugins()`,
+/// `Loader::load_only_used_plugins()` and their parallel versions.
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+pub enum LoadPluginsError {
+    /// Failed to register a plugin
+    #[error("Plugin registration error")]
+    Register(#[from] RegisterPluginError),
+    /// Failed to unregister an unused plugin
+    #[error("Plugin unregistration error")]
+    Unregister(#[from] UnregisterPluginError),
+    /// Failed to load a plugin
+    #[error("Plugin load error")]
+    Load(#[from] LoadPluginError),
+}
+
+/// Errors that can occur when reloading a plugin.
+///
+/// This error type is returned by `Loader::reload_plugin_by_bundle()` and wraps
+/// the errors of the individual reload steps.
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+pub enum ReloadPluginError {
+    /// The plugin to reload was not found
+    #[error("Not found plugin")]
+    NotFound,
+    /// Failed to unload the plugin or one of its dependents
+    #[error("Plugin unload error")]
+    UnloadPlugin(#[from] UnloadPluginError),
+    /// Failed to unregister the plugin
+    #[error("Plugin unregistration error")]
+    UnregisterPlugin(#[from] UnregisterPluginError),
+    /// Failed to register the plugin again from its path
+    #[error("Plugin registration error")]
+    RegisterPlugin(#[from] RegisterPluginError),
+    /// Failed to load the plugin or one of its dependents
+    #[error("Plugin load error")]
+    LoadPlugin(#[from] LoadPluginError),
+    /// The plugin is virtual and has no files to register it again from
+    #[error("Virtual plugins cannot be reloaded")]
+    VirtualPlugin,
+}
+
+/// Errors that can occur when swapping a plugin for another version.
+///
+/// This error type is returned by `Loader::swap_plugin()` and wraps
+/// the errors of the individual swap steps.
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+pub enum SwapPluginError {
+    /// The plugin to swap was not found
+    #[error("Not found plugin")]
+    NotFound,
+    /// The new plugin has a different ID than the plugin it replaces
+    #[error("The plugin `{new}` is not a version of the plugin `{old}`")]
+    DifferentPlugin {
+        /// The plugin being replaced
+        old: Bundle,
+        /// The plugin that was supposed to replace it
+        new: Bundle,
+    },
+    /// The new version of the plugin is older than the version it replaces
+    #[error("The plugin `{new}` is older than the plugin `{old}`")]
+    OlderVersion {
+        /// The plugin being replaced
+        old: Bundle,
+        /// The plugin that was supposed to replace it
+        new: Bundle,
+    },
+    /// Failed to register the new version of the plugin
+    #[error("Plugin registration error")]
+    RegisterPlugin(#[from] RegisterPluginError),
+    /// Failed to load the new version of the plugin or one of the dependents
+    #[error("Plugin load error")]
+    LoadPlugin(#[from] LoadPluginError),
+    /// Failed to unload one of the dependents or the old version of the plugin
+    #[error("Plugin unload error")]
+    UnloadPlugin(#[from] UnloadPluginError),
+    /// Failed to unregister the old version of the plugin
+    #[error("Plugin unregistration error")]
+    UnregisterPlugin(#[from] UnregisterPluginError),
 }
 
 /// Errors that can occur when unloading a plugin.
 ///
 /// This error type is returned by plugin unloading operations.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum UnloadPluginError {
     /// The plugin was not found
@@ -202,11 +419,11 @@ pub enum UnloadPluginError {
     NotFound,
     /// The plugin is currently used as a dependency by another plugin
     #[error("The plugin `{plugin}` currently uses the plugin `{depend}` as a dependency")]
-    CurrentlyUsesDepend { 
+    CurrentlyUsesDepend {
         /// The plugin that depends on the one being unloaded
-        plugin: Bundle, 
+        plugin: Bundle,
         /// The dependency that is being unloaded
-        depend: Bundle 
+        depend: Bundle,
     },
     /// The plugin manager returned an error during unloading
     #[error("Plugin unload error by the manager")]
@@ -221,24 +438,35 @@ pub enum RegisterRequestError {
     /// The requested function was not found
     #[error("Function not found")]
     NotFound,
-    /// The function arguments are incorrectly specified
-    #[error("The arguments are set incorrectly")]
-    ArgumentsIncorrectly,
+    /// The function argument at the given index is missing, extra or has an incorrect type
+    #[error("The argument at index {0} is set incorrectly")]
+    ArgumentsIncorrectly(usize),
+    /// The function output is incorrectly specified
+    #[error("The output is set incorrectly")]
+    OutputIncorrectly,
+    /// A request with the same name is already registered
+    #[error("Request `{0}` is already registered")]
+    DuplicateName(String),
 }
 
 /// Errors that can occur when calling a plugin request.
 ///
 /// This error type is returned when attempting to call a function request on a plugin.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum PluginCallRequestError {
     /// The requested function was not found in the plugin
     #[error("Request not found")]
     NotFound,
+    /// The plugin did not respond to the request in time
+    #[error("Request to plugin `{0}` timed out")]
+    Timeout(Bundle),
 }
 
 /// Errors that can occur when registering a function in a plugin.
 ///
 /// This error type is returned when attempting to register a function in a plugin's registry.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum PluginRegisterFunctionError {
     /// A function with the same name already exists in the registry
@@ -249,6 +477,7 @@ pub enum PluginRegisterFunctionError {
 /// Errors that can occur when calling a function in a plugin.
 ///
 /// This error type is returned when attempting to call a function in a plugin's registry.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum PluginCallFunctionError {
     /// The requested function was not found in the plugin's registry
@@ -256,9 +485,10 @@ pub enum PluginCallFunctionError {
     NotFound,
 }
 
-/// Errors that can occur when calling a function on a plugin dependency.
+/// Errors that can occur when calling a function or request on a plugin dependency.
 ///
-/// This error type is returned when attempting to call a function on a plugin's dependency.
+/// This error type is returned when attempting to call a function or request on a plugin's dependency.
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum CallFunctionDependError {
     /// The required dependency was not found
@@ -267,12 +497,63 @@ pub enum CallFunctionDependError {
     /// Failed to call the function on the dependency
     #[error("Failed to call function")]
     FailedCallFunction(#[from] PluginCallFunctionError),
+    /// Failed to call the request on the dependency
+    #[error("Failed to call request")]
+    FailedCallRequest(#[from] PluginCallRequestError),
+}
+
+/// Errors that can occur when calling a function with argument validation.
+///
+/// This error type is returned by `Function::call_checked()` when the passed
+/// arguments do not match the function signature.
+#[derive(Error, Debug)]
+pub enum FunctionCallError {
+    /// The number of arguments does not match the function signature
+    #[error("Expected {expected} arguments, but {actual} were passed")]
+    ArgumentsCount {
+        /// The number of arguments declared by the function
+        expected: usize,
+        /// The number of arguments passed
+        actual: usize,
+    },
+    /// An argument has a type different from the declared one
+    #[error("Argument `{name}` expected type `{expected}`, but `{actual}` was passed")]
+    ArgumentType {
+        /// The name of the mismatched argument
+        name: String,
+        /// The declared type of the argument
+        expected: VariableType,
+        /// The type of the passed value
+        actual: VariableType,
+    },
+    /// An argument does not satisfy the declared constraint
+    #[error("Argument `{name}` does not satisfy the constraint `{constraint}`")]
+    ArgumentConstraint {
+        /// The name of the argument
+        name: String,
+        /// The constraint that is not satisfied
+        constraint: ArgConstraint,
+    },
+}
+
+/// Errors that can occur when taking the value of a function output.
+///
+/// This error type is returned by `FunctionOutputExt::into_variable()`.
+#[derive(Error, Debug)]
+pub enum FunctionOutputError {
+    /// The function returned an error
+    #[error("Function call failed")]
+    CallFailed(#[from] Box<dyn StdError + Send + Sync>),
+    /// The function succeeded without returning a value
+    #[error("Expected a value, but the function returned nothing")]
+    NoValue,
 }
 
 /// Result type for manager operations.
 ///
 /// This type alias is used throughout the plugin system for operations that can fail.
 /// It provides a consistent error handling interface for manager implementations.
+#[cfg(feature = "std")]
 pub type ManagerResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
 /// Error that occurs when parsing a Variable into a specific type.
@@ -300,7 +581,7 @@ impl ParseVariableError {
 }
 
 impl Display for ParseVariableError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "data cannot be converted to this type `{}`", self.ty)
     }
 }
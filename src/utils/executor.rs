@@ -0,0 +1,54 @@
+use std::{
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs a future to completion on the current thread.
+///
+/// This is a minimal executor used to drive asynchronous managers from the synchronous
+/// loader API. The current thread is parked while the future is pending and woken up
+/// by the future's waker.
+///
+/// # Parameters
+///
+/// * `future` - The future to run
+///
+/// # Returns
+///
+/// Returns the output of the future.
+///
+/// # Example
+///
+/// ```rust
+/// use plux_rs::utils::block_on;
+///
+/// let value = block_on(async { 1 + 2 });
+/// assert_eq!(value, 3);
+/// ```
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
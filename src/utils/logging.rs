@@ -0,0 +1,40 @@
+// Internal logging macros.
+//
+// They forward to the `log` crate when the `log` feature is enabled. Without it they
+// compile to nothing, but the arguments are still type-checked and count as used.
+
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        ::log::trace!($($arg)+);
+        #[cfg(not(feature = "log"))]
+        if false {
+            let _ = ::core::format_args!($($arg)+);
+        }
+    };
+}
+
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        ::log::debug!($($arg)+);
+        #[cfg(not(feature = "log"))]
+        if false {
+            let _ = ::core::format_args!($($arg)+);
+        }
+    };
+}
+
+macro_rules! log_warn {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        ::log::warn!($($arg)+);
+        #[cfg(not(feature = "log"))]
+        if false {
+            let _ = ::core::format_args!($($arg)+);
+        }
+    };
+}
+
+// `warn` is renamed on export, since the name is ambiguous with the builtin attribute
+pub(crate) use {debug, log_warn as warn, trace};
@@ -1,7 +1,15 @@
 mod error;
+#[cfg(feature = "async")]
+mod executor;
+#[cfg(feature = "std")]
+pub(crate) mod logging;
+#[cfg(feature = "std")]
 mod ptr;
 
 pub use error::*;
+#[cfg(feature = "async")]
+pub use executor::*;
+#[cfg(feature = "std")]
 pub use ptr::*;
 
 /// Archive utilities for plugin packaging.
@@ -1,12 +1,10 @@
-use std::{
-    marker::PhantomData,
-    sync::atomic::{AtomicPtr, Ordering},
-};
+use std::marker::PhantomData;
 
 /// A thread-safe pointer wrapper for shared mutable access.
 ///
-/// Ptr provides a way to safely share mutable references across threads using atomic operations.
-/// It wraps an `AtomicPtr` and provides methods to access the underlying data safely.
+/// Ptr provides a way to share mutable references across threads. It wraps a raw pointer,
+/// which may also point to an unsized value such as a trait object, and provides methods
+/// to access the underlying data.
 ///
 /// # Type Parameters
 ///
@@ -18,6 +16,23 @@ use std::{
 /// This type uses unsafe operations internally but provides a safe interface.
 /// The caller must ensure that the pointer remains valid for the lifetime `'a`.
 ///
+/// Ptr does not track borrows: `as_ref` and `as_mut` may be called any number of times,
+/// and nothing prevents two mutable references to the same data from existing at once.
+/// Using such overlapping references is undefined behavior. Within the crate, Ptr is
+/// used in the following ways, each relying on the loader being driven from one thread
+/// at a time:
+///
+/// * A plugin points to the boxed value of its manager, not to the slot of the manager
+///   in the loader, so registering, unregistering or merging managers does not move it.
+///   The pointer stays valid while the manager is registered, and the loader unloads and
+///   unregisters the plugins of a manager before unregistering it.
+/// * An `Api` points to the loader while a plugin is being loaded or called. Plugins
+///   call back into the loader through it while the loader itself is borrowed, so
+///   managers must not hold references into the loader across such calls.
+///
+/// The `par_*` loader methods only read the loader in parallel, any mutation happens
+/// sequentially on the calling thread.
+///
 /// # Example
 ///
 /// ```rust
@@ -36,12 +51,16 @@ use std::{
 /// assert_eq!(*ptr.as_ref(), 24);
 /// ```
 #[derive(Debug)]
-pub struct Ptr<'a, T: 'a> {
-    value: AtomicPtr<T>,
+pub struct Ptr<'a, T: 'a + ?Sized> {
+    value: *mut T,
     marker: PhantomData<&'a T>,
 }
 
-impl<'a, T> Ptr<'a, T> {
+// The pointer is shared between threads under the rules described above
+unsafe impl<T: ?Sized> Send for Ptr<'_, T> {}
+unsafe impl<T: ?Sized> Sync for Ptr<'_, T> {}
+
+impl<'a, T: ?Sized> Ptr<'a, T> {
     /// Creates a new Ptr from a mutable pointer.
     ///
     /// # Parameters
@@ -58,7 +77,7 @@ impl<'a, T> Ptr<'a, T> {
     /// and that no other mutable references to the same data exist.
     pub const fn new(value: *mut T) -> Self {
         Self {
-            value: AtomicPtr::new(value),
+            value,
             marker: PhantomData,
         }
     }
@@ -68,8 +87,8 @@ impl<'a, T> Ptr<'a, T> {
     /// # Returns
     ///
     /// Returns the underlying `*mut T` pointer.
-    pub fn as_ptr(&self) -> *mut T {
-        self.value.load(Ordering::Relaxed)
+    pub const fn as_ptr(&self) -> *mut T {
+        self.value
     }
 
     /// Returns an immutable reference to the data.
@@ -83,7 +102,7 @@ impl<'a, T> Ptr<'a, T> {
     /// This method is safe as long as the original pointer was valid and no mutable
     /// references are being used concurrently.
     pub fn as_ref(&self) -> &T {
-        unsafe { &*self.value.load(Ordering::Relaxed) }
+        unsafe { &*self.value }
     }
 
     /// Returns a mutable reference to the data.
@@ -97,6 +116,6 @@ impl<'a, T> Ptr<'a, T> {
     /// This method is safe as long as the original pointer was valid and no other
     /// references (mutable or immutable) are being used concurrently.
     pub fn as_mut(&self) -> &mut T {
-        unsafe { &mut *self.value.load(Ordering::Relaxed) }
+        unsafe { &mut *self.value }
     }
 }
@@ -0,0 +1,190 @@
+use serde_json::{Map, Number, Value};
+
+use crate::{utils::ParseVariableError, variable::Variable};
+
+impl Variable {
+    /// Converts the Variable into a natural JSON value.
+    ///
+    /// Unlike the serde representation of `Variable`, which tags every value with its
+    /// variant, this produces plain JSON: numbers, strings, arrays and objects.
+    ///
+    /// The conversion is lossy in some cases:
+    /// * Integer types are not distinguished, they all become JSON numbers
+    /// * `I128`/`U128` values outside the `i64`/`u64` range become `f64` numbers
+    /// * `F32`/`F64` values that are NaN or infinite become `null`
+    /// * `Char` becomes a string and `Bytes` becomes an array of numbers
+    /// * Non-string `Map` keys are converted to strings with their `Display` form
+    ///
+    /// # Returns
+    ///
+    /// Returns the JSON representation of the Variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::Variable;
+    ///
+    /// let var = Variable::List(vec![1_i32.into(), "two".into()]);
+    /// assert_eq!(var.to_json(), serde_json::json!([1, "two"]));
+    /// ```
+    pub fn to_json(&self) -> Value {
+        match self {
+            Variable::Null => Value::Null,
+            Variable::I8(v) => Value::from(*v),
+            Variable::I16(v) => Value::from(*v),
+            Variable::I32(v) => Value::from(*v),
+            Variable::I64(v) => Value::from(*v),
+            Variable::I128(v) => match i64::try_from(*v) {
+                Ok(v) => Value::from(v),
+                Err(_) => Value::from(*v as f64),
+            },
+            Variable::U8(v) => Value::from(*v),
+            Variable::U16(v) => Value::from(*v),
+            Variable::U32(v) => Value::from(*v),
+            Variable::U64(v) => Value::from(*v),
+            Variable::U128(v) => match u64::try_from(*v) {
+                Ok(v) => Value::from(v),
+                Err(_) => Value::from(*v as f64),
+            },
+            // `Value::from` turns NaN and infinite floats into null
+            Variable::F32(v) => Value::from(*v),
+            Variable::F64(v) => Value::from(*v),
+            Variable::Bool(v) => Value::Bool(*v),
+            Variable::Char(v) => Value::String(v.to_string()),
+            Variable::String(v) => Value::String(v.clone()),
+            Variable::List(v) => Value::Array(v.iter().map(Variable::to_json).collect()),
+            Variable::Bytes(v) => Value::Array(v.iter().map(|byte| Value::from(*byte)).collect()),
+            Variable::Map(v) => Value::Object(
+                v.iter()
+                    .map(|(key, value)| (key.to_string(), value.to_json()))
+                    .collect::<Map<_, _>>(),
+            ),
+        }
+    }
+
+    /// Creates a Variable from a JSON value.
+    ///
+    /// The JSON value is mapped naturally: `null` to `Null`, booleans to `Bool`,
+    /// strings to `String`, arrays to `List` and objects to `Map` with `String` keys.
+    /// Numbers become `I64` if they fit, `U64` if they are larger positive integers,
+    /// and `F64` otherwise. Since JSON does not distinguish integer widths, a value
+    /// produced from `I32` comes back as `I64`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The JSON value to convert
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Variable, ParseVariableError>` containing the converted value,
+    /// or an error if a number cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::Variable;
+    ///
+    /// let var = Variable::from_json(&serde_json::json!({ "answer": 42 })).unwrap();
+    /// assert_eq!(var, Variable::Map(vec![("answer".into(), Variable::I64(42))]));
+    /// ```
+    pub fn from_json(value: &Value) -> Result<Variable, ParseVariableError> {
+        Ok(match value {
+            Value::Null => Variable::Null,
+            Value::Bool(v) => Variable::Bool(*v),
+            Value::Number(v) => number_to_variable(v)?,
+            Value::String(v) => Variable::String(v.clone()),
+            Value::Array(v) => Variable::List(
+                v.iter()
+                    .map(Variable::from_json)
+                    .collect::<Result<_, _>>()?,
+            ),
+            Value::Object(v) => Variable::Map(
+                v.iter()
+                    .map(|(key, value)| {
+                        Ok((Variable::String(key.clone()), Variable::from_json(value)?))
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+}
+
+fn number_to_variable(number: &Number) -> Result<Variable, ParseVariableError> {
+    if let Some(v) = number.as_i64() {
+        Ok(Variable::I64(v))
+    } else if let Some(v) = number.as_u64() {
+        Ok(Variable::U64(v))
+    } else {
+        number
+            .as_f64()
+            .map(Variable::F64)
+            .ok_or(ParseVariableError::new("f64"))
+    }
+}
+
+#[test]
+fn to_json() {
+    use serde_json::json;
+
+    let var = Variable::Map(vec![
+        ("name".into(), "plux".into()),
+        ("version".into(), Variable::I32(1)),
+        ("ratio".into(), Variable::F64(0.5)),
+        (
+            "tags".into(),
+            Variable::List(vec!['a'.into(), Variable::Null]),
+        ),
+        (Variable::I32(7), Variable::Bool(true)),
+        ("big".into(), Variable::U128(u128::MAX)),
+        ("nan".into(), Variable::F64(f64::NAN)),
+    ]);
+
+    assert_eq!(
+        var.to_json(),
+        json!({
+            "name": "plux",
+            "version": 1,
+            "ratio": 0.5,
+            "tags": ["a", null],
+            "7": true,
+            "big": u128::MAX as f64,
+            "nan": null,
+        })
+    );
+}
+
+#[test]
+fn from_json() {
+    use serde_json::json;
+
+    let var = Variable::from_json(&json!({
+        "list": [1, -2, 1.5, "text", null, false],
+        "large": u64::MAX,
+    }))
+    .unwrap();
+
+    assert_eq!(
+        var,
+        Variable::Map(vec![
+            (
+                "list".into(),
+                Variable::List(vec![
+                    Variable::I64(1),
+                    Variable::I64(-2),
+                    Variable::F64(1.5),
+                    "text".into(),
+                    Variable::Null,
+                    Variable::Bool(false),
+                ])
+            ),
+            ("large".into(), Variable::U64(u64::MAX)),
+        ])
+    );
+
+    // Integer widths are lost on a round trip
+    let var = Variable::I32(5);
+    assert_eq!(
+        Variable::from_json(&var.to_json()).unwrap(),
+        Variable::I64(5)
+    );
+}
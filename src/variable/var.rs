@@ -1,8 +1,17 @@
-use std::fmt::Display;
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{cmp::Ordering, fmt::Display};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::utils::ParseVariableError;
+use crate::{utils::ParseVariableError, variable::VariableType};
 
 /// Represents a dynamically typed value that can be passed between plugins.
 ///
@@ -12,13 +21,15 @@ use crate::utils::ParseVariableError;
 /// # Variants
 ///
 /// * `Null` - Represents a null/empty value (default)
-/// * `I8`, `I16`, `I32`, `I64` - Signed integer types
-/// * `U8`, `U16`, `U32`, `U64` - Unsigned integer types
+/// * `I8`, `I16`, `I32`, `I64`, `I128` - Signed integer types
+/// * `U8`, `U16`, `U32`, `U64`, `U128` - Unsigned integer types
 /// * `F32`, `F64` - Floating point types
 /// * `Bool` - Boolean values
 /// * `Char` - Unicode characters
 /// * `String` - UTF-8 strings
 /// * `List` - Lists/arrays of variables
+/// * `Bytes` - Raw binary data
+/// * `Map` - Ordered key-value pairs of variables
 ///
 /// # Examples
 ///
@@ -36,7 +47,7 @@ use crate::utils::ParseVariableError;
 /// let var2: Variable = "hello".into();
 /// let var3: Variable = vec![1, 2, 3].into();
 /// ```
-#[derive(Default, PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub enum Variable {
     /// Null/empty value
     #[default]
@@ -49,6 +60,8 @@ pub enum Variable {
     I32(i32),
     /// 64-bit signed integer
     I64(i64),
+    /// 128-bit signed integer
+    I128(i128),
     /// 8-bit unsigned integer
     U8(u8),
     /// 16-bit unsigned integer
@@ -57,6 +70,8 @@ pub enum Variable {
     U32(u32),
     /// 64-bit unsigned integer
     U64(u64),
+    /// 128-bit unsigned integer
+    U128(u128),
     /// 32-bit floating point
     F32(f32),
     /// 64-bit floating point
@@ -69,6 +84,10 @@ pub enum Variable {
     String(String),
     /// List of variables
     List(Vec<Variable>),
+    /// Raw binary data
+    Bytes(Vec<u8>),
+    /// Key-value pairs of variables in insertion order
+    Map(Vec<(Variable, Variable)>),
 }
 
 /// Trait for converting Variables to specific Rust types.
@@ -189,9 +208,24 @@ macro_rules! impl_from_variable {
                 }
             }
         }
+
+        impl TryFrom<Variable> for $ty {
+            type Error = ParseVariableError;
+
+            fn try_from(var: Variable) -> Result<Self, Self::Error> {
+                <$ty as FromVariable>::from_var(var)
+            }
+        }
     };
 }
 
+// `()` is the unit value of void functions, which return `Variable::Null`
+impl From<()> for Variable {
+    fn from(_: ()) -> Self {
+        Self::Null
+    }
+}
+
 impl From<&str> for Variable {
     fn from(x: &str) -> Self {
         Self::String(x.to_string())
@@ -216,38 +250,144 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<K, V> From<HashMap<K, V>> for Variable
+where
+    K: Into<Variable>,
+    V: Into<Variable>,
+{
+    fn from(x: HashMap<K, V>) -> Self {
+        Self::Map(x.into_iter().map(|(k, v)| (k.into(), v.into())).collect())
+    }
+}
+
+impl<K, V> From<BTreeMap<K, V>> for Variable
+where
+    K: Into<Variable>,
+    V: Into<Variable>,
+{
+    fn from(x: BTreeMap<K, V>) -> Self {
+        Self::Map(x.into_iter().map(|(k, v)| (k.into(), v.into())).collect())
+    }
+}
+
 impl_from!(i8, I8);
 impl_from!(i16, I16);
 impl_from!(i32, I32);
 impl_from!(i64, I64);
+impl_from!(i128, I128);
 impl_from!(u8, U8);
 impl_from!(u16, U16);
 impl_from!(u32, U32);
 impl_from!(u64, U64);
+impl_from!(u128, U128);
 impl_from!(f32, F32);
 impl_from!(f64, F64);
 impl_from!(bool, Bool);
 impl_from!(char, Char);
 impl_from!(String, String);
 
+impl PartialEq for Variable {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Variable::Null, Variable::Null) => true,
+            (Variable::I8(a), Variable::I8(b)) => a == b,
+            (Variable::I16(a), Variable::I16(b)) => a == b,
+            (Variable::I32(a), Variable::I32(b)) => a == b,
+            (Variable::I64(a), Variable::I64(b)) => a == b,
+            (Variable::I128(a), Variable::I128(b)) => a == b,
+            (Variable::U8(a), Variable::U8(b)) => a == b,
+            (Variable::U16(a), Variable::U16(b)) => a == b,
+            (Variable::U32(a), Variable::U32(b)) => a == b,
+            (Variable::U64(a), Variable::U64(b)) => a == b,
+            (Variable::U128(a), Variable::U128(b)) => a == b,
+            (Variable::F32(a), Variable::F32(b)) => a == b,
+            (Variable::F64(a), Variable::F64(b)) => a == b,
+            (Variable::Bool(a), Variable::Bool(b)) => a == b,
+            (Variable::Char(a), Variable::Char(b)) => a == b,
+            (Variable::String(a), Variable::String(b)) => a == b,
+            (Variable::List(a), Variable::List(b)) => a == b,
+            (Variable::Bytes(a), Variable::Bytes(b)) => a == b,
+            // Maps are compared regardless of the order of their entries
+            (Variable::Map(a), Variable::Map(b)) => {
+                entries_match(a, b, |(key, value), (k, v)| key == k && value == v)
+            }
+            _ => false,
+        }
+    }
+}
+
+// Ordered consistently with `==`, so maps are ordered by their sorted entries
+impl PartialOrd for Variable {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Variable::Null, Variable::Null) => Some(Ordering::Equal),
+            (Variable::I8(a), Variable::I8(b)) => a.partial_cmp(b),
+            (Variable::I16(a), Variable::I16(b)) => a.partial_cmp(b),
+            (Variable::I32(a), Variable::I32(b)) => a.partial_cmp(b),
+            (Variable::I64(a), Variable::I64(b)) => a.partial_cmp(b),
+            (Variable::I128(a), Variable::I128(b)) => a.partial_cmp(b),
+            (Variable::U8(a), Variable::U8(b)) => a.partial_cmp(b),
+            (Variable::U16(a), Variable::U16(b)) => a.partial_cmp(b),
+            (Variable::U32(a), Variable::U32(b)) => a.partial_cmp(b),
+            (Variable::U64(a), Variable::U64(b)) => a.partial_cmp(b),
+            (Variable::U128(a), Variable::U128(b)) => a.partial_cmp(b),
+            (Variable::F32(a), Variable::F32(b)) => a.partial_cmp(b),
+            (Variable::F64(a), Variable::F64(b)) => a.partial_cmp(b),
+            (Variable::Bool(a), Variable::Bool(b)) => a.partial_cmp(b),
+            (Variable::Char(a), Variable::Char(b)) => a.partial_cmp(b),
+            (Variable::String(a), Variable::String(b)) => a.partial_cmp(b),
+            (Variable::List(a), Variable::List(b)) => a.partial_cmp(b),
+            (Variable::Bytes(a), Variable::Bytes(b)) => a.partial_cmp(b),
+            (Variable::Map(a), Variable::Map(b)) => {
+                sorted_entries(a).partial_cmp(&sorted_entries(b))
+            }
+            _ => variant_index(self).partial_cmp(&variant_index(other)),
+        }
+    }
+}
+
 impl Display for Variable {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Variable::Null => write!(f, "Null"),
             Variable::I8(v) => write!(f, "{v}"),
             Variable::I16(v) => write!(f, "{v}"),
             Variable::I32(v) => write!(f, "{v}"),
             Variable::I64(v) => write!(f, "{v}"),
+            Variable::I128(v) => write!(f, "{v}"),
             Variable::U8(v) => write!(f, "{v}"),
             Variable::U16(v) => write!(f, "{v}"),
             Variable::U32(v) => write!(f, "{v}"),
             Variable::U64(v) => write!(f, "{v}"),
+            Variable::U128(v) => write!(f, "{v}"),
             Variable::F32(v) => write!(f, "{v}"),
             Variable::F64(v) => write!(f, "{v}"),
             Variable::Bool(v) => write!(f, "{v}"),
             Variable::Char(v) => write!(f, "{v}"),
             Variable::String(v) => write!(f, "{v}"),
             Variable::List(v) => write!(f, "{v:?}"),
+            Variable::Bytes(v) => {
+                // Only a short hex preview is printed, blobs can be arbitrarily large
+                const PREVIEW_LEN: usize = 16;
+
+                write!(f, "0x")?;
+                for byte in v.iter().take(PREVIEW_LEN) {
+                    write!(f, "{byte:02x}")?;
+                }
+                if v.len() > PREVIEW_LEN {
+                    write!(f, "...")?;
+                }
+                write!(f, " ({} bytes)", v.len())
+            }
+            Variable::Map(v) => write!(
+                f,
+                "{{{}}}",
+                v.iter()
+                    .map(|(key, value)| format!("{key}: {value}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         }
     }
 }
@@ -276,6 +416,438 @@ impl Variable {
             _ => false,
         }
     }
+
+    /// Returns the VariableType corresponding to the Variable.
+    ///
+    /// `Variable::Null` carries no type information and maps to `VariableType::Let`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `VariableType` of the stored value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::{Variable, VariableType};
+    ///
+    /// assert_eq!(Variable::I32(42).type_of(), VariableType::I32);
+    /// assert_eq!(Variable::List(vec![]).type_of(), VariableType::List);
+    /// assert_eq!(Variable::Null.type_of(), VariableType::Let);
+    /// ```
+    pub fn type_of(&self) -> VariableType {
+        match self {
+            Variable::Null => VariableType::Let,
+            Variable::I8(_) => VariableType::I8,
+            Variable::I16(_) => VariableType::I16,
+            Variable::I32(_) => VariableType::I32,
+            Variable::I64(_) => VariableType::I64,
+            Variable::I128(_) => VariableType::I128,
+            Variable::U8(_) => VariableType::U8,
+            Variable::U16(_) => VariableType::U16,
+            Variable::U32(_) => VariableType::U32,
+            Variable::U64(_) => VariableType::U64,
+            Variable::U128(_) => VariableType::U128,
+            Variable::F32(_) => VariableType::F32,
+            Variable::F64(_) => VariableType::F64,
+            Variable::Bool(_) => VariableType::Bool,
+            Variable::Char(_) => VariableType::Char,
+            Variable::String(_) => VariableType::String,
+            Variable::List(_) => VariableType::List,
+            Variable::Bytes(_) => VariableType::Bytes,
+            Variable::Map(_) => VariableType::Map,
+        }
+    }
+
+    /// Compares two Variables, allowing floats to differ by a tolerance.
+    ///
+    /// `F32` and `F64` values are equal if their difference does not exceed `epsilon`.
+    /// Lists are compared element-wise and map values are compared by key, so floats
+    /// nested in them are compared with the same tolerance. All other variants are
+    /// compared exactly, as with `==`.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The Variable to compare with
+    /// * `epsilon` - Maximum allowed difference between floats
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the Variables are equal within the tolerance, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::Variable;
+    ///
+    /// let area = Variable::F64(0.1 + 0.2);
+    ///
+    /// assert_ne!(area, Variable::F64(0.3));
+    /// assert!(area.approx_eq(&Variable::F64(0.3), 1e-9));
+    /// ```
+    pub fn approx_eq(&self, other: &Variable, epsilon: f64) -> bool {
+        match (self, other) {
+            (Variable::F32(a), Variable::F32(b)) => {
+                a == b || (*a as f64 - *b as f64).abs() <= epsilon
+            }
+            (Variable::F64(a), Variable::F64(b)) => a == b || (a - b).abs() <= epsilon,
+            (Variable::List(a), Variable::List(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.approx_eq(b, epsilon))
+            }
+            (Variable::Map(a), Variable::Map(b)) => entries_match(a, b, |(key, value), (k, v)| {
+                key == k && value.approx_eq(v, epsilon)
+            }),
+            _ => self == other,
+        }
+    }
+
+    /// Converts the Variable to another type, allowing lossy conversions.
+    ///
+    /// Unlike `parse`, which only extracts a value of the exact stored type, `cast`
+    /// performs explicit coercions between compatible types. The supported casts are:
+    /// * Any numeric type to any other numeric type
+    /// * `Bool` to an integer type (`true` becomes `1`) and an integer type to `Bool`
+    ///   (any non-zero value becomes `true`)
+    /// * `Char` to `String`, and a `String` of exactly one character to `Char`
+    ///
+    /// Casting to the same type or to `VariableType::Let` returns a copy of the value.
+    ///
+    /// Numeric casts follow the semantics of Rust's `as` operator and are lossy:
+    /// * Narrowing an integer keeps only the low bits, so `I32(300)` becomes `U8(44)`
+    ///   and `I32(-1)` becomes `U32(u32::MAX)`
+    /// * A float cast to an integer is truncated toward zero and saturates at the
+    ///   bounds of the target type, NaN becomes `0`
+    /// * An integer cast to a float, or `F64` cast to `F32`, is rounded to the
+    ///   nearest representable value
+    ///
+    /// # Parameters
+    ///
+    /// * `target` - The type to convert the Variable to
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Variable, ParseVariableError>` containing the converted value,
+    /// or an error if there is no cast from the stored type to `target`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::{Variable, VariableType};
+    ///
+    /// assert_eq!(Variable::F64(2.9).cast(VariableType::I32).unwrap(), Variable::I32(2));
+    /// assert_eq!(Variable::I32(300).cast(VariableType::U8).unwrap(), Variable::U8(44));
+    /// assert_eq!(Variable::Bool(true).cast(VariableType::I32).unwrap(), Variable::I32(1));
+    /// assert!(Variable::from("text").cast(VariableType::I32).is_err());
+    /// ```
+    pub fn cast(&self, target: VariableType) -> Result<Variable, ParseVariableError> {
+        if target == VariableType::Let || target == self.type_of() {
+            return Ok(self.clone());
+        }
+
+        let number = match self {
+            Variable::I8(v) => Number::Signed(*v as i128),
+            Variable::I16(v) => Number::Signed(*v as i128),
+            Variable::I32(v) => Number::Signed(*v as i128),
+            Variable::I64(v) => Number::Signed(*v as i128),
+            Variable::I128(v) => Number::Signed(*v),
+            Variable::U8(v) => Number::Unsigned(*v as u128),
+            Variable::U16(v) => Number::Unsigned(*v as u128),
+            Variable::U32(v) => Number::Unsigned(*v as u128),
+            Variable::U64(v) => Number::Unsigned(*v as u128),
+            Variable::U128(v) => Number::Unsigned(*v),
+            Variable::F32(v) => Number::Float(*v as f64),
+            Variable::F64(v) => Number::Float(*v),
+            Variable::Bool(v) if matches!(target, VariableType::Int(_)) => {
+                Number::Unsigned(*v as u128)
+            }
+            Variable::Char(v) if target == VariableType::String => {
+                return Ok(Variable::String(v.to_string()));
+            }
+            Variable::String(v) if target == VariableType::Char => {
+                let mut chars = v.chars();
+                return match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(Variable::Char(c)),
+                    _ => Err(ParseVariableError::new("Char")),
+                };
+            }
+            _ => return Err(ParseVariableError::new(type_name(target))),
+        };
+
+        macro_rules! cast_number {
+            ($ty:ty) => {
+                match number {
+                    Number::Signed(v) => v as $ty,
+                    Number::Unsigned(v) => v as $ty,
+                    Number::Float(v) => v as $ty,
+                }
+            };
+        }
+
+        Ok(match target {
+            VariableType::I8 => Variable::I8(cast_number!(i8)),
+            VariableType::I16 => Variable::I16(cast_number!(i16)),
+            VariableType::I32 => Variable::I32(cast_number!(i32)),
+            VariableType::I64 => Variable::I64(cast_number!(i64)),
+            VariableType::I128 => Variable::I128(cast_number!(i128)),
+            VariableType::U8 => Variable::U8(cast_number!(u8)),
+            VariableType::U16 => Variable::U16(cast_number!(u16)),
+            VariableType::U32 => Variable::U32(cast_number!(u32)),
+            VariableType::U64 => Variable::U64(cast_number!(u64)),
+            VariableType::U128 => Variable::U128(cast_number!(u128)),
+            VariableType::F32 => Variable::F32(cast_number!(f32)),
+            VariableType::F64 => Variable::F64(cast_number!(f64)),
+            VariableType::Bool => match number {
+                Number::Signed(v) => Variable::Bool(v != 0),
+                Number::Unsigned(v) => Variable::Bool(v != 0),
+                Number::Float(_) => return Err(ParseVariableError::new("Bool")),
+            },
+            _ => return Err(ParseVariableError::new(type_name(target))),
+        })
+    }
+
+    /// Transforms the Variable and every value nested in it.
+    ///
+    /// The function is applied bottom-up: the elements of a `List` and the values of
+    /// a `Map` are transformed first, then the function is called on the collection
+    /// holding the transformed elements. Map keys are left unchanged, so the entries
+    /// of a map stay distinct.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The function applied to every value
+    ///
+    /// # Returns
+    ///
+    /// Returns the transformed Variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::Variable;
+    ///
+    /// let var = Variable::List(vec!["plugin".into(), Variable::List(vec!["loader".into()])]);
+    ///
+    /// // Truncate every string to 4 characters
+    /// let var = var.map_recursive(|var| match var {
+    ///     Variable::String(s) => Variable::String(s.chars().take(4).collect()),
+    ///     var => var,
+    /// });
+    ///
+    /// assert_eq!(var, Variable::List(vec!["plug".into(), Variable::List(vec!["load".into()])]));
+    /// ```
+    pub fn map_recursive(self, mut f: impl FnMut(Variable) -> Variable) -> Variable {
+        self.map_recursive_with(&mut f)
+    }
+
+    fn map_recursive_with<F: FnMut(Variable) -> Variable>(self, f: &mut F) -> Variable {
+        let var = match self {
+            Variable::List(list) => Variable::List(
+                list.into_iter()
+                    .map(|var| var.map_recursive_with(f))
+                    .collect(),
+            ),
+            Variable::Map(map) => Variable::Map(
+                map.into_iter()
+                    .map(|(key, value)| (key, value.map_recursive_with(f)))
+                    .collect(),
+            ),
+            var => var,
+        };
+        f(var)
+    }
+
+    /// Compares two Variables with a total order.
+    ///
+    /// Unlike `partial_cmp`, this ordering is defined for every pair of values, so it can
+    /// be used to sort any list of Variables, e.g. with `slice.sort_by(Variable::total_cmp)`.
+    /// Values of different variants are ordered by the declaration order of the variants,
+    /// from `Null` to `Map`. The order across variants is arbitrary, but stable, and
+    /// numbers of different types are not compared by value: `I32(5)` is less than `I8(1)`.
+    /// Values of the same variant are ordered by value:
+    /// * Floats are ordered with `f64::total_cmp`, so `-0.0` is less than `0.0` and NaN
+    ///   is greater than infinity
+    /// * Lists and bytes are ordered lexicographically
+    /// * Maps are ordered lexicographically by their entries sorted with this ordering,
+    ///   so like equality it does not depend on the order of the entries
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The Variable to compare with
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Ordering` of the Variable relative to `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::Variable;
+    ///
+    /// let mut list = vec![Variable::F64(f64::NAN), "text".into(), Variable::F64(1.5), Variable::Null];
+    /// list.sort_by(Variable::total_cmp);
+    ///
+    /// assert!(list[0].is_null());
+    /// assert_eq!(list[1], Variable::F64(1.5));
+    /// assert_eq!(list[3], "text".into());
+    /// ```
+    pub fn total_cmp(&self, other: &Variable) -> Ordering {
+        match (self, other) {
+            (Variable::Null, Variable::Null) => Ordering::Equal,
+            (Variable::I8(a), Variable::I8(b)) => a.cmp(b),
+            (Variable::I16(a), Variable::I16(b)) => a.cmp(b),
+            (Variable::I32(a), Variable::I32(b)) => a.cmp(b),
+            (Variable::I64(a), Variable::I64(b)) => a.cmp(b),
+            (Variable::I128(a), Variable::I128(b)) => a.cmp(b),
+            (Variable::U8(a), Variable::U8(b)) => a.cmp(b),
+            (Variable::U16(a), Variable::U16(b)) => a.cmp(b),
+            (Variable::U32(a), Variable::U32(b)) => a.cmp(b),
+            (Variable::U64(a), Variable::U64(b)) => a.cmp(b),
+            (Variable::U128(a), Variable::U128(b)) => a.cmp(b),
+            (Variable::F32(a), Variable::F32(b)) => a.total_cmp(b),
+            (Variable::F64(a), Variable::F64(b)) => a.total_cmp(b),
+            (Variable::Bool(a), Variable::Bool(b)) => a.cmp(b),
+            (Variable::Char(a), Variable::Char(b)) => a.cmp(b),
+            (Variable::String(a), Variable::String(b)) => a.cmp(b),
+            (Variable::List(a), Variable::List(b)) => total_cmp_list(a.iter(), b.iter()),
+            (Variable::Bytes(a), Variable::Bytes(b)) => a.cmp(b),
+            (Variable::Map(a), Variable::Map(b)) => {
+                let a = sorted_entries(a)
+                    .into_iter()
+                    .flat_map(|(key, value)| [key, value]);
+                let b = sorted_entries(b)
+                    .into_iter()
+                    .flat_map(|(key, value)| [key, value]);
+                total_cmp_list(a, b)
+            }
+            _ => variant_index(self).cmp(&variant_index(other)),
+        }
+    }
+}
+
+// Lexicographic `Variable::total_cmp` of two sequences
+fn total_cmp_list<'a>(
+    mut a: impl Iterator<Item = &'a Variable>,
+    mut b: impl Iterator<Item = &'a Variable>,
+) -> Ordering {
+    loop {
+        match (a.next(), b.next()) {
+            (Some(a), Some(b)) => match a.total_cmp(b) {
+                Ordering::Equal => (),
+                ordering => return ordering,
+            },
+            (a, b) => return a.is_some().cmp(&b.is_some()),
+        }
+    }
+}
+
+// Entries of a map sorted with `Variable::total_cmp`, by key and then by value
+fn sorted_entries(map: &[(Variable, Variable)]) -> Vec<&(Variable, Variable)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.total_cmp(&b.1)));
+    entries
+}
+
+// Checks that every entry of `a` matches a distinct entry of `b`, so maps with
+// repeated entries are compared as multisets
+fn entries_match(
+    a: &[(Variable, Variable)],
+    b: &[(Variable, Variable)],
+    eq: impl Fn(&(Variable, Variable), &(Variable, Variable)) -> bool,
+) -> bool {
+    let mut matched = vec![false; b.len()];
+    a.len() == b.len()
+        && a.iter().all(
+            |entry| match (0..b.len()).find(|&i| !matched[i] && eq(entry, &b[i])) {
+                Some(i) => {
+                    matched[i] = true;
+                    true
+                }
+                None => false,
+            },
+        )
+}
+
+// Position of the variant in the declaration order for `Variable::total_cmp`
+fn variant_index(var: &Variable) -> u8 {
+    match var {
+        Variable::Null => 0,
+        Variable::I8(_) => 1,
+        Variable::I16(_) => 2,
+        Variable::I32(_) => 3,
+        Variable::I64(_) => 4,
+        Variable::I128(_) => 5,
+        Variable::U8(_) => 6,
+        Variable::U16(_) => 7,
+        Variable::U32(_) => 8,
+        Variable::U64(_) => 9,
+        Variable::U128(_) => 10,
+        Variable::F32(_) => 11,
+        Variable::F64(_) => 12,
+        Variable::Bool(_) => 13,
+        Variable::Char(_) => 14,
+        Variable::String(_) => 15,
+        Variable::List(_) => 16,
+        Variable::Bytes(_) => 17,
+        Variable::Map(_) => 18,
+    }
+}
+
+// Numeric value of a Variable widened for `Variable::cast`
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+// Static name of a type for conversion errors
+fn type_name(ty: VariableType) -> &'static str {
+    match ty {
+        VariableType::Let => "Let",
+        VariableType::I8 => "I8",
+        VariableType::I16 => "I16",
+        VariableType::I32 => "I32",
+        VariableType::I64 => "I64",
+        VariableType::I128 => "I128",
+        VariableType::U8 => "U8",
+        VariableType::U16 => "U16",
+        VariableType::U32 => "U32",
+        VariableType::U64 => "U64",
+        VariableType::U128 => "U128",
+        VariableType::F32 => "F32",
+        VariableType::F64 => "F64",
+        VariableType::Bool => "Bool",
+        VariableType::Char => "Char",
+        VariableType::String => "String",
+        VariableType::List => "List",
+        VariableType::Bytes => "Bytes",
+        VariableType::Map => "Map",
+    }
+}
+
+impl Variable {
+    /// Creates a `Variable::Bytes` from raw binary data.
+    ///
+    /// `Vec<u8>` and `&[u8]` already convert into `Variable::List` through the generic
+    /// `From` implementations, so binary data has to be wrapped explicitly.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - The binary data to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Variable::Bytes` instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::Variable;
+    ///
+    /// let data = Variable::from_bytes(vec![0xDE, 0xAD, 0xBE, 0xEF]);
+    /// assert_eq!(data.parse_ref::<[u8]>(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+    /// ```
+    pub fn from_bytes<B: Into<Vec<u8>>>(bytes: B) -> Self {
+        Self::Bytes(bytes.into())
+    }
 }
 
 impl Variable {
@@ -307,7 +879,7 @@ impl Variable {
     /// ```
     pub fn parse<F>(self) -> F::Output
     where
-        F: FromVariable,
+        F: FromVariable + ?Sized,
     {
         F::from_var(self).unwrap()
     }
@@ -330,7 +902,7 @@ impl Variable {
     /// Panics if the Variable cannot be converted to the target type.
     pub fn parse_ref<F>(&self) -> F::RefOutput<'_>
     where
-        F: FromVariable,
+        F: FromVariable + ?Sized,
     {
         F::from_var_ref(self).unwrap()
     }
@@ -353,7 +925,7 @@ impl Variable {
     /// Panics if the Variable cannot be converted to the target type.
     pub fn parse_mut<F>(&mut self) -> F::MutOutput<'_>
     where
-        F: FromVariable,
+        F: FromVariable + ?Sized,
     {
         F::from_var_mut(self).unwrap()
     }
@@ -361,7 +933,8 @@ impl Variable {
     /// Try to parse the Variable into a specific type.
     ///
     /// This method attempts to convert the Variable to the specified type,
-    /// returning an error if the conversion fails.
+    /// returning an error if the conversion fails. Primitive types also implement
+    /// `TryFrom<Variable>`, so `i32::try_from(var)?` is equivalent.
     ///
     /// # Type Parameters
     ///
@@ -385,7 +958,7 @@ impl Variable {
     /// ```
     pub fn try_parse<F>(self) -> Result<F::Output, ParseVariableError>
     where
-        F: FromVariable,
+        F: FromVariable + ?Sized,
     {
         F::from_var(self)
     }
@@ -405,7 +978,7 @@ impl Variable {
     /// converted value or an error if the conversion fails.
     pub fn try_parse_ref<F>(&self) -> Result<F::RefOutput<'_>, ParseVariableError>
     where
-        F: FromVariable,
+        F: FromVariable + ?Sized,
     {
         F::from_var_ref(self)
     }
@@ -425,10 +998,98 @@ impl Variable {
     /// converted value or an error if the conversion fails.
     pub fn try_parse_mut<F>(&mut self) -> Result<F::MutOutput<'_>, ParseVariableError>
     where
-        F: FromVariable,
+        F: FromVariable + ?Sized,
     {
         F::from_var_mut(self)
     }
+
+    /// Parse the Variable into a specific type, falling back to a default value.
+    ///
+    /// Plugins often return `Null` to mean "no value", so the default is returned
+    /// both when the Variable is `Null` and when the conversion fails.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The target type that implements FromVariable
+    ///
+    /// # Parameters
+    ///
+    /// * `default` - Value returned when the Variable is `Null` or cannot be converted
+    ///
+    /// # Returns
+    ///
+    /// Returns the converted value of type `F::Output` or the default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::Variable;
+    ///
+    /// assert_eq!(Variable::I32(42).parse_or::<i32>(0), 42);
+    /// assert_eq!(Variable::Null.parse_or::<i32>(0), 0);
+    /// assert_eq!(Variable::Bool(true).parse_or::<i32>(0), 0);
+    /// ```
+    pub fn parse_or<F>(self, default: F::Output) -> F::Output
+    where
+        F: FromVariable + ?Sized,
+    {
+        self.parse_or_else::<F>(|| default)
+    }
+
+    /// Parse the Variable into a specific type, computing a default value on failure.
+    ///
+    /// Like `parse_or`, but the default is only computed when the Variable is `Null`
+    /// or cannot be converted.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The target type that implements FromVariable
+    ///
+    /// # Parameters
+    ///
+    /// * `default` - Function computing the value to return instead
+    ///
+    /// # Returns
+    ///
+    /// Returns the converted value of type `F::Output` or the computed default.
+    pub fn parse_or_else<F>(self, default: impl FnOnce() -> F::Output) -> F::Output
+    where
+        F: FromVariable + ?Sized,
+    {
+        match self {
+            Variable::Null => default(),
+            var => F::from_var(var).unwrap_or_else(|_| default()),
+        }
+    }
+}
+
+impl FromVariable for () {
+    type Output = ();
+    type RefOutput<'a> = ();
+    type MutOutput<'a> = ();
+
+    fn from_var(var: Variable) -> Result<Self::Output, ParseVariableError> {
+        Self::from_var_ref(&var)
+    }
+
+    fn from_var_ref(var: &Variable) -> Result<Self::RefOutput<'_>, ParseVariableError> {
+        match var {
+            Variable::Null => Ok(()),
+            _ => Err(ParseVariableError::new("()")),
+        }
+    }
+
+    fn from_var_mut(var: &mut Variable) -> Result<Self::MutOutput<'_>, ParseVariableError> {
+        Self::from_var_ref(var)
+    }
+}
+
+impl TryFrom<Variable> for () {
+    type Error = ParseVariableError;
+
+    fn try_from(var: Variable) -> Result<Self, Self::Error> {
+        <() as FromVariable>::from_var(var)
+    }
 }
 
 impl FromVariable for Vec<Variable> {
@@ -458,6 +1119,33 @@ impl FromVariable for Vec<Variable> {
     }
 }
 
+impl FromVariable for [u8] {
+    type Output = Vec<u8>;
+    type RefOutput<'a> = &'a [u8];
+    type MutOutput<'a> = &'a mut Vec<u8>;
+
+    fn from_var(var: Variable) -> Result<Self::Output, ParseVariableError> {
+        match var {
+            Variable::Bytes(x) => Ok(x),
+            _ => Err(ParseVariableError::new("Bytes")),
+        }
+    }
+
+    fn from_var_ref(var: &Variable) -> Result<Self::RefOutput<'_>, ParseVariableError> {
+        match var {
+            Variable::Bytes(x) => Ok(x),
+            _ => Err(ParseVariableError::new("Bytes")),
+        }
+    }
+
+    fn from_var_mut(var: &mut Variable) -> Result<Self::MutOutput<'_>, ParseVariableError> {
+        match var {
+            Variable::Bytes(x) => Ok(x),
+            _ => Err(ParseVariableError::new("Bytes")),
+        }
+    }
+}
+
 impl<T> FromVariable for Vec<T>
 where
     T: FromVariable,
@@ -512,14 +1200,71 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<T> FromVariable for HashMap<String, T>
+where
+    T: FromVariable,
+{
+    type Output = HashMap<String, T::Output>;
+    type RefOutput<'a>
+        = HashMap<&'a String, T::RefOutput<'a>>
+    where
+        T: 'a;
+    type MutOutput<'a>
+        = HashMap<&'a String, T::MutOutput<'a>>
+    where
+        T: 'a;
+
+    fn from_var(var: Variable) -> Result<Self::Output, ParseVariableError> {
+        match var {
+            Variable::Map(x) => {
+                let mut map = HashMap::with_capacity(x.len());
+                for (key, value) in x.into_iter() {
+                    map.insert(key.try_parse::<String>()?, value.try_parse::<T>()?);
+                }
+                Ok(map)
+            }
+            _ => Err(ParseVariableError::new("HashMap<String, T>")),
+        }
+    }
+
+    fn from_var_ref(var: &Variable) -> Result<Self::RefOutput<'_>, ParseVariableError> {
+        match var {
+            Variable::Map(x) => {
+                let mut map = HashMap::with_capacity(x.len());
+                for (key, value) in x.iter() {
+                    map.insert(key.try_parse_ref::<String>()?, value.try_parse_ref::<T>()?);
+                }
+                Ok(map)
+            }
+            _ => Err(ParseVariableError::new("HashMap<String, T>")),
+        }
+    }
+
+    fn from_var_mut(var: &mut Variable) -> Result<Self::MutOutput<'_>, ParseVariableError> {
+        match var {
+            Variable::Map(x) => {
+                let mut map = HashMap::with_capacity(x.len());
+                for (key, value) in x.iter_mut() {
+                    map.insert(key.try_parse_ref::<String>()?, value.try_parse_mut::<T>()?);
+                }
+                Ok(map)
+            }
+            _ => Err(ParseVariableError::new("HashMap<String, T>")),
+        }
+    }
+}
+
 impl_from_variable!(i8, I8);
 impl_from_variable!(i16, I16);
 impl_from_variable!(i32, I32);
 impl_from_variable!(i64, I64);
+impl_from_variable!(i128, I128);
 impl_from_variable!(u8, U8);
 impl_from_variable!(u16, U16);
 impl_from_variable!(u32, U32);
 impl_from_variable!(u64, U64);
+impl_from_variable!(u128, U128);
 impl_from_variable!(f32, F32);
 impl_from_variable!(f64, F64);
 impl_from_variable!(bool, Bool);
@@ -534,6 +1279,16 @@ fn into() {
     assert_eq!(b, Variable::I16(10));
 }
 
+#[test]
+fn into_wide_int() {
+    let a: Variable = i128::MIN.into();
+    let b: Variable = u128::MAX.into();
+
+    assert_eq!(a.to_string(), i128::MIN.to_string());
+    assert_eq!(b.clone().parse::<u128>(), u128::MAX);
+    assert!(b.try_parse::<u64>().is_err());
+}
+
 #[test]
 fn parse() {
     let mut a: Variable = 10_i16.into();
@@ -558,6 +1313,32 @@ fn parse() {
     };
 }
 
+#[test]
+fn parse_or() {
+    // Null
+    assert_eq!(Variable::Null.parse_or::<String>("none".into()), "none");
+    assert_eq!(Variable::Null.parse_or_else::<i32>(|| 7), 7);
+
+    // Wrong type
+    assert_eq!(Variable::Bool(true).parse_or::<i32>(7), 7);
+    assert_eq!(Variable::from("text").parse_or_else::<i32>(|| 7), 7);
+
+    // Correct type
+    assert_eq!(Variable::I32(42).parse_or::<i32>(7), 42);
+    assert_eq!(
+        Variable::from(vec![1_u8, 2]).parse_or_else::<Vec<u8>>(|| panic!("not called")),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn try_from() {
+    let a: Variable = 10_i16.into();
+
+    assert_eq!(i16::try_from(a.clone()).unwrap(), 10);
+    assert!(String::try_from(a).is_err());
+}
+
 #[test]
 fn parse_vec() {
     let mut a: Variable = vec![10_i16].into();
@@ -566,3 +1347,324 @@ fn parse_vec() {
 
     assert_eq!(b, vec![&mut 10]);
 }
+
+#[test]
+fn parse_bytes() {
+    let mut a = Variable::from_bytes([1_u8, 2, 3].as_slice());
+
+    assert_eq!(a.parse_ref::<[u8]>(), &[1, 2, 3]);
+    a.parse_mut::<[u8]>().push(4);
+    assert_eq!(a.clone().parse::<[u8]>(), vec![1, 2, 3, 4]);
+    assert!(Variable::from(vec![1_u8]).try_parse_ref::<[u8]>().is_err());
+
+    assert_eq!(a.to_string(), "0x01020304 (4 bytes)");
+}
+
+#[test]
+fn parse_map() {
+    let a: Variable = BTreeMap::from([("a", 1_i32), ("b", 2_i32)]).into();
+    let b = Variable::Map(vec![("b".into(), 2.into()), ("a".into(), 1.into())]);
+
+    assert_eq!(a, b);
+    assert_eq!(a.to_string(), "{a: 1, b: 2}");
+
+    let map = a.parse_ref::<HashMap<String, i32>>();
+    assert_eq!(map.get(&"b".to_string()), Some(&&2));
+
+    let c = Variable::Map(vec![(1.into(), 2.into())]);
+    assert!(c.try_parse::<HashMap<String, i32>>().is_err());
+}
+
+#[test]
+fn map_eq() {
+    let a = Variable::Map(vec![("a".into(), 1.into()), ("a".into(), 1.into())]);
+    let b = Variable::Map(vec![("a".into(), 1.into()), ("b".into(), 2.into())]);
+    assert_ne!(a, b);
+    assert_ne!(b, a);
+
+    let c = Variable::Map(vec![("b".into(), 2.into()), ("a".into(), 1.into())]);
+    assert_eq!(b, c);
+
+    // Ordering agrees with equality
+    assert_eq!(b.partial_cmp(&c), Some(Ordering::Equal));
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Less));
+    assert_eq!(b.partial_cmp(&a), Some(Ordering::Greater));
+    assert_eq!(
+        Variable::List(vec![b.clone()]).partial_cmp(&Variable::List(vec![c])),
+        Some(Ordering::Equal)
+    );
+    assert!(Variable::Null < b);
+    assert_eq!(
+        Variable::F64(f64::NAN).partial_cmp(&Variable::F64(f64::NAN)),
+        None
+    );
+}
+
+#[test]
+fn type_of() {
+    assert_eq!(Variable::Null.type_of(), VariableType::Let);
+    assert_eq!(Variable::from(10_u128).type_of(), VariableType::U128);
+    assert_eq!(Variable::from("a").type_of(), VariableType::String);
+    assert_eq!(Variable::from_bytes(vec![1_u8]).type_of(), VariableType::Bytes);
+}
+
+#[test]
+fn approx_eq() {
+    let epsilon = 1e-6;
+
+    assert!(Variable::F64(0.1 + 0.2).approx_eq(&Variable::F64(0.3), epsilon));
+    assert!(Variable::F32(3.141_592_7).approx_eq(&Variable::F32(3.141_592), epsilon));
+    assert!(!Variable::F64(1.0).approx_eq(&Variable::F64(1.1), epsilon));
+    assert!(!Variable::F64(f64::NAN).approx_eq(&Variable::F64(f64::NAN), epsilon));
+
+    // Infinities are equal as with `==`
+    assert!(Variable::F64(f64::INFINITY).approx_eq(&Variable::F64(f64::INFINITY), epsilon));
+    assert!(Variable::F32(f32::NEG_INFINITY).approx_eq(&Variable::F32(f32::NEG_INFINITY), epsilon));
+    assert!(!Variable::F64(f64::INFINITY).approx_eq(&Variable::F64(f64::NEG_INFINITY), epsilon));
+
+    // Floats of different widths are different variants
+    assert!(!Variable::F32(1.0).approx_eq(&Variable::F64(1.0), epsilon));
+
+    let a = Variable::List(vec![Variable::F64(0.1 + 0.2), "area".into()]);
+    assert!(a.approx_eq(
+        &Variable::List(vec![Variable::F64(0.3), "area".into()]),
+        epsilon
+    ));
+    assert!(!a.approx_eq(&Variable::List(vec![Variable::F64(0.3)]), epsilon));
+
+    let a = Variable::Map(vec![
+        ("x".into(), 0.5_f64.into()),
+        ("y".into(), 2_i32.into()),
+    ]);
+    let b = Variable::Map(vec![
+        ("y".into(), 2_i32.into()),
+        ("x".into(), (0.5_f64 + 1e-9).into()),
+    ]);
+    assert!(a.approx_eq(&b, epsilon));
+
+    // Repeated entries are matched one to one
+    let a = Variable::Map(vec![
+        ("x".into(), 0.5_f64.into()),
+        ("x".into(), 0.5_f64.into()),
+    ]);
+    let b = Variable::Map(vec![
+        ("x".into(), 0.5_f64.into()),
+        ("y".into(), 0.5_f64.into()),
+    ]);
+    assert!(!a.approx_eq(&b, epsilon));
+    assert!(!b.approx_eq(&a, epsilon));
+
+    assert!(Variable::I32(2).approx_eq(&Variable::I32(2), epsilon));
+    assert!(!Variable::I32(2).approx_eq(&Variable::I32(3), epsilon));
+}
+
+#[test]
+fn cast() {
+    // Widening and narrowing integers
+    assert_eq!(
+        Variable::I8(-5).cast(VariableType::I64).unwrap(),
+        Variable::I64(-5)
+    );
+    assert_eq!(
+        Variable::I32(300).cast(VariableType::U8).unwrap(),
+        Variable::U8(44)
+    );
+    assert_eq!(
+        Variable::I32(-1).cast(VariableType::U32).unwrap(),
+        Variable::U32(u32::MAX)
+    );
+    assert_eq!(
+        Variable::U128(u128::MAX).cast(VariableType::I128).unwrap(),
+        Variable::I128(-1)
+    );
+
+    // Floats truncate toward zero and saturate
+    assert_eq!(
+        Variable::F64(-2.9).cast(VariableType::I32).unwrap(),
+        Variable::I32(-2)
+    );
+    assert_eq!(
+        Variable::F32(1e10).cast(VariableType::I16).unwrap(),
+        Variable::I16(i16::MAX)
+    );
+    assert_eq!(
+        Variable::F64(-1.0).cast(VariableType::U8).unwrap(),
+        Variable::U8(0)
+    );
+    assert_eq!(
+        Variable::F64(f64::NAN).cast(VariableType::I32).unwrap(),
+        Variable::I32(0)
+    );
+    assert_eq!(
+        Variable::U64(u64::MAX).cast(VariableType::F64).unwrap(),
+        Variable::F64(u64::MAX as f64)
+    );
+    assert_eq!(
+        Variable::F64(0.5).cast(VariableType::F32).unwrap(),
+        Variable::F32(0.5)
+    );
+
+    // Bool and integers
+    assert_eq!(
+        Variable::Bool(true).cast(VariableType::I32).unwrap(),
+        Variable::I32(1)
+    );
+    assert_eq!(
+        Variable::Bool(false).cast(VariableType::U8).unwrap(),
+        Variable::U8(0)
+    );
+    assert_eq!(
+        Variable::I32(-3).cast(VariableType::Bool).unwrap(),
+        Variable::Bool(true)
+    );
+    assert_eq!(
+        Variable::U8(0).cast(VariableType::Bool).unwrap(),
+        Variable::Bool(false)
+    );
+    assert!(Variable::Bool(true).cast(VariableType::F32).is_err());
+    assert!(Variable::F64(1.0).cast(VariableType::Bool).is_err());
+
+    // Char and String
+    assert_eq!(
+        Variable::Char('a').cast(VariableType::String).unwrap(),
+        "a".into()
+    );
+    assert_eq!(
+        Variable::from("b").cast(VariableType::Char).unwrap(),
+        Variable::Char('b')
+    );
+    assert!(Variable::from("ab").cast(VariableType::Char).is_err());
+    assert!(Variable::from("").cast(VariableType::Char).is_err());
+
+    // Identity and unsupported casts
+    let list = Variable::List(vec![1.into()]);
+    assert_eq!(list.cast(VariableType::Let).unwrap(), list);
+    assert_eq!(list.cast(VariableType::List).unwrap(), list);
+    assert!(list.cast(VariableType::Map).is_err());
+    assert!(Variable::from("1").cast(VariableType::I32).is_err());
+    assert!(Variable::Null.cast(VariableType::I32).is_err());
+}
+
+#[test]
+fn unit() {
+    assert_eq!(Variable::from(()), Variable::Null);
+
+    let mut var = Variable::Null;
+    assert!(var.try_parse_ref::<()>().is_ok());
+    assert!(var.try_parse_mut::<()>().is_ok());
+    assert!(var.try_parse::<()>().is_ok());
+    assert!(<()>::try_from(Variable::Null).is_ok());
+
+    let var = Variable::I32(0);
+    assert!(var.try_parse_ref::<()>().is_err());
+    assert!(var.try_parse::<()>().is_err());
+}
+
+#[test]
+fn map_recursive() {
+    let var = Variable::Map(vec![
+        ("name".into(), "plugin".into()),
+        (
+            "tags".into(),
+            Variable::List(vec![
+                "loader".into(),
+                Variable::I32(1),
+                Variable::List(vec![]),
+            ]),
+        ),
+    ]);
+
+    let mut visited = vec![];
+    let var = var.map_recursive(|var| {
+        visited.push(var.type_of());
+        match var {
+            Variable::String(s) => Variable::String(s.chars().take(4).collect()),
+            Variable::I32(v) => Variable::I32(v * 10),
+            var => var,
+        }
+    });
+
+    assert_eq!(
+        var,
+        Variable::Map(vec![
+            ("name".into(), "plug".into()),
+            (
+                "tags".into(),
+                Variable::List(vec![
+                    "load".into(),
+                    Variable::I32(10),
+                    Variable::List(vec![])
+                ]),
+            ),
+        ])
+    );
+
+    // Children are visited before their collection, keys are not visited
+    assert_eq!(
+        visited,
+        vec![
+            VariableType::String,
+            VariableType::String,
+            VariableType::I32,
+            VariableType::List,
+            VariableType::List,
+            VariableType::Map,
+        ]
+    );
+
+    // The collection receives the transformed children
+    let var = Variable::List(vec![Variable::Null, Variable::Null]).map_recursive(|var| match var {
+        Variable::Null => Variable::I32(0),
+        Variable::List(list) => Variable::I32(list.len() as i32),
+        var => var,
+    });
+    assert_eq!(var, Variable::I32(2));
+}
+
+#[test]
+fn total_cmp() {
+    let mut list = vec![
+        Variable::F64(f64::NAN),
+        Variable::F64(1.5),
+        Variable::I8(1),
+        Variable::F64(-0.0),
+        Variable::I32(5),
+        Variable::F64(0.0),
+        "b".into(),
+        "a".into(),
+        Variable::Null,
+    ];
+    list.sort_by(Variable::total_cmp);
+
+    let expected = vec![
+        Variable::Null,
+        Variable::I8(1),
+        Variable::I32(5),
+        Variable::F64(-0.0),
+        Variable::F64(0.0),
+        Variable::F64(1.5),
+        Variable::F64(f64::NAN),
+        "a".into(),
+        "b".into(),
+    ];
+    assert!(
+        list.iter()
+            .zip(expected.iter())
+            .all(|(a, b)| a.total_cmp(b) == Ordering::Equal)
+    );
+
+    // Lists are ordered lexicographically
+    let short = Variable::List(vec![1.into()]);
+    let long = Variable::List(vec![1.into(), 0.into()]);
+    let greater = Variable::List(vec![2.into()]);
+    assert_eq!(short.total_cmp(&long), Ordering::Less);
+    assert_eq!(long.total_cmp(&greater), Ordering::Less);
+
+    // Maps are ordered regardless of the order of their entries
+    let a = Variable::Map(vec![("x".into(), 1.into()), ("y".into(), 2.into())]);
+    let b = Variable::Map(vec![("y".into(), 2.into()), ("x".into(), 1.into())]);
+    let c = Variable::Map(vec![("x".into(), 1.into()), ("y".into(), 3.into())]);
+    assert_eq!(a.total_cmp(&b), Ordering::Equal);
+    assert_eq!(b.total_cmp(&c), Ordering::Less);
+    assert_eq!(c.total_cmp(&a), Ordering::Greater);
+}
@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use core::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +17,8 @@ use serde::{Deserialize, Serialize};
 /// * `Char` - Unicode characters
 /// * `String` - UTF-8 strings
 /// * `List` - Lists/arrays of variables
+/// * `Bytes` - Raw binary data
+/// * `Map` - Key-value pairs of variables
 ///
 /// # Examples
 ///
@@ -48,6 +50,10 @@ pub enum VariableType {
     String,
     /// Lists/arrays of variables
     List,
+    /// Raw binary data
+    Bytes,
+    /// Key-value pairs of variables
+    Map,
 }
 
 /// Represents integer types with size and signedness information.
@@ -77,6 +83,8 @@ pub enum VariableSignedIntType {
     I32,
     /// 64-bit signed integer
     I64,
+    /// 128-bit signed integer
+    I128,
 }
 
 /// Represents unsigned integer types of various sizes.
@@ -94,6 +102,8 @@ pub enum VariableUnsignedIntType {
     U32,
     /// 64-bit unsigned integer
     U64,
+    /// 128-bit unsigned integer
+    U128,
 }
 
 /// Represents floating point types of various sizes.
@@ -122,6 +132,9 @@ impl VariableType {
     /// 64-bit signed integer type
     pub const I64: VariableType =
         VariableType::Int(VariableIntType::Signed(VariableSignedIntType::I64));
+    /// 128-bit signed integer type
+    pub const I128: VariableType =
+        VariableType::Int(VariableIntType::Signed(VariableSignedIntType::I128));
     /// 8-bit unsigned integer type
     pub const U8: VariableType =
         VariableType::Int(VariableIntType::Unsigned(VariableUnsignedIntType::U8));
@@ -134,10 +147,47 @@ impl VariableType {
     /// 64-bit unsigned integer type
     pub const U64: VariableType =
         VariableType::Int(VariableIntType::Unsigned(VariableUnsignedIntType::U64));
+    /// 128-bit unsigned integer type
+    pub const U128: VariableType =
+        VariableType::Int(VariableIntType::Unsigned(VariableUnsignedIntType::U128));
     /// 32-bit floating point type
     pub const F32: VariableType = VariableType::Float(VariableFloatType::F32);
     /// 64-bit floating point type
     pub const F64: VariableType = VariableType::Float(VariableFloatType::F64);
+
+    /// Checks whether values of this type can be used where the other type is expected.
+    ///
+    /// The coercion rules are:
+    ///
+    /// * `Let` is a wildcard and is compatible with any type, on either side
+    /// * Integer types are compatible only if both signedness and size agree
+    /// * Float types are compatible only if their sizes agree
+    /// * All other types are compatible only with themselves
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The type to check compatibility with
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the types are compatible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use plux_rs::variable::VariableType;
+    ///
+    /// assert!(VariableType::Let.is_compatible_with(&VariableType::String));
+    /// assert!(VariableType::I32.is_compatible_with(&VariableType::I32));
+    /// assert!(!VariableType::I32.is_compatible_with(&VariableType::U32));
+    /// assert!(!VariableType::I32.is_compatible_with(&VariableType::I64));
+    /// ```
+    pub fn is_compatible_with(&self, other: &VariableType) -> bool {
+        match (self, other) {
+            (Self::Let, _) | (_, Self::Let) => true,
+            (a, b) => a == b,
+        }
+    }
 }
 
 impl Default for VariableIntType {
@@ -147,7 +197,7 @@ impl Default for VariableIntType {
 }
 
 impl Display for VariableType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Int(t) => write!(f, "{t}"),
             Self::Float(t) => write!(f, "{t}"),
@@ -157,7 +207,7 @@ impl Display for VariableType {
 }
 
 impl Display for VariableIntType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Signed(t) => write!(f, "{t}"),
             Self::Unsigned(t) => write!(f, "{t}"),
@@ -166,19 +216,30 @@ impl Display for VariableIntType {
 }
 
 impl Display for VariableFloatType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self:?}")
     }
 }
 
 impl Display for VariableSignedIntType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self:?}")
     }
 }
 
 impl Display for VariableUnsignedIntType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self:?}")
     }
 }
+
+#[test]
+fn compatibility() {
+    assert!(VariableType::Let.is_compatible_with(&VariableType::Map));
+    assert!(VariableType::Bytes.is_compatible_with(&VariableType::Let));
+    assert!(VariableType::U8.is_compatible_with(&VariableType::U8));
+    assert!(!VariableType::U8.is_compatible_with(&VariableType::I8));
+    assert!(!VariableType::U8.is_compatible_with(&VariableType::U16));
+    assert!(!VariableType::F32.is_compatible_with(&VariableType::F64));
+    assert!(!VariableType::String.is_compatible_with(&VariableType::Char));
+}
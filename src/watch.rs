@@ -0,0 +1,53 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, mpsc::Receiver},
+    time::{Duration, Instant},
+};
+
+use notify::{Event, RecommendedWatcher};
+
+use crate::{Bundle, utils::ReloadPluginError};
+
+/// Time without changes after which a changed plugin is reloaded.
+pub(crate) const WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Handle of the file watcher started by `Loader::watch_plugins`.
+///
+/// The watcher runs on its own thread and only queues the file system events, which the
+/// host applies with `Loader::process_watch_events`. Dropping the handle stops watching,
+/// events that are already queued are still processed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let _watch = loader.watch_plugins()?;
+///
+/// loop {
+///     for bundle in loader.process_watch_events()?.reloaded {
+///         println!("Reloaded {bundle}");
+///     }
+///     std::thread::sleep(std::time::Duration::from_millis(100));
+/// }
+/// ```
+pub struct WatchHandle {
+    pub(crate) _watcher: RecommendedWatcher,
+}
+
+/// Outcome of `Loader::process_watch_events`.
+///
+/// A plugin that fails to reload does not prevent the other changed plugins from being
+/// reloaded. Its change stays pending and is retried by the next call.
+#[derive(Debug, Default)]
+pub struct WatchReport {
+    /// Bundles of the reloaded plugins
+    pub reloaded: Vec<Bundle>,
+    /// Bundles of the plugins that failed to reload, with the reload errors
+    pub failed: Vec<(Bundle, ReloadPluginError)>,
+}
+
+// Queued file system events and plugins waiting for their changes to settle,
+// the receiver is behind a mutex to keep the loader `Sync`
+pub(crate) struct PluginWatch {
+    pub(crate) events: Mutex<Receiver<(Instant, notify::Result<Event>)>>,
+    pub(crate) pending: HashMap<Bundle, Instant>,
+}
@@ -4,6 +4,13 @@ mod utils;
 mod dependency {
     use std::path::PathBuf;
 
+    use plux_rs::utils::{
+        LoadPluginError, ManagerResult, ReloadPluginError, UnloadPluginError, UnregisterPluginError,
+    };
+    use plux_rs::{
+        Bundle, Depend, Info, Loader, Manager, Plugin, RegisterPluginContext, StdInfo,
+        function::FunctionOutput,
+    };
     use semver::Version;
 
     use crate::utils::{get_plugin_path, loader_init, managers::VoidPluginManager};
@@ -39,6 +46,329 @@ mod dependency {
             .unwrap();
     }
 
+    #[test]
+    fn plugins_in_load_order() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        for path in get_dependencys_path().iter().rev() {
+            loader.register_plugin(path.to_str().unwrap()).unwrap();
+        }
+
+        loader
+            .load_plugin("dep_4", &Version::parse("1.0.0").unwrap())
+            .unwrap();
+
+        let ids = loader
+            .plugins_in_load_order()
+            .into_iter()
+            .map(|plugin| plugin.info().bundle.id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["dep_1", "dep_2", "dep_3", "dep_4"]);
+    }
+
+    #[test]
+    fn dependency_graph() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        for path in get_dependencys_path() {
+            loader.register_plugin(path.to_str().unwrap()).unwrap();
+        }
+        loader
+            .register_plugin(
+                get_plugin_path("plugin_for_manager", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let graph = loader
+            .dependency_graph()
+            .into_iter()
+            .map(|(bundle, depends)| {
+                (
+                    bundle.id,
+                    depends
+                        .into_iter()
+                        .map(|d| format!("{}-v{}.{}", d.id, d.version, d.format))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            graph,
+            [
+                ("dep_1".to_string(), vec![]),
+                ("dep_2".to_string(), vec!["dep_1-v1.0.0.vpl".to_string()]),
+                ("dep_3".to_string(), vec!["dep_2-v1.0.0.vpl".to_string()]),
+                (
+                    "dep_4".to_string(),
+                    vec!["dep_1-v1.0.0.vpl".to_string(), "dep_3-v1.0.0.vpl".to_string()]
+                ),
+                (
+                    "plugin_for_manager".to_string(),
+                    vec!["function_plugin-v1.0.0.".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn load_circular_dependency_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        loader
+            .register_plugin(
+                get_plugin_path("dependency/cycle_1", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+        loader
+            .register_plugin(
+                get_plugin_path("dependency/cycle_2", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let result = loader.load_plugin("cycle_1", &Version::parse("1.0.0").unwrap());
+        match result {
+            Err(LoadPluginError::CircularDependency(cycle)) => {
+                let ids = cycle.iter().map(|b| b.id.as_str()).collect::<Vec<_>>();
+                assert_eq!(ids, ["cycle_1", "cycle_2", "cycle_1"]);
+            }
+            r => panic!("Expected circular dependency, got {r:?}"),
+        }
+    }
+
+    #[test]
+    fn reload_dependency_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let bundles = get_dependencys_path()
+            .iter()
+            .map(|path| loader.register_plugin(path.to_str().unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        loader
+            .load_plugin("dep_4", &Version::parse("1.0.0").unwrap())
+            .unwrap();
+
+        loader.reload_plugin_by_bundle(&bundles[0]).unwrap();
+
+        for bundle in bundles {
+            assert!(loader.get_plugin_by_bundle(&bundle).unwrap().is_load());
+        }
+    }
+
+    #[test]
+    fn reload_dependency_plugin_failure() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let dir = std::env::temp_dir().join("plux_reload_dependency_plugin_failure");
+        let dep_1 = dir.join("dep_1-v1.0.0.vpl");
+        std::fs::create_dir_all(&dep_1).unwrap();
+        std::fs::copy(
+            get_dependencys_path()[0].join("config.toml"),
+            dep_1.join("config.toml"),
+        )
+        .unwrap();
+
+        let mut paths = get_dependencys_path();
+        paths[0] = dep_1.clone();
+        let bundles = paths
+            .iter()
+            .map(|path| loader.register_plugin(path.to_str().unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        loader
+            .load_plugin("dep_4", &Version::parse("1.0.0").unwrap())
+            .unwrap();
+
+        // The plugin cannot be registered again, so it keeps its previous information
+        std::fs::remove_file(dep_1.join("config.toml")).unwrap();
+        assert!(matches!(
+            loader.reload_plugin_by_bundle(&bundles[0]),
+            Err(ReloadPluginError::RegisterPlugin(_))
+        ));
+        assert_eq!(loader.plugin_count(), 4);
+        assert!(bundles.iter().all(|bundle| loader.is_loaded(bundle)));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unload_and_unregister_plugins() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let bundles = get_dependencys_path()
+            .iter()
+            .map(|path| loader.register_plugin(path.to_str().unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        loader
+            .load_plugin("dep_4", &Version::parse("1.0.0").unwrap())
+            .unwrap();
+
+        // One by one, dependencies cannot be unloaded before their dependents
+        assert!(matches!(
+            loader.unload_plugin_by_bundle(&bundles[0]),
+            Err(UnloadPluginError::CurrentlyUsesDepend { .. })
+        ));
+
+        loader.unload_plugins(&bundles).unwrap();
+        assert!(bundles.iter().all(|bundle| !loader.is_loaded(bundle)));
+
+        loader
+            .load_plugin("dep_4", &Version::parse("1.0.0").unwrap())
+            .unwrap();
+
+        let missing = Bundle::from_filename("dep_5-v1.0.0.vpl").unwrap();
+        assert!(matches!(
+            loader.unregister_plugins(&[bundles[0].clone(), missing]),
+            Err(UnregisterPluginError::NotFound)
+        ));
+        assert_eq!(loader.plugin_count(), 4);
+
+        loader.unregister_plugins(&bundles).unwrap();
+        assert_eq!(loader.plugin_count(), 0);
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn dependents_of() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let bundles = get_dependencys_path()
+            .iter()
+            .map(|path| loader.register_plugin(path.to_str().unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        let ids = |plugins: Vec<&Plugin<'_, FunctionOutput, StdInfo>>| {
+            let mut ids = plugins
+                .into_iter()
+                .map(|plugin| plugin.info().bundle.id.clone())
+                .collect::<Vec<_>>();
+            ids.sort();
+            ids
+        };
+
+        // Plugins that are not loaded do not use their dependencies
+        assert!(loader.dependents_of(&bundles[0]).is_empty());
+
+        loader
+            .load_plugin("dep_4", &Version::parse("1.0.0").unwrap())
+            .unwrap();
+
+        assert_eq!(ids(loader.dependents_of(&bundles[0])), ["dep_2", "dep_4"]);
+        assert_eq!(
+            ids(loader.par_dependents_of(&bundles[0])),
+            ["dep_2", "dep_4"]
+        );
+        assert!(loader.dependents_of(&bundles[3]).is_empty());
+
+        match loader.unload_plugin_by_bundle(&bundles[0]) {
+            Err(UnloadPluginError::CurrentlyUsesDepend { plugin, .. }) => {
+                assert!(ids(loader.dependents_of(&bundles[0])).contains(&plugin.id))
+            }
+            r => panic!("Expected a dependency in use, got {r:?}"),
+        }
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn validate_plugins() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        // dep_1 is missing, so dep_2 cannot be loaded
+        let bundles = [
+            get_plugin_path("dependency/cycle_1", "1.0.0", "vpl"),
+            get_plugin_path("dependency/cycle_2", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_2", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_3", "1.0.0", "vpl"),
+        ]
+        .iter()
+        .map(|path| loader.register_plugin(path.to_str().unwrap()).unwrap())
+        .collect::<Vec<_>>();
+        let missing = Bundle::from_filename("dep_5-v1.0.0.vpl").unwrap();
+
+        let errors = loader
+            .validate_plugins(&[bundles[0].clone(), bundles[3].clone(), missing])
+            .unwrap_err();
+        assert_eq!(errors.len(), 3);
+
+        match &errors[0] {
+            LoadPluginError::CircularDependency(cycle) => {
+                let ids = cycle.iter().map(|b| b.id.as_str()).collect::<Vec<_>>();
+                assert_eq!(ids, ["cycle_1", "cycle_2", "cycle_1"]);
+            }
+            e => panic!("Expected a circular dependency, got {e:?}"),
+        }
+        match &errors[1] {
+            LoadPluginError::LoadDependency { depend, error } => {
+                assert_eq!(depend.id, "dep_2");
+                assert!(matches!(
+                    error.as_ref(),
+                    LoadPluginError::NotFoundDependencies(depends) if depends[0].id == "dep_1"
+                ));
+            }
+            e => panic!("Expected a dependency error, got {e:?}"),
+        }
+        assert!(matches!(errors[2], LoadPluginError::NotFound));
+
+        // Nothing was loaded while validating
+        assert!(bundles.iter().all(|bundle| !loader.is_loaded(bundle)));
+
+        loader
+            .register_plugin(
+                get_plugin_path("dependency/dep_1", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+        loader.validate_plugins(&bundles[2..]).unwrap();
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn reload_and_unload_all_plugins() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let bundles = get_dependencys_path()
+            .iter()
+            .map(|path| loader.register_plugin(path.to_str().unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        loader
+            .load_plugin("dep_2", &Version::parse("1.0.0").unwrap())
+            .unwrap();
+
+        // Only the plugins loaded before are loaded back
+        loader.reload_all().unwrap();
+        let is_loaded = |loader: &Loader<'_, FunctionOutput, StdInfo>| {
+            bundles
+                .iter()
+                .map(|bundle| loader.is_loaded(bundle))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(is_loaded(&loader), vec![true, true, false, false]);
+
+        loader.unload_all_plugins().unwrap();
+        assert_eq!(is_loaded(&loader), vec![false; 4]);
+        assert_eq!(loader.plugin_count(), 4);
+        assert_eq!(loader.manager_count(), 1);
+
+        loader
+            .load_plugin("dep_4", &Version::parse("1.0.0").unwrap())
+            .unwrap();
+        assert!(loader.is_loaded(&bundles[3]));
+
+        loader.stop().unwrap();
+    }
+
     #[test]
     fn load_plugins() {
         let mut loader = loader_init(VoidPluginManager::new());
@@ -56,4 +386,119 @@ mod dependency {
             );
         }
     }
+
+    struct FeatureInfo {
+        depends: Vec<Depend>,
+        optional_depends: Vec<Depend>,
+        provides: Vec<String>,
+    }
+
+    impl Info for FeatureInfo {
+        fn depends(&self) -> &Vec<Depend> {
+            &self.depends
+        }
+
+        fn optional_depends(&self) -> &Vec<Depend> {
+            &self.optional_depends
+        }
+
+        fn provides(&self) -> &[String] {
+            &self.provides
+        }
+    }
+
+    struct FeatureManager;
+
+    impl<'a> Manager<'a, FunctionOutput, FeatureInfo> for FeatureManager {
+        fn format(&self) -> &'static str {
+            "feat"
+        }
+
+        fn register_plugin(
+            &mut self,
+            context: RegisterPluginContext,
+        ) -> ManagerResult<FeatureInfo> {
+            let logger = |feature: &str| {
+                Depend::new("logger".to_string(), "1.0".parse().unwrap()).with_features([feature])
+            };
+
+            let (depends, optional_depends, provides) = match context.bundle.id.as_str() {
+                "logger" => (vec![], vec![], vec!["json".to_string()]),
+                "app" => (vec![logger("json")], vec![logger("xml")], vec![]),
+                _ => (vec![logger("xml")], vec![], vec![]),
+            };
+            Ok(FeatureInfo {
+                depends,
+                optional_depends,
+                provides,
+            })
+        }
+    }
+
+    #[test]
+    fn depend_features() {
+        let mut loader = Loader::<'_, FunctionOutput, FeatureInfo>::new();
+        loader.context(|mut ctx| ctx.register_manager(FeatureManager).unwrap());
+
+        let dir = std::env::temp_dir().join("plux_depend_features");
+        let mut bundles = vec![];
+        for name in ["logger-v1.0.0.feat", "app-v1.0.0.feat", "bad-v1.0.0.feat"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+            bundles.push(
+                loader
+                    .register_plugin(dir.join(name).to_str().unwrap())
+                    .unwrap(),
+            );
+        }
+
+        // The optional dependency without the required feature is skipped
+        loader.load_plugin_by_bundle(&bundles[1]).unwrap();
+        let app = loader.get_plugin_by_bundle(&bundles[1]).unwrap();
+        assert_eq!(app.resolved_depends(), &[bundles[0].clone()]);
+        assert!(app.resolved_optional_depends().is_empty());
+
+        match loader.load_plugin_by_bundle(&bundles[2]) {
+            Err(LoadPluginError::MissingFeature { depend, feature }) => {
+                assert_eq!(depend.id, "logger");
+                assert_eq!(feature, "xml");
+            }
+            _ => assert!(false),
+        };
+
+        std::fs::remove_dir_all(dir).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn dependents_ignore_other_ids() {
+        let mut loader = Loader::<'_, FunctionOutput, FeatureInfo>::new();
+        loader.context(|mut ctx| ctx.register_manager(FeatureManager).unwrap());
+
+        // A higher version of another plugin matching the requirement does not hide the dependency
+        let dir = std::env::temp_dir().join("plux_dependents_ignore_other_ids");
+        let mut bundles = vec![];
+        for name in ["logger-v1.0.0.feat", "app-v1.0.0.feat", "other-v1.5.0.feat"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+            bundles.push(
+                loader
+                    .register_plugin(dir.join(name).to_str().unwrap())
+                    .unwrap(),
+            );
+        }
+
+        loader.load_plugin_by_bundle(&bundles[1]).unwrap();
+        let dependents = loader.dependents_of(&bundles[0]);
+        assert_eq!(dependents.len(), 1);
+        assert_eq!(dependents[0].info().bundle, bundles[1]);
+
+        match loader.unload_plugin_by_bundle(&bundles[0]) {
+            Err(UnloadPluginError::CurrentlyUsesDepend { plugin, .. }) => {
+                assert_eq!(plugin, bundles[1])
+            }
+            r => panic!("Expected a dependency in use, got {r:?}"),
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+        loader.stop().unwrap();
+    }
 }
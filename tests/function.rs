@@ -4,6 +4,9 @@ mod utils;
 mod tests {
     use plux_lua_manager::LuaManager;
     use plux_rs::prelude::*;
+    use std::sync::{Arc, Mutex};
+
+    use plux_rs::{LoadPluginContext, Manager, RegisterPluginContext};
     use semver::Version;
 
     use crate::utils::{benchmark, get_plugin_path, managers::VoidPluginManager};
@@ -27,6 +30,43 @@ mod tests {
         });
     }
 
+    #[test]
+    fn try_register_function() {
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+        loader.context(move |mut ctx| {
+            assert!(ctx.try_register_function(add()));
+            assert!(!ctx.try_register_function(add()));
+            assert!(ctx.try_register_function(sub()));
+        });
+
+        assert_eq!(loader.get_registry().len(), 2);
+    }
+
+    #[test]
+    fn signatures() {
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+        loader.context(move |mut ctx| {
+            ctx.register_function(add());
+            ctx.register_request(
+                Request::new(
+                    "join",
+                    vec![VariableType::List, VariableType::String],
+                    Some(VariableType::String),
+                )
+                .with_optional_inputs(1),
+            );
+        });
+
+        assert_eq!(
+            loader.registry_signatures(),
+            vec!["add(a: I32, b: I32) -> output(I32)"]
+        );
+        assert_eq!(
+            loader.request_signatures(),
+            vec!["join(List, String?) -> String"]
+        );
+    }
+
     #[test]
     fn register_functions() {
         let mut loader = Loader::new();
@@ -58,6 +98,54 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn try_register_request() {
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+        loader.context(move |mut ctx| {
+            ctx.try_register_request(Request::new("log", vec![VariableType::String], None))
+                .unwrap();
+            assert!(matches!(
+                ctx.try_register_request(Request::new("log", vec![], None)),
+                Err(RegisterRequestError::DuplicateName(name)) if name == "log"
+            ));
+
+            // `register_request` replaces the request with the same name
+            ctx.register_requests([
+                Request::new("log", vec![], None),
+                Request::new("add", vec![VariableType::I32; 2], Some(VariableType::I32)),
+            ]);
+        });
+
+        let requests = loader.get_requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].inputs.is_empty());
+    }
+
+    #[test]
+    fn requests_not_found() {
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+        loader.context(move |mut ctx| {
+            ctx.register_request(Request::new(
+                "echo",
+                vec![VariableType::String],
+                Some(VariableType::String),
+            ));
+            ctx.register_manager(VoidPluginManager::new()).unwrap();
+        });
+
+        match loader.load_plugin_now(
+            get_plugin_path("void_plugin", "1.0.0", "vpl")
+                .to_str()
+                .unwrap(),
+        ) {
+            Err(LoadPluginsError::Load(error)) => assert_eq!(
+                error.to_string(),
+                "Requests not found: echo(String) -> String"
+            ),
+            _ => assert!(false),
+        };
+    }
+
     #[test]
     fn call_request() {
         let mut loader = Loader::new();
@@ -89,6 +177,299 @@ mod tests {
         };
     }
 
+    struct CounterManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for CounterManager {
+        fn format(&self) -> &'static str {
+            "cnt"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn load_plugin(
+            &mut self,
+            mut context: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            _: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            let mut count = 0;
+            context.register_request(StatefulFunction::new(
+                "next",
+                vec![],
+                Some(Arg::new("count", VariableType::I32)),
+                move |_| {
+                    count += 1;
+                    Ok(Some(count.into()))
+                },
+            ))?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn call_request_mut() {
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_request(Request::new("next", vec![], Some(VariableType::I32)));
+            ctx.register_manager(CounterManager).unwrap();
+        });
+
+        let path = std::env::temp_dir().join("plux_call_request_mut/counter-v1.0.0.cnt");
+        std::fs::create_dir_all(&path).unwrap();
+        let bundle = loader.load_plugin_now(path.to_str().unwrap()).unwrap();
+
+        let plugin = loader.get_plugin_mut_by_bundle(&bundle).unwrap();
+        for expected in 1..=3 {
+            assert_eq!(
+                plugin.call_request_mut("next", &[]).unwrap().unwrap(),
+                Some(expected.into())
+            );
+        }
+        assert!(matches!(
+            plugin.call_request_mut("missing", &[]),
+            Err(PluginCallRequestError::NotFound)
+        ));
+    }
+
+    // Plugins implement `greet` and expose `version`, `client` calls both on its `server`
+    struct GreetManager(Arc<Mutex<Vec<String>>>);
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for GreetManager {
+        fn format(&self) -> &'static str {
+            "grt"
+        }
+
+        fn register_plugin(&mut self, context: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(match context.bundle.id.as_str() {
+                "client" => {
+                    StdInfo::new().with_depend(Depend::new("server".into(), "1.0".parse()?))
+                }
+                _ => StdInfo::new(),
+            })
+        }
+
+        fn load_plugin(
+            &mut self,
+            mut context: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            api: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            let id = context.plugin().info().bundle.id.clone();
+            context.register_request(DynamicFunction::new(
+                "greet",
+                vec![],
+                Some(Arg::new("output", VariableType::String)),
+                move |_| Ok(Some(format!("Hello from {id}").into())),
+            ))?;
+
+            let version = context.plugin().info().bundle.version.to_string();
+            api.get_plugin_mut_by_bundle(api.plugin())
+                .unwrap()
+                .register_function(DynamicFunction::new(
+                    "version",
+                    vec![],
+                    Some(Arg::new("output", VariableType::String)),
+                    move |_| Ok(Some(version.clone().into())),
+                ))?;
+
+            if context.plugin().info().bundle.id == "client" {
+                let version = Version::new(1, 0, 0);
+                let mut results = self.0.lock().unwrap();
+
+                let output = api.call_request_depend("server", &version, "greet", &[])?;
+                results.push(output?.unwrap().parse::<String>());
+
+                let output =
+                    api.call_function_depend_req("server", &"^1".parse()?, "version", &[])?;
+                results.push(output?.unwrap().parse::<String>());
+
+                for result in [
+                    api.call_request_depend("client", &version, "greet", &[]),
+                    api.call_request_depend("server", &version, "missing", &[]),
+                    api.call_function_depend_req("server", &"^2".parse()?, "version", &[]),
+                ] {
+                    results.push(match result {
+                        Err(e) => e.to_string(),
+                        Ok(_) => "Ok".into(),
+                    });
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn call_request_depend() {
+        let results = Arc::new(Mutex::new(vec![]));
+
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_request(Request::new("greet", vec![], Some(VariableType::String)));
+            ctx.register_manager(GreetManager(results.clone())).unwrap();
+        });
+
+        let dir = std::env::temp_dir().join("plux_call_request_depend");
+        for name in ["server-v1.0.0.grt", "client-v1.0.0.grt"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+        }
+        loader
+            .load_plugins([
+                dir.join("server-v1.0.0.grt").to_str().unwrap(),
+                dir.join("client-v1.0.0.grt").to_str().unwrap(),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            *results.lock().unwrap(),
+            vec![
+                "Hello from server".to_string(),
+                "1.0.0".to_string(),
+                "Depend not found".to_string(),
+                "Failed to call request".to_string(),
+                "Depend not found".to_string(),
+            ]
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+        loader.stop().unwrap();
+    }
+
+    struct CommandManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for CommandManager {
+        fn format(&self) -> &'static str {
+            "cmd"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn load_plugin(
+            &mut self,
+            mut context: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            _api: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            let id = context.plugin().info().bundle.id.clone();
+            context.register_request(DynamicFunction::new(
+                "run",
+                vec![],
+                Some(Arg::new("output", VariableType::String)),
+                move |_| Ok(Some(format!("Handled by {id}").into())),
+            ))?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn call_request_first() {
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_request(Request::new("run", vec![], Some(VariableType::String)));
+            ctx.register_manager(CommandManager).unwrap();
+        });
+
+        let dir = std::env::temp_dir().join("plux_call_request_first");
+        let paths =
+            ["idle-v1.0.0.cmd", "first-v1.0.0.cmd", "second-v1.0.0.cmd"].map(|name| dir.join(name));
+        for path in paths.iter() {
+            std::fs::create_dir_all(path).unwrap();
+        }
+        let bundles = loader
+            .register_plugins(paths.iter().map(|path| path.to_str().unwrap()))
+            .unwrap();
+        for bundle in bundles[1..].iter() {
+            loader.load_plugin_by_bundle(bundle).unwrap();
+        }
+
+        // The plugin that is not loaded has no requests, the second one is not called
+        let output = loader.call_request_first("run", &[]).unwrap().unwrap();
+        assert_eq!(output.unwrap(), Some("Handled by first".into()));
+        assert!(matches!(
+            loader.call_request("run", &[]),
+            Err(PluginCallRequestError::NotFound)
+        ));
+
+        assert!(loader.call_request_first("missing", &[]).unwrap().is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn export_schema() {
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_request(Request::new("run", vec![], Some(VariableType::String)));
+            ctx.register_manager(CommandManager).unwrap();
+        });
+
+        let dir = std::env::temp_dir().join("plux_export_schema");
+        let paths = ["idle-v1.0.0.cmd", "tool-v1.2.0.cmd"].map(|name| dir.join(name));
+        for path in paths.iter() {
+            std::fs::create_dir_all(path).unwrap();
+        }
+        let bundles = loader
+            .register_plugins(paths.iter().map(|path| path.to_str().unwrap()))
+            .unwrap();
+        loader.load_plugin_by_bundle(&bundles[1]).unwrap();
+        loader
+            .get_plugin_mut_by_bundle(&bundles[1])
+            .unwrap()
+            .register_function(add())
+            .unwrap();
+
+        // Only loaded plugins are exported
+        let schema = loader.export_schema();
+        assert_eq!(schema.plugins.len(), 1);
+
+        let plugin = &schema.plugins[0];
+        assert_eq!(plugin.id, "tool");
+        assert_eq!(plugin.version, Version::new(1, 2, 0));
+
+        assert_eq!(plugin.functions.len(), 1);
+        assert_eq!(plugin.functions[0].name, "add");
+        assert_eq!(
+            plugin.functions[0].inputs,
+            vec![
+                Arg::new("a", VariableType::I32),
+                Arg::new("b", VariableType::I32)
+            ]
+        );
+        assert_eq!(
+            plugin.functions[0].output,
+            Some(Arg::new("output", VariableType::I32))
+        );
+
+        assert_eq!(plugin.requests.len(), 1);
+        assert_eq!(plugin.requests[0].name, "run");
+        assert!(plugin.requests[0].inputs.is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn loader_call_function() {
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+        loader.context(move |mut ctx| {
+            ctx.register_function(add());
+            ctx.register_function(sub());
+        });
+
+        assert_eq!(
+            loader
+                .call_function("sub", &[5.into(), 3.into()])
+                .unwrap()
+                .unwrap(),
+            Some(2.into())
+        );
+        match loader.call_function("mul", &[]) {
+            Err(PluginCallFunctionError::NotFound) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
     #[test]
     fn common_call() {
         let mut loader = Loader::new();
@@ -114,6 +495,67 @@ mod tests {
         };
     }
 
+    #[test]
+    fn plugin_request_call() {
+        let mut loader = Loader::new();
+        loader.context(move |mut ctx| {
+            ctx.register_request(Request::new(
+                "echo".to_string(),
+                vec![VariableType::String],
+                Some(VariableType::String),
+            ));
+            ctx.register_manager(LuaManager::new()).unwrap();
+        });
+
+        let plugin = loader
+            .load_plugin_now(
+                get_plugin_path("function_plugin", "1.0.0", "lua")
+                    .to_str()
+                    .unwrap(),
+            )
+            .map(|bundle| loader.get_plugin_by_bundle(&bundle).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            request_call!(plugin, "echo", "Hello world")
+                .unwrap()
+                .unwrap(),
+            Some("Message v.1.0.0: Hello world".into())
+        );
+        assert!(request_call!(plugin, "missing").is_err());
+        assert!(request_call!(plugin, "missing", 1, 2).is_err());
+    }
+
+    #[test]
+    fn plugin_functions() {
+        let mut loader = Loader::new();
+        loader.context(move |mut ctx| {
+            ctx.register_request(Request::new("main".to_string(), vec![], None));
+            ctx.register_manager(LuaManager::new()).unwrap();
+        });
+
+        let bundle = loader
+            .load_plugin_now(
+                get_plugin_path("function_plugin", "1.0.0", "lua")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let plugin = loader.get_plugin_mut_by_bundle(&bundle).unwrap();
+        plugin.register_function(add()).unwrap();
+
+        assert!(plugin.has_request("main"));
+        assert!(!plugin.has_request("echo"));
+        assert!(plugin.has_function("add"));
+        assert!(!plugin.has_function("sub"));
+        assert_eq!(
+            plugin.function_signatures(),
+            vec![format!("{}", plugin.get_registry()[0])]
+        );
+        assert!(plugin.function_signatures()[0].starts_with("add("));
+    }
+
     #[test]
     fn loader_call_request() {
         let mut loader = Loader::new();
@@ -146,6 +588,41 @@ mod tests {
         };
     }
 
+    #[test]
+    fn loader_call_request_with_bundles() {
+        let mut loader = Loader::new();
+        loader.context(move |mut ctx| {
+            ctx.register_request(Request::new(
+                "echo".to_string(),
+                vec![VariableType::String],
+                Some(VariableType::String),
+            ));
+            ctx.register_manager(LuaManager::new()).unwrap();
+        });
+
+        let bundle = loader
+            .load_plugin_now(
+                get_plugin_path("function_plugin", "1.0.0", "lua")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let results = loader
+            .call_request_with_bundles("echo", &["Hello world".into()])
+            .unwrap();
+        assert_eq!(results.len(), 1);
+
+        match &results[0] {
+            (_, Err(e)) => panic!("{:?}: {}", e, e.to_string()),
+            (b, Ok(Some(result))) => {
+                assert_eq!(*b, bundle);
+                println!("{}: {:?}", b, result);
+            }
+            (_, Ok(None)) => panic!("Unexpected result"),
+        };
+    }
+
     #[test]
     fn parallel_call_request() {
         let mut loader = Loader::new();
@@ -2,8 +2,15 @@ mod utils;
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    };
+
     use plux_rs::prelude::*;
     use plux_lua_manager::LuaManager;
+    use plux_rs::utils::archive::zip;
+    use plux_rs::{LoadPluginContext, Manager, PluginInfo, RegisterPluginContext};
 
     use crate::utils::{get_plugin_path, loader_init, managers::VoidPluginManager};
 
@@ -17,6 +24,169 @@ mod tests {
         loader.stop().unwrap();
     }
 
+    #[test]
+    fn registered_formats() {
+        let mut loader = loader_init(VoidPluginManager::new());
+        loader.register_manager(LuaManager::new()).unwrap();
+        assert_eq!(loader.registered_formats(), vec!["vpl", "lua"]);
+
+        let bundle = Bundle::from_filename("void_plugin-v1.0.0.vpl").unwrap();
+        assert!(
+            loader
+                .registered_formats()
+                .contains(&bundle.format.as_str())
+        );
+
+        loader.unregister_manager("lua").unwrap();
+        assert_eq!(loader.registered_formats(), vec!["vpl"]);
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn get_plugin_manager_by_type() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        assert!(loader.get_manager_by_type::<VoidPluginManager>().is_some());
+        assert!(loader.get_manager_mut_by_type::<VoidPluginManager>().is_some());
+        assert!(loader.get_manager_by_type::<LuaManager>().is_none());
+
+        loader.stop().unwrap();
+    }
+
+    struct MultiFormatManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for MultiFormatManager {
+        fn format(&self) -> &'static str {
+            "mfa"
+        }
+
+        fn formats(&self) -> Vec<&'static str> {
+            vec!["mfa", "mfb"]
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+    }
+
+    #[test]
+    fn manager_multiple_formats() {
+        let mut loader = loader_init(MultiFormatManager);
+
+        assert!(loader.get_manager_by_type::<MultiFormatManager>().is_some());
+        assert!(loader.get_manager_ref("mfa").is_some());
+        assert!(loader.get_manager_ref("mfb").is_some());
+        assert!(loader.par_get_manager_ref("mfb").is_some());
+
+        struct OverlappingManager;
+
+        impl<'a> Manager<'a, FunctionOutput, StdInfo> for OverlappingManager {
+            fn format(&self) -> &'static str {
+                "mfb"
+            }
+
+            fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+                Ok(StdInfo::new())
+            }
+        }
+
+        match loader.register_manager(OverlappingManager) {
+            Err(RegisterManagerError::AlreadyOccupiedFormat(format)) => assert_eq!(format, "mfb"),
+            _ => assert!(false),
+        };
+
+        let path = std::env::temp_dir().join("plux_manager_multiple_formats/plugin-v1.0.0.mfb");
+        std::fs::create_dir_all(&path).unwrap();
+
+        let bundle = loader.register_plugin(path.to_str().unwrap()).unwrap();
+        assert_eq!(bundle.format, "mfb");
+
+        loader.unregister_manager("mfb").unwrap();
+        assert!(loader.get_plugin_by_bundle(&bundle).is_none());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        loader.stop().unwrap();
+    }
+
+    struct PriorityManager(i32);
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for PriorityManager {
+        fn format(&self) -> &'static str {
+            "mfb"
+        }
+
+        fn priority(&self) -> i32 {
+            self.0
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+    }
+
+    #[test]
+    fn manager_priority() {
+        let mut loader = loader_init(MultiFormatManager);
+
+        // A fallback with a lower priority does not take over the format
+        loader.register_manager(PriorityManager(-1)).unwrap();
+        assert!(
+            loader
+                .get_manager_ref("mfb")
+                .unwrap()
+                .as_any()
+                .is::<MultiFormatManager>()
+        );
+
+        // Managers with the same priority cannot share a format
+        match loader.register_manager(PriorityManager(-1)) {
+            Err(RegisterManagerError::AlreadyOccupiedFormat(format)) => assert_eq!(format, "mfb"),
+            _ => assert!(false),
+        };
+
+        // A manager with a higher priority wins
+        loader.register_manager(PriorityManager(10)).unwrap();
+        let manager = loader.get_manager_ref("mfb").unwrap().as_any();
+        assert_eq!(manager.downcast_ref::<PriorityManager>().unwrap().0, 10);
+        let manager = loader.par_get_manager_ref("mfb").unwrap().as_any();
+        assert_eq!(manager.downcast_ref::<PriorityManager>().unwrap().0, 10);
+        assert!(
+            loader
+                .get_manager_ref("mfa")
+                .unwrap()
+                .as_any()
+                .is::<MultiFormatManager>()
+        );
+
+        let path = std::env::temp_dir().join("plux_manager_priority/plugin-v1.0.0.mfb");
+        std::fs::create_dir_all(&path).unwrap();
+
+        let bundle = loader.register_plugin(path.to_str().unwrap()).unwrap();
+
+        // Unregistering the format removes the manager that handles it
+        loader.unregister_manager("mfb").unwrap();
+        assert!(loader.get_plugin_by_bundle(&bundle).is_none());
+        let manager = loader.get_manager_ref("mfb").unwrap().as_any();
+        assert!(manager.is::<MultiFormatManager>());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn unregister_manager_by_type() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        assert!(loader.unregister_manager_by_type::<LuaManager>().is_err());
+        loader
+            .unregister_manager_by_type::<VoidPluginManager>()
+            .unwrap();
+        assert!(loader.get_manager_ref("vpl").is_none());
+
+        loader.stop().unwrap();
+    }
+
     #[test]
     fn register_plugin() {
         let mut loader = loader_init(VoidPluginManager::new());
@@ -40,6 +210,361 @@ mod tests {
         loader.stop().unwrap();
     }
 
+    #[test]
+    fn canonical_plugin_path() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let path = get_plugin_path("void_plugin", "1.0.0", "vpl");
+        let bundle = loader
+            .register_plugin("./tests/plugins/../plugins/void_plugin-v1.0.0.vpl")
+            .unwrap();
+
+        let plugin = loader.get_plugin_by_bundle(&bundle).unwrap();
+        assert!(plugin.path().is_absolute());
+        assert_eq!(plugin.path(), std::fs::canonicalize(&path).unwrap());
+
+        // Another spelling of the same path is the same plugin
+        assert!(matches!(
+            loader.register_plugin(path.to_str().unwrap()),
+            Err(RegisterPluginError::AlreadyExistsIDAndVersion(..))
+        ));
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn register_plugin_from_bytes() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let name = "void_plugin-v1.0.0.vpl";
+        let temp_path = std::env::temp_dir().join("plux_register_plugin_from_bytes");
+        std::fs::create_dir_all(&temp_path).unwrap();
+        zip(
+            &get_plugin_path("void_plugin", "1.0.0", "vpl"),
+            temp_path.to_str().unwrap(),
+            zip::CompressionMethod::Stored,
+            None::<fn(&std::path::Path)>,
+        )
+        .unwrap();
+        let data = std::fs::read(temp_path.join(name)).unwrap();
+        std::fs::remove_dir_all(&temp_path).unwrap();
+
+        let bundle = loader.register_plugin_from_bytes(name, &data).unwrap();
+
+        let path = loader
+            .get_plugin_by_bundle(&bundle)
+            .unwrap()
+            .info()
+            .path
+            .clone();
+        assert!(path.join("config.toml").exists());
+
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+        loader.unregister_plugin_by_bundle(&bundle).unwrap();
+        assert!(!path.exists());
+
+        loader.stop().unwrap();
+    }
+
+    struct StickyManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for StickyManager {
+        fn format(&self) -> &'static str {
+            "sticky"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn unregister_plugin(
+            &mut self,
+            _: &Plugin<'a, FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            Err("unregister plugin".into())
+        }
+    }
+
+    #[test]
+    fn register_plugin_from_bytes_cleanup() {
+        use std::io::Write;
+        use zip::{ZipWriter, write::SimpleFileOptions};
+
+        let mut loader = loader_init(VoidPluginManager::new());
+        loader.register_manager(StickyManager).unwrap();
+
+        let archive = |root: &str| {
+            let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+            writer
+                .add_directory(root, SimpleFileOptions::default())
+                .unwrap();
+            writer
+                .start_file(format!("{root}/config.toml"), SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(b"depends = []").unwrap();
+            writer.finish().unwrap().into_inner()
+        };
+
+        // The root directory of the archive must match the bundle name
+        let data = archive("other_plugin-v1.0.0.vpl");
+        match loader.register_plugin_from_bytes("void_plugin-v1.0.0.vpl", &data) {
+            Err(RegisterPluginError::ExtractArchiveFailed(
+                BundleUnzipError::MismatchedBundle { .. },
+            )) => assert!(true),
+            _ => assert!(false),
+        }
+        assert!(loader.get_plugins().is_empty());
+
+        // The temporary directory is removed even if the manager fails to unregister
+        let data = archive("sticky_plugin-v1.0.0.sticky");
+        let bundle = loader
+            .register_plugin_from_bytes("sticky_plugin-v1.0.0.sticky", &data)
+            .unwrap();
+        let path = loader
+            .get_plugin_by_bundle(&bundle)
+            .unwrap()
+            .info()
+            .path
+            .clone();
+        assert!(path.join("config.toml").exists());
+
+        assert!(matches!(
+            loader.unregister_plugin_by_bundle(&bundle),
+            Err(UnregisterPluginError::UnregisterPluginByManager(_))
+        ));
+        assert!(!path.exists());
+
+        loader.stop().unwrap();
+    }
+
+    struct VirtualManager(Arc<AtomicUsize>);
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for VirtualManager {
+        fn format(&self) -> &'static str {
+            "virt"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Err("Virtual plugins have no files".into())
+        }
+
+        fn register_virtual_plugin(&mut self, info: &PluginInfo<StdInfo>) -> ManagerResult<()> {
+            match info.bundle.id.as_str() {
+                "rejected" => Err("Rejected by the manager".into()),
+                _ => {
+                    self.0.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn register_virtual_plugin() {
+        let registered = Arc::new(AtomicUsize::new(0));
+        let mut loader = loader_init(VirtualManager(registered.clone()));
+
+        let plugin_info = |name: &str| PluginInfo {
+            path: std::path::PathBuf::new(),
+            bundle: Bundle::from_filename(name).unwrap(),
+            info: StdInfo::new(),
+        };
+
+        let bundle = loader
+            .register_virtual_plugin("virt", plugin_info("generated-v1.0.0.virt"))
+            .unwrap();
+        assert_eq!(registered.load(Ordering::SeqCst), 1);
+
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+        assert!(loader.is_loaded(&bundle));
+        assert!(loader.get_plugin_by_bundle(&bundle).unwrap().is_virtual());
+
+        assert!(matches!(
+            loader.register_virtual_plugin("virt", plugin_info("generated-v1.0.0.virt")),
+            Err(RegisterPluginError::AlreadyExistsIDAndVersion(..))
+        ));
+        assert!(matches!(
+            loader.register_virtual_plugin("none", plugin_info("other-v1.0.0.none")),
+            Err(RegisterPluginError::UnknownManagerFormat(format)) if format == "none"
+        ));
+        assert!(matches!(
+            loader.register_virtual_plugin("virt", plugin_info("other-v1.0.0.lua")),
+            Err(RegisterPluginError::MismatchedFormat { found, .. }) if found == "lua"
+        ));
+        assert!(matches!(
+            loader.register_virtual_plugin("virt", plugin_info("rejected-v1.0.0.virt")),
+            Err(RegisterPluginError::RegisterPluginByManager(_))
+        ));
+        assert_eq!(registered.load(Ordering::SeqCst), 1);
+
+        // A virtual plugin has no files to reload from, so it stays loaded
+        assert!(matches!(
+            loader.reload_plugin_by_bundle(&bundle),
+            Err(ReloadPluginError::VirtualPlugin)
+        ));
+        assert!(loader.is_loaded(&bundle));
+
+        loader.unregister_plugin_by_bundle(&bundle).unwrap();
+        assert_eq!(loader.get_plugins().len(), 0);
+
+        loader.stop().unwrap();
+    }
+
+    struct InfoManager {
+        loaded: Arc<std::sync::Mutex<Vec<(std::path::PathBuf, Vec<Depend>)>>>,
+    }
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for InfoManager {
+        fn format(&self) -> &'static str {
+            "inf"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Err("Info plugins have no files".into())
+        }
+
+        fn load_plugin(
+            &mut self,
+            _: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            api: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            let info = api.plugin_info().ok_or("Plugin is not registered")?;
+            assert_eq!(info.bundle, *api.plugin());
+
+            self.loaded
+                .lock()
+                .unwrap()
+                .push((info.path.clone(), info.info.optional_depends.clone()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn api_plugin_info() {
+        let loaded = Arc::new(std::sync::Mutex::new(vec![]));
+        let mut loader = loader_init(InfoManager {
+            loaded: loaded.clone(),
+        });
+
+        let depend = Depend::new("palette".into(), "1.0".parse().unwrap());
+        let bundle = loader
+            .register_virtual_plugin(
+                "inf",
+                PluginInfo {
+                    path: "plugins/paint".into(),
+                    bundle: Bundle::from_filename("paint-v1.0.0.inf").unwrap(),
+                    info: StdInfo::new().with_optional_depend(depend.clone()),
+                },
+            )
+            .unwrap();
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+
+        assert_eq!(
+            *loaded.lock().unwrap(),
+            vec![("plugins/paint".into(), vec![depend])]
+        );
+
+        loader.stop().unwrap();
+    }
+
+    struct EventManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for EventManager {
+        fn format(&self) -> &'static str {
+            "evt"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Err("Event plugins have no files".into())
+        }
+
+        fn load_plugin(
+            &mut self,
+            _: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            api: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            api.emit("loading", Variable::Null);
+            api.emit("loaded", api.plugin().id.clone().into());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn plugin_events() {
+        let mut loader = loader_init(EventManager);
+
+        let plugin_info = |name: &str| PluginInfo {
+            path: std::path::PathBuf::new(),
+            bundle: Bundle::from_filename(name).unwrap(),
+            info: StdInfo::new(),
+        };
+        let first = loader
+            .register_virtual_plugin("evt", plugin_info("first-v1.0.0.evt"))
+            .unwrap();
+        let second = loader
+            .register_virtual_plugin("evt", plugin_info("second-v1.0.0.evt"))
+            .unwrap();
+
+        let events = loader.events();
+        let dropped = loader.events();
+        drop(dropped);
+
+        loader.load_plugin_by_bundle(&first).unwrap();
+        let late = loader.events();
+        loader.load_plugin_by_bundle(&second).unwrap();
+
+        let received: Vec<_> = events.try_iter().collect();
+        assert_eq!(
+            received,
+            vec![
+                PluginEvent {
+                    source: first.clone(),
+                    name: "loading".into(),
+                    payload: Variable::Null,
+                },
+                PluginEvent {
+                    source: first.clone(),
+                    name: "loaded".into(),
+                    payload: "first".into(),
+                },
+                PluginEvent {
+                    source: second.clone(),
+                    name: "loading".into(),
+                    payload: Variable::Null,
+                },
+                PluginEvent {
+                    source: second.clone(),
+                    name: "loaded".into(),
+                    payload: "second".into(),
+                },
+            ]
+        );
+
+        // A subscriber only receives the events emitted after it subscribed
+        let received: Vec<_> = late.try_iter().collect();
+        assert_eq!(received.len(), 2);
+        assert!(received.iter().all(|event| event.source == second));
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn verify_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let path = std::env::temp_dir().join("plux_verify_plugin/broken_plugin-v1.0.0.vpl");
+        std::fs::create_dir_all(&path).unwrap();
+
+        match loader.register_plugin(path.to_str().unwrap()) {
+            Err(RegisterPluginError::RegisterPluginByManager(_)) => assert!(true),
+            _ => assert!(false),
+        };
+        assert!(loader.get_plugins_by_id("broken_plugin").is_empty());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        loader.stop().unwrap();
+    }
+
     #[test]
     fn load_plugin() {
         let mut loader = loader_init(VoidPluginManager::new());
@@ -58,73 +583,1107 @@ mod tests {
         loader.stop().unwrap();
     }
 
+    // Reports a fixed amount of memory for every loaded plugin
+    struct MemoryManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for MemoryManager {
+        fn format(&self) -> &'static str {
+            "mem"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn plugin_memory(&self, plugin: &Plugin<'a, FunctionOutput, StdInfo>) -> Option<usize> {
+            plugin.is_load().then_some(1024)
+        }
+    }
+
     #[test]
-    fn load_now_plugin() {
-        let mut loader = loader_init(VoidPluginManager::new());
+    fn plugin_memory() {
+        let mut loader = loader_init(MemoryManager);
+        loader
+            .context(|mut ctx| ctx.register_manager(VoidPluginManager::new()))
+            .unwrap();
 
-        let bundle = loader
+        let path = std::env::temp_dir().join("plux_plugin_memory/plugin-v1.0.0.mem");
+        std::fs::create_dir_all(&path).unwrap();
+        let bundle = loader.register_plugin(path.to_str().unwrap()).unwrap();
+        assert_eq!(loader.plugin_memory(&bundle), None);
+
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+        assert_eq!(loader.plugin_memory(&bundle), Some(1024));
+        assert_eq!(
+            loader.get_plugin_by_bundle(&bundle).unwrap().memory_usage(),
+            Some(1024)
+        );
+
+        // The void manager does not report memory usage
+        let void = loader
             .load_plugin_now(
                 get_plugin_path("void_plugin", "1.0.0", "vpl")
                     .to_str()
                     .unwrap(),
             )
             .unwrap();
+        assert_eq!(loader.plugin_memory(&void), None);
 
-        loader.unload_plugin_by_bundle(&bundle).unwrap();
+        loader.unregister_plugin_by_bundle(&bundle).unwrap();
+        assert_eq!(loader.plugin_memory(&bundle), None);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
         loader.stop().unwrap();
     }
 
+    #[cfg(feature = "log")]
     #[test]
-    fn unload_managers() {
-        let mut loader = Loader::new();
-        loader.context(|mut ctx| {
-            ctx.register_manager(VoidPluginManager::new()).unwrap();
-            ctx.register_manager(LuaManager::new()).unwrap();
-        });
+    fn log_loader() {
+        use std::sync::Mutex;
 
-        let paths = vec![
-            get_plugin_path("dependency/dep_1", "1.0.0", "vpl"),
-            get_plugin_path("plugin_for_manager", "1.0.0", "vpl"),
-            get_plugin_path("dependency/dep_2", "1.0.0", "vpl"),
-            get_plugin_path("function_plugin", "1.0.0", "lua"),
-            get_plugin_path("dependency/dep_3", "1.0.0", "vpl"),
-            get_plugin_path("dependency/dep_4", "1.0.0", "vpl"),
-        ];
+        struct Logger(Mutex<Vec<String>>);
 
-        loader
-            .load_plugins(paths.iter().map(|x| x.to_str().unwrap()))
+        impl log::Log for Logger {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.target().starts_with("plux_rs")
+            }
+
+            fn log(&self, record: &log::Record) {
+                if self.enabled(record.metadata()) {
+                    self.0.lock().unwrap().push(record.args().to_string());
+                }
+            }
+
+            fn flush(&self) {}
+        }
+
+        static LOGGER: Logger = Logger(Mutex::new(vec![]));
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let mut loader = loader_init(VoidPluginManager::new());
+        let bundle = loader
+            .register_plugin(
+                get_plugin_path("void_plugin", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
             .unwrap();
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+        loader.stop().unwrap();
 
-        match loader.unregister_manager("lua") {
-            Err(UnregisterManagerError::UnregisterPlugin(UnregisterPluginError::UnloadError(
-                UnloadPluginError::CurrentlyUsesDepend { .. },
-            ))) => assert!(true),
-            _ => assert!(false),
-        };
+        // Other tests run in parallel, so only check that the expected records are present
+        let records = LOGGER.0.lock().unwrap();
+        for record in [
+            "Registered manager for formats [\"vpl\"] with priority 0".to_string(),
+            format!("Loaded plugin {bundle}"),
+            format!("Unloaded plugin {bundle}"),
+            format!("Unregistering plugin {bundle}"),
+        ] {
+            assert!(records.contains(&record), "missing record: {record}");
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn load_plugin_async() {
+        use crate::utils::managers::AsyncVoidPluginManager;
+        use plux_rs::AsyncManagerAdapter;
+
+        let mut loader = loader_init(AsyncManagerAdapter::new(AsyncVoidPluginManager::new()));
+
+        block_on(async {
+            loader
+                .register_plugin_async(
+                    get_plugin_path("dependency/dep_1", "1.0.0", "vpl")
+                        .to_str()
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            let bundle = loader
+                .register_plugin_async(
+                    get_plugin_path("dependency/dep_4", "1.0.0", "vpl")
+                        .to_str()
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            loader.load_plugin_by_bundle_async(&bundle).await.unwrap();
+            assert!(loader.is_loaded(&bundle));
+            assert!(loader.is_loaded_by_id("dep_1", &bundle.version));
+        });
 
         loader.stop().unwrap();
     }
 
+    #[cfg(feature = "async")]
     #[test]
-    fn heavy_load() {
-        let mut loader = Loader::new();
-        loader.context(|mut ctx| {
-            ctx.register_manager(VoidPluginManager::new()).unwrap();
-            ctx.register_manager(LuaManager::new()).unwrap();
+    fn context_async() {
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+
+        let future = loader.context_async(|mut ctx| async move {
+            // Stands in for an asynchronous manager initialization
+            let manager = async { VoidPluginManager::new() }.await;
+            ctx.register_manager(manager)
         });
 
-        let paths = vec![
-            get_plugin_path("dependency/dep_1", "1.0.0", "vpl"),
-            get_plugin_path("plugin_for_manager", "1.0.0", "vpl"),
-            get_plugin_path("dependency/dep_2", "1.0.0", "vpl"),
-            get_plugin_path("function_plugin", "1.0.0", "lua"),
-            get_plugin_path("dependency/dep_3", "1.0.0", "vpl"),
-            get_plugin_path("dependency/dep_4", "1.0.0", "vpl"),
-        ];
+        // The future can be moved to another thread of a runtime
+        fn assert_send<T: Send>(_: &T) {}
+        assert_send(&future);
 
-        loader
-            .load_plugins(paths.iter().map(|x| x.to_str().unwrap()))
-            .unwrap();
+        block_on(future).unwrap();
+        assert!(loader.get_manager_ref("vpl").is_some());
+
+        loader.stop().unwrap();
+    }
+
+    struct PolicyManager {
+        policy: std::sync::Arc<std::sync::Mutex<Option<LoadPolicy>>>,
+    }
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for PolicyManager {
+        fn format(&self) -> &'static str {
+            "pol"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn load_plugin(
+            &mut self,
+            context: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            _: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            *self.policy.lock().unwrap() = Some(context.policy().clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn load_policy() {
+        let policy = LoadPolicy {
+            max_memory: Some(1024),
+            timeout: Some(std::time::Duration::from_millis(100)),
+            ..LoadPolicy::new()
+        };
+        let seen_policy = std::sync::Arc::new(std::sync::Mutex::new(None));
+
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.set_default_policy(policy.clone());
+            ctx.register_manager(PolicyManager {
+                policy: seen_policy.clone(),
+            })
+            .unwrap();
+        });
+        assert_eq!(loader.default_policy(), &policy);
+
+        let path = std::env::temp_dir().join("plux_load_policy/plugin-v1.0.0.pol");
+        std::fs::create_dir_all(&path).unwrap();
+
+        loader.load_plugin_now(path.to_str().unwrap()).unwrap();
+        assert_eq!(seen_policy.lock().unwrap().as_ref(), Some(&policy));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn plugin_counts() {
+        let mut loader = Loader::<FunctionOutput, StdInfo>::with_capacity(16, 2);
+        assert!(loader.get_plugins().capacity() >= 16);
+
+        loader.context(|mut ctx| ctx.register_manager(VoidPluginManager::new()).unwrap());
+        assert_eq!(loader.manager_count(), 1);
+
+        let bundle = loader
+            .register_plugin(
+                get_plugin_path("void_plugin", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(loader.plugin_count(), 1);
+        assert_eq!(loader.loaded_plugin_count(), 0);
+
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+        assert_eq!(loader.plugin_count(), 1);
+        assert_eq!(loader.loaded_plugin_count(), 1);
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn is_loaded_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let bundle = loader
+            .register_plugin(
+                get_plugin_path("void_plugin", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert!(!loader.is_loaded(&bundle));
+
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+        assert!(loader.is_loaded(&bundle));
+        assert!(loader.is_loaded_by_id(&bundle.id, &bundle.version));
+        assert_eq!(
+            loader.get_plugin_by_bundle(&bundle).unwrap().bundle(),
+            &bundle
+        );
+
+        loader.unload_plugin_by_bundle(&bundle).unwrap();
+        assert!(!loader.is_loaded(&bundle));
+        assert!(!loader.is_loaded_by_id("missing", &bundle.version));
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn find_plugins() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let bundle = loader
+            .register_plugin(
+                get_plugin_path("void_plugin", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let is_loaded_vpl = |plugin: &Plugin<'_, FunctionOutput, StdInfo>| {
+            plugin.is_load() && plugin.bundle().format == "vpl"
+        };
+        assert!(loader.find_plugins(is_loaded_vpl).is_empty());
+
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+
+        let plugins = loader.find_plugins(is_loaded_vpl);
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].bundle(), &bundle);
+        assert_eq!(loader.par_find_plugins(is_loaded_vpl).len(), 1);
+        assert!(
+            loader
+                .find_plugins(|plugin| plugin.bundle().id == "missing")
+                .is_empty()
+        );
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn register_plugins_from_dir() {
+        let dir = std::env::current_dir().unwrap().join("tests/plugins");
+
+        let mut loader = loader_init(VoidPluginManager::new());
+        let bundles = loader.register_plugins_from_dir(&dir, false).unwrap();
+        let ids: Vec<_> = bundles.iter().map(|bundle| bundle.id.as_str()).collect();
+        assert_eq!(ids, ["plugin_for_manager", "void_plugin"]);
+        loader.stop().unwrap();
+
+        // Lua plugins are skipped without a Lua manager
+        let mut loader = loader_init(VoidPluginManager::new());
+        let bundles = loader.register_plugins_from_dir(&dir, true).unwrap();
+        assert_eq!(bundles.len(), 13);
+        assert!(bundles.iter().all(|bundle| bundle.format == "vpl"));
+        loader.stop().unwrap();
+
+        assert!(matches!(
+            loader.register_plugins_from_dir(&dir.join("missing"), false),
+            Err(RegisterPluginError::ReadDirectoryFailed(_))
+        ));
+    }
+
+    #[test]
+    fn load_now_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let bundle = loader
+            .load_plugin_now(
+                get_plugin_path("void_plugin", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        loader.unload_plugin_by_bundle(&bundle).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn load_now_missing_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        match loader.load_plugin_now(
+            get_plugin_path("missing_plugin", "1.0.0", "vpl")
+                .to_str()
+                .unwrap(),
+        ) {
+            Err(LoadPluginsError::Register(_)) => assert!(true),
+            _ => assert!(false),
+        };
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn load_metrics() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let bundle = loader
+            .load_plugin_now(
+                get_plugin_path("void_plugin", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert!(loader.load_metrics().is_empty());
+
+        loader.enable_load_metrics();
+        loader.unload_plugin_by_bundle(&bundle).unwrap();
+        loader.load_plugin_by_bundle(&bundle).unwrap();
+
+        let metrics = loader.load_metrics();
+        assert_eq!(metrics.len(), 1);
+        assert!(metrics.contains_key(&bundle));
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn unload_managers() {
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_manager(VoidPluginManager::new()).unwrap();
+            ctx.register_manager(LuaManager::new()).unwrap();
+        });
+
+        let paths = vec![
+            get_plugin_path("dependency/dep_1", "1.0.0", "vpl"),
+            get_plugin_path("plugin_for_manager", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_2", "1.0.0", "vpl"),
+            get_plugin_path("function_plugin", "1.0.0", "lua"),
+            get_plugin_path("dependency/dep_3", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_4", "1.0.0", "vpl"),
+        ];
+
+        loader
+            .load_plugins(paths.iter().map(|x| x.to_str().unwrap()))
+            .unwrap();
+
+        match loader.unregister_manager("lua") {
+            Err(UnregisterManagerError::UnregisterPlugin(UnregisterPluginError::UnloadError(
+                UnloadPluginError::CurrentlyUsesDepend { .. },
+            ))) => assert!(true),
+            _ => assert!(false),
+        };
+
+        loader.stop().unwrap();
+    }
+
+    struct FailingManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for FailingManager {
+        fn format(&self) -> &'static str {
+            "fail"
+        }
+
+        fn unregister_manager(&mut self) -> ManagerResult<()> {
+            Err("unregister manager".into())
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn unregister_plugin(
+            &mut self,
+            _: &Plugin<'a, FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            Err("unregister plugin".into())
+        }
+
+        fn unload_plugin(&mut self, _: &Plugin<'a, FunctionOutput, StdInfo>) -> ManagerResult<()> {
+            Err("unload plugin".into())
+        }
+    }
+
+    fn failing_loader() -> Loader<'static, FunctionOutput, StdInfo> {
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_manager(FailingManager).unwrap();
+            ctx.register_manager(VoidPluginManager::new()).unwrap();
+        });
+
+        let dir = std::env::temp_dir().join("plux_stop_failures");
+        for name in ["first-v1.0.0.fail", "second-v1.0.0.fail"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+            loader
+                .load_plugin_now(dir.join(name).to_str().unwrap())
+                .unwrap();
+        }
+        loader
+            .load_plugin_now(
+                get_plugin_path("void_plugin", "1.0.0", "vpl")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+        loader
+    }
+
+    #[test]
+    fn stop_with_failures() {
+        let mut loader = failing_loader();
+
+        match loader.stop() {
+            Err(StopLoaderError::UnregisterFailed { plugins, managers }) => {
+                // Each failing plugin fails to unload and to unregister
+                assert_eq!(plugins.len(), 4);
+                assert_eq!(managers.len(), 1);
+            }
+            _ => assert!(false),
+        };
+
+        // Everything is detached despite the failures
+        assert_eq!(loader.plugin_count(), 0);
+        assert_eq!(loader.manager_count(), 0);
+        loader.stop().unwrap();
+
+        // Dropping a loader that fails to stop does not panic
+        drop(failing_loader());
+    }
+
+    struct CountingManager {
+        unregistered: Arc<AtomicUsize>,
+    }
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for CountingManager {
+        fn format(&self) -> &'static str {
+            "cnt"
+        }
+
+        fn unregister_manager(&mut self) -> ManagerResult<()> {
+            self.unregistered.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn unregister_plugin(
+            &mut self,
+            _: &Plugin<'a, FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            self.unregistered.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn plugin_manager_after_managers_change() {
+        let unregistered = Arc::new(AtomicUsize::new(0));
+
+        let mut loader = loader_init(VoidPluginManager::new());
+        loader
+            .register_manager(CountingManager {
+                unregistered: unregistered.clone(),
+            })
+            .unwrap();
+
+        let path = std::env::temp_dir().join("plux_manager_change/counted-v1.0.0.cnt");
+        std::fs::create_dir_all(&path).unwrap();
+        let bundle = loader.register_plugin(path.to_str().unwrap()).unwrap();
+
+        // Shift and grow the managers, the plugin keeps calling its own manager
+        loader.unregister_manager("vpl").unwrap();
+        loader
+            .register_manager(BatchManager {
+                log: Default::default(),
+            })
+            .unwrap();
+        loader.register_manager(LuaManager::new()).unwrap();
+
+        loader.unregister_plugin_by_bundle(&bundle).unwrap();
+        assert_eq!(unregistered.load(Ordering::SeqCst), 1);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn stop_idempotent() {
+        let unregistered = Arc::new(AtomicUsize::new(0));
+
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+        loader.context(|mut ctx| {
+            ctx.register_manager(CountingManager {
+                unregistered: unregistered.clone(),
+            })
+            .unwrap();
+        });
+
+        let path = std::env::temp_dir().join("plux_stop_idempotent/counted-v1.0.0.cnt");
+        std::fs::create_dir_all(&path).unwrap();
+        loader.load_plugin_now(path.to_str().unwrap()).unwrap();
+
+        // The plugin and the manager are unregistered once
+        loader.stop().unwrap();
+        assert_eq!(unregistered.load(Ordering::SeqCst), 2);
+        loader.stop().unwrap();
+        drop(loader);
+        assert_eq!(unregistered.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn heavy_load() {
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_manager(VoidPluginManager::new()).unwrap();
+            ctx.register_manager(LuaManager::new()).unwrap();
+        });
+
+        let paths = vec![
+            get_plugin_path("dependency/dep_1", "1.0.0", "vpl"),
+            get_plugin_path("plugin_for_manager", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_2", "1.0.0", "vpl"),
+            get_plugin_path("function_plugin", "1.0.0", "lua"),
+            get_plugin_path("dependency/dep_3", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_4", "1.0.0", "vpl"),
+        ];
+
+        loader
+            .load_plugins(paths.iter().map(|x| x.to_str().unwrap()))
+            .unwrap();
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn resolved_depends() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let paths = vec![
+            get_plugin_path("dependency/dep_1", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_2", "1.0.0", "vpl"),
+        ];
+
+        let bundles = loader
+            .load_plugins(paths.iter().map(|x| x.to_str().unwrap()))
+            .unwrap();
+
+        let plugin = loader.get_plugin_by_bundle(&bundles[1]).unwrap();
+        assert_eq!(plugin.resolved_depends(), &[bundles[0].clone()]);
+        // The optional dependency on dep_3 v2.0.0 cannot be resolved
+        assert!(plugin.resolved_optional_depends().is_empty());
+
+        loader.unload_plugin_by_bundle(&bundles[1]).unwrap();
+        let plugin = loader.get_plugin_by_bundle(&bundles[1]).unwrap();
+        assert!(plugin.resolved_depends().is_empty());
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn par_load_with_thread_pool() {
+        let pool = std::sync::Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap(),
+        );
+
+        let mut loader: Loader<'_, FunctionOutput, StdInfo> =
+            Loader::builder().thread_pool(pool.clone()).build().unwrap();
+        loader.context(|mut ctx| {
+            ctx.register_manager(VoidPluginManager::new()).unwrap();
+        });
+
+        assert!(std::ptr::eq(loader.thread_pool().unwrap(), pool.as_ref()));
+
+        let paths = vec![
+            get_plugin_path("dependency/dep_1", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_2", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_3", "1.0.0", "vpl"),
+            get_plugin_path("dependency/dep_4", "1.0.0", "vpl"),
+        ];
+
+        let paths: Vec<_> = paths.iter().map(|x| x.to_str().unwrap()).collect();
+        let bundles = loader.par_load_plugins(paths).unwrap();
+
+        assert_eq!(bundles.len(), 4);
+        assert!(bundles.iter().all(|bundle| loader.is_loaded(bundle)));
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn par_register_same_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let path = get_plugin_path("dependency/dep_1", "1.0.0", "vpl");
+        let path = path.to_str().unwrap();
+
+        // Paths are checked in parallel, the duplicate is caught when registering
+        assert!(matches!(
+            loader.par_register_plugins(vec![path; 8]),
+            Err(RegisterPluginError::AlreadyExistsIDAndVersion(..))
+        ));
+        assert_eq!(loader.plugin_count(), 1);
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn merge() {
+        let mut loader = loader_init(VoidPluginManager::new());
+        let path = get_plugin_path("dependency/dep_1", "1.0.0", "vpl");
+        let bundle = loader.load_plugin_now(path.to_str().unwrap()).unwrap();
+
+        let unregistered = Arc::new(AtomicUsize::new(0));
+        let mut other = Loader::new();
+        other.context(|mut ctx| {
+            ctx.register_manager(CountingManager {
+                unregistered: unregistered.clone(),
+            })
+            .unwrap();
+        });
+
+        let path = std::env::temp_dir().join("plux_merge/counted-v1.0.0.cnt");
+        std::fs::create_dir_all(&path).unwrap();
+        let counted = other.load_plugin_now(path.to_str().unwrap()).unwrap();
+
+        loader.merge(other).unwrap();
+        assert_eq!(loader.manager_count(), 2);
+        assert_eq!(loader.plugin_count(), 2);
+        assert!(loader.is_loaded(&bundle));
+        assert!(loader.is_loaded(&counted));
+
+        // The moved plugin still reaches its manager
+        loader.unregister_plugin_by_bundle(&counted).unwrap();
+        assert_eq!(unregistered.load(Ordering::SeqCst), 1);
+
+        // Conflicting loaders are rejected without changing the loader
+        assert!(matches!(
+            loader.merge(loader_init(VoidPluginManager::new())),
+            Err(MergeLoaderError::AlreadyOccupiedFormat(format)) if format == "vpl"
+        ));
+
+        let mut other = Loader::new();
+        other.context(|mut ctx| ctx.register_request(Request::new("ping", vec![], None)));
+        assert!(matches!(
+            loader.merge(other),
+            Err(MergeLoaderError::RequestsNotFound(plugin, _)) if plugin == bundle
+        ));
+        assert_eq!(loader.manager_count(), 2);
+        assert_eq!(loader.plugin_count(), 1);
+
+        loader.stop().unwrap();
+        assert_eq!(unregistered.load(Ordering::SeqCst), 2);
+    }
+
+    struct ApiManager {
+        api: Arc<Mutex<Option<Api<FunctionOutput, StdInfo>>>>,
+    }
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for ApiManager {
+        fn format(&self) -> &'static str {
+            "api"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn load_plugin(
+            &mut self,
+            _: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            api: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            *self.api.lock().unwrap() = Some(api);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn merge_loaded_plugin_api() {
+        let mut loader = loader_init(VoidPluginManager::new());
+        let path = get_plugin_path("dependency/dep_1", "1.0.0", "vpl");
+        let bundle = loader.load_plugin_now(path.to_str().unwrap()).unwrap();
+
+        let api = Arc::new(Mutex::new(None));
+        let mut other = Loader::new();
+        other.context(|mut ctx| {
+            ctx.register_manager(ApiManager { api: api.clone() })
+                .unwrap()
+        });
+
+        let dir = std::env::temp_dir().join("plux_merge_api");
+        std::fs::create_dir_all(dir.join("kept-v1.0.0.api")).unwrap();
+        let kept = other
+            .load_plugin_now(dir.join("kept-v1.0.0.api").to_str().unwrap())
+            .unwrap();
+
+        loader.merge(other).unwrap();
+        assert!(loader.is_loaded(&kept));
+
+        // The plugin was loaded again, so its `Api` refers to the merged loader
+        {
+            let api = api.lock().unwrap();
+            let api = api.as_ref().unwrap();
+            assert!(api.get_plugin_by_bundle(&bundle).unwrap().is_load());
+            assert!(api.get_plugin_by_bundle(&kept).unwrap().is_load());
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn get_plugin_by_path() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let path = get_plugin_path("dependency/dep_1", "1.0.0", "vpl");
+        let bundle = loader.register_plugin(path.to_str().unwrap()).unwrap();
+
+        // The path does not have to be canonical
+        let plugin = loader.get_plugin_by_path(&path).unwrap();
+        assert_eq!(plugin.info().bundle, bundle);
+
+        let canonical = std::fs::canonicalize(&path).unwrap();
+        assert!(loader.get_plugin_by_path(&canonical).is_some());
+
+        let path = get_plugin_path("dependency/dep_2", "1.0.0", "vpl");
+        assert!(loader.get_plugin_by_path(&path).is_none());
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn register_plugin_not_a_directory() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let path = get_plugin_path("dependency/missing", "1.0.0", "vpl");
+        assert!(matches!(
+            loader.register_plugin(path.to_str().unwrap()),
+            Err(RegisterPluginError::NotFound)
+        ));
+
+        // An archive has to be extracted before it is registered
+        let path = std::env::current_dir()
+            .unwrap()
+            .join("tests/bundles/plugin_b-v1.0.0.vpl");
+        assert!(matches!(
+            loader.register_plugin(path.to_str().unwrap()),
+            Err(RegisterPluginError::NotADirectory(error_path)) if error_path == path
+        ));
+
+        loader.stop().unwrap();
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch_plugins() {
+        let unregistered = Arc::new(AtomicUsize::new(0));
+
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+        loader.context(|mut ctx| {
+            ctx.register_manager(CountingManager {
+                unregistered: unregistered.clone(),
+            })
+            .unwrap();
+        });
+
+        let path = std::env::temp_dir().join("plux_watch_plugins/watched-v1.0.0.cnt");
+        std::fs::create_dir_all(&path).unwrap();
+        let bundle = loader.load_plugin_now(path.to_str().unwrap()).unwrap();
+
+        // Nothing happens without changes
+        let watch = loader.watch_plugins().unwrap();
+        assert!(loader.process_watch_events().unwrap().reloaded.is_empty());
+
+        std::fs::write(path.join("main.txt"), "changed").unwrap();
+
+        let timer = std::time::Instant::now();
+        let reloaded = loop {
+            let reloaded = loader.process_watch_events().unwrap().reloaded;
+            if !reloaded.is_empty() || timer.elapsed() > std::time::Duration::from_secs(5) {
+                break reloaded;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        };
+
+        assert_eq!(reloaded, vec![bundle.clone()]);
+        assert_eq!(unregistered.load(Ordering::SeqCst), 1);
+        assert!(loader.is_loaded(&bundle));
+
+        // Dropping the handle stops watching
+        drop(watch);
+        std::fs::write(path.join("main.txt"), "changed again").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(400));
+        assert!(loader.process_watch_events().unwrap().reloaded.is_empty());
+
+        loader.stop().unwrap();
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[cfg(feature = "watch")]
+    struct FlakyManager(Arc<std::sync::atomic::AtomicBool>);
+
+    #[cfg(feature = "watch")]
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for FlakyManager {
+        fn format(&self) -> &'static str {
+            "flk"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn load_plugin(
+            &mut self,
+            context: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            _: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            // Only the plugin named `flaky` fails to load
+            let flaky = context.plugin().info().bundle.id == "flaky";
+            match flaky && self.0.load(Ordering::SeqCst) {
+                true => Err("load plugin".into()),
+                false => Ok(()),
+            }
+        }
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch_plugins_retry() {
+        let fail = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let mut loader = loader_init(FlakyManager(fail.clone()));
+
+        let path = std::env::temp_dir().join("plux_watch_plugins_retry/flaky-v1.0.0.flk");
+        std::fs::create_dir_all(&path).unwrap();
+        let bundle = loader.load_plugin_now(path.to_str().unwrap()).unwrap();
+
+        let _watch = loader.watch_plugins().unwrap();
+        fail.store(true, Ordering::SeqCst);
+        std::fs::write(path.join("main.txt"), "changed").unwrap();
+
+        let timer = std::time::Instant::now();
+        let report = loop {
+            let report = loader.process_watch_events().unwrap();
+            if !report.failed.is_empty() || timer.elapsed().as_secs() >= 5 {
+                break report;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        };
+        assert!(report.reloaded.is_empty());
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, bundle);
+
+        // The failed change is retried without another change of the files
+        fail.store(false, Ordering::SeqCst);
+        let report = loader.process_watch_events().unwrap();
+        assert_eq!(report.reloaded, vec![bundle.clone()]);
+        assert!(report.failed.is_empty());
+        assert!(loader.process_watch_events().unwrap().reloaded.is_empty());
+
+        loader.stop().unwrap();
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch_plugins_partial_failure() {
+        let fail = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let mut loader = loader_init(FlakyManager(fail.clone()));
+
+        let dir = std::env::temp_dir().join("plux_watch_plugins_partial_failure");
+        let mut bundles = vec![];
+        for name in ["flaky-v1.0.0.flk", "good-v1.0.0.flk"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+            bundles.push(
+                loader
+                    .load_plugin_now(dir.join(name).to_str().unwrap())
+                    .unwrap(),
+            );
+        }
+
+        let _watch = loader.watch_plugins().unwrap();
+        fail.store(true, Ordering::SeqCst);
+        for name in ["flaky-v1.0.0.flk", "good-v1.0.0.flk"] {
+            std::fs::write(dir.join(name).join("main.txt"), "changed").unwrap();
+        }
+
+        // The broken plugin sorts first, the good one is reloaded anyway
+        let (mut reloaded, mut failed) = (vec![], vec![]);
+        let timer = std::time::Instant::now();
+        while (reloaded.is_empty() || failed.is_empty()) && timer.elapsed().as_secs() < 5 {
+            let report = loader.process_watch_events().unwrap();
+            reloaded.extend(report.reloaded);
+            failed.extend(report.failed.into_iter().map(|(bundle, _)| bundle));
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        assert_eq!(reloaded, vec![bundles[1].clone()]);
+        assert!(!failed.is_empty() && failed.iter().all(|bundle| *bundle == bundles[0]));
+        assert!(loader.is_loaded(&bundles[1]));
+
+        // Only the failed change is still pending
+        fail.store(false, Ordering::SeqCst);
+        let report = loader.process_watch_events().unwrap();
+        assert_eq!(report.reloaded, vec![bundles[0].clone()]);
+        assert!(report.failed.is_empty());
+
+        loader.stop().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    struct BatchManager {
+        log: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for BatchManager {
+        fn format(&self) -> &'static str {
+            "bat"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn load_plugin(
+            &mut self,
+            context: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            _: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            let id = context.plugin().info().bundle.id.clone();
+            self.log.lock().unwrap().push(id);
+            Ok(())
+        }
+
+        fn begin_batch(&mut self) {
+            self.log.lock().unwrap().push("begin".to_string());
+        }
+
+        fn end_batch(&mut self) {
+            self.log.lock().unwrap().push("end".to_string());
+        }
+    }
+
+    #[test]
+    fn load_plugins_batch() {
+        let log = Arc::new(std::sync::Mutex::new(vec![]));
+
+        let mut loader = Loader::<'_, FunctionOutput, StdInfo>::new();
+        loader.context(|mut ctx| {
+            ctx.register_manager(BatchManager { log: log.clone() })
+                .unwrap();
+        });
+
+        let dir = std::env::temp_dir().join("plux_load_plugins_batch");
+        let paths: Vec<_> = ["first-v1.0.0.bat", "second-v1.0.0.bat"]
+            .iter()
+            .map(|name| dir.join(name))
+            .collect();
+        paths
+            .iter()
+            .for_each(|path| std::fs::create_dir_all(path).unwrap());
+
+        loader
+            .load_plugins(paths.iter().map(|path| path.to_str().unwrap()))
+            .unwrap();
+        assert_eq!(*log.lock().unwrap(), ["begin", "first", "second", "end"]);
+
+        // Loading a single plugin is not a batch
+        log.lock().unwrap().clear();
+        let path = dir.join("third-v1.0.0.bat");
+        std::fs::create_dir_all(&path).unwrap();
+        loader.load_plugin_now(path.to_str().unwrap()).unwrap();
+        assert_eq!(*log.lock().unwrap(), ["third"]);
+
+        loader.stop().unwrap();
+    }
+
+    struct RejectingManager;
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for RejectingManager {
+        fn format(&self) -> &'static str {
+            "rej"
+        }
+
+        fn register_manager(&mut self) -> ManagerResult<()> {
+            Err("register manager".into())
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+    }
+
+    #[test]
+    fn register_managers_rollback() {
+        let unregistered = Arc::new(AtomicUsize::new(0));
+
+        let mut loader = loader_init(VoidPluginManager::new());
+        let managers: Vec<Box<dyn Manager<'_, FunctionOutput, StdInfo>>> = vec![
+            Box::new(CountingManager {
+                unregistered: unregistered.clone(),
+            }),
+            Box::new(BatchManager {
+                log: Default::default(),
+            }),
+            Box::new(RejectingManager),
+            Box::new(LuaManager::new()),
+        ];
+
+        assert!(matches!(
+            loader.register_managers(managers),
+            Err(RegisterManagerError::RegisterManagerByManager(_))
+        ));
+
+        // The managers of the batch are unregistered, the earlier ones are kept
+        assert_eq!(loader.manager_count(), 1);
+        assert!(loader.get_manager_ref("vpl").is_some());
+        assert_eq!(unregistered.load(Ordering::SeqCst), 1);
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn sync_loader() {
+        let loader = SyncLoader::new(loader_init(VoidPluginManager::new()));
+
+        let handle = loader.clone();
+        let bundle = std::thread::spawn(move || {
+            let path = get_plugin_path("dependency/dep_1", "1.0.0", "vpl");
+            handle.load_plugin_now(path.to_str().unwrap()).unwrap()
+        })
+        .join()
+        .unwrap();
+
+        // Every clone of the handle shares the same loader
+        assert!(loader.is_loaded(&bundle));
+        assert_eq!(loader.lock().plugin_count(), 1);
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let handle = loader.clone();
+                let bundle = bundle.clone();
+                std::thread::spawn(move || handle.unload_plugin_by_bundle(&bundle).is_ok())
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+        assert!(!loader.is_loaded(&bundle));
 
         loader.stop().unwrap();
     }
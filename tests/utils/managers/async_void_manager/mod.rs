@@ -0,0 +1,53 @@
+use plux_rs::{
+    Api, AsyncManager, ManagerFuture, RegisterPluginContext, StdInfo, context::LoadPluginContext,
+};
+
+use super::void_manager::config::*;
+
+#[allow(dead_code)]
+pub struct AsyncVoidPluginManager {
+    configs: Vec<Config>,
+}
+
+impl<'a, O: Send + Sync> AsyncManager<'a, O, StdInfo> for AsyncVoidPluginManager {
+    fn format(&self) -> &'static str {
+        "vpl"
+    }
+
+    fn register_plugin<'f>(
+        &'f mut self,
+        context: RegisterPluginContext<'f>,
+    ) -> ManagerFuture<'f, StdInfo> {
+        Box::pin(async move {
+            let (config, info) = load_config(context.path)?;
+            self.configs.push(config);
+
+            println!(
+                "AsyncVoidPluginManager::register_plugin - {}",
+                context.bundle
+            );
+            Ok(info)
+        })
+    }
+
+    fn load_plugin<'f>(
+        &'f mut self,
+        context: LoadPluginContext<'a, 'f, O, StdInfo>,
+        _: Api<O, StdInfo>,
+    ) -> ManagerFuture<'f, ()> {
+        Box::pin(async move {
+            println!(
+                "AsyncVoidPluginManager::load_plugin - {}",
+                context.plugin().info().bundle
+            );
+            Ok(())
+        })
+    }
+}
+
+impl AsyncVoidPluginManager {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self { configs: vec![] }
+    }
+}
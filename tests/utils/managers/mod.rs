@@ -1,3 +1,8 @@
+#[cfg(feature = "async")]
+mod async_void_manager;
 mod void_manager;
 
+#[cfg(feature = "async")]
+#[allow(unused_imports)]
+pub use async_void_manager::AsyncVoidPluginManager;
 pub use void_manager::VoidPluginManager;
@@ -4,7 +4,7 @@ use plux_rs::{
     utils::ManagerResult,
 };
 
-mod config;
+pub mod config;
 
 pub struct VoidPluginManager {
     configs: Vec<Config>,
@@ -25,6 +25,15 @@ impl<'a, O: Send + Sync> Manager<'a, O, StdInfo> for VoidPluginManager {
         Ok(())
     }
 
+    fn verify_plugin(&self, context: &RegisterPluginContext) -> ManagerResult<()> {
+        if !context.path.join("config.toml").exists() {
+            return Err(Box::new(RegisterPluginError::DoesNotContainConfig));
+        }
+
+        println!("VoidPluginManager::verify_plugin - {}", context.bundle);
+        Ok(())
+    }
+
     fn register_plugin(&mut self, context: RegisterPluginContext) -> ManagerResult<StdInfo> {
         let (config, info) = load_config(context.path)?;
         self.configs.push(config);
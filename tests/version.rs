@@ -2,10 +2,19 @@ mod utils;
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::{
+        path::PathBuf,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
 
     use plux_rs::prelude::*;
     use plux_lua_manager::LuaManager;
+    use plux_rs::{LoadPluginContext, Manager, RegisterPluginContext};
+    use semver::VersionReq;
 
     use crate::utils::{get_plugin_path, loader_init, managers::VoidPluginManager};
 
@@ -94,6 +103,184 @@ mod tests {
         loader.stop().unwrap();
     }
 
+    #[test]
+    fn get_plugin_matching() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        loader
+            .load_plugins(get_versions_path().iter().map(|x| x.to_str().unwrap()))
+            .unwrap();
+
+        let matching = |loader: &Loader<'_, FunctionOutput, StdInfo>, req: &str| {
+            loader
+                .get_plugin_matching("brush", &req.parse().unwrap())
+                .map(|plugin| plugin.bundle().version.to_string())
+        };
+        assert_eq!(matching(&loader, "*"), Some("3.0.0".to_string()));
+        assert_eq!(matching(&loader, "<3.0.0"), Some("2.0.0".to_string()));
+        assert_eq!(matching(&loader, "=1.0.0"), Some("1.0.0".to_string()));
+        assert_eq!(matching(&loader, ">=4.0.0"), None);
+
+        // Only loaded plugins are matched
+        loader
+            .unload_plugin("brush", &"3.0.0".parse().unwrap())
+            .unwrap();
+        assert_eq!(matching(&loader, "*"), Some("2.0.0".to_string()));
+
+        assert!(
+            loader
+                .get_plugin_matching("missing", &"*".parse().unwrap())
+                .is_none()
+        );
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn get_latest_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        loader
+            .register_plugins(get_versions_path().iter().map(|x| x.to_str().unwrap()))
+            .unwrap();
+
+        let version = |plugin: Option<&Plugin<'_, FunctionOutput, StdInfo>>| {
+            plugin.map(|plugin| plugin.bundle().version.to_string())
+        };
+        assert_eq!(
+            version(loader.get_latest_plugin("brush")),
+            Some("3.0.0".to_string())
+        );
+        assert_eq!(
+            version(loader.par_get_latest_plugin("brush")),
+            Some("3.0.0".to_string())
+        );
+        assert!(loader.get_latest_plugin("missing").is_none());
+
+        let plugin = loader.get_latest_plugin_mut("brush").unwrap();
+        plugin.set_enabled(false);
+        assert!(
+            !loader
+                .par_get_latest_plugin_mut("brush")
+                .unwrap()
+                .is_enabled()
+        );
+
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn swap_plugin() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        // A version of brush between 2.0.0 and 3.0.0 that paint accepts
+        let dir = std::env::temp_dir().join("plux_swap_plugin");
+        let patched = dir.join("brush-v2.2.0.vpl");
+        std::fs::create_dir_all(&patched).unwrap();
+        std::fs::copy(
+            get_versions_path()[1].join("config.toml"),
+            patched.join("config.toml"),
+        )
+        .unwrap();
+
+        let paths = get_versions_path();
+        let path = |index: usize| paths[index].to_str().unwrap();
+        let brush = loader.register_plugin(path(1)).unwrap();
+        let paint = loader
+            .load_plugin_now(get_tools_path()[0].to_str().unwrap())
+            .unwrap();
+        let depends = |loader: &Loader<'_, FunctionOutput, StdInfo>| {
+            loader
+                .get_plugin_by_bundle(&paint)
+                .unwrap()
+                .resolved_depends()
+                .to_vec()
+        };
+        assert_eq!(depends(&loader), vec![brush.clone()]);
+
+        // Paint moves to the new version
+        let new = loader
+            .swap_plugin(&brush, patched.to_str().unwrap())
+            .unwrap();
+        assert_eq!(new.version.to_string(), "2.2.0");
+        assert!(loader.is_loaded(&new));
+        assert!(loader.is_loaded(&paint));
+        assert!(loader.get_plugin_by_bundle(&brush).is_none());
+        assert_eq!(depends(&loader), vec![new.clone()]);
+
+        // Another plugin cannot replace brush
+        match loader.swap_plugin(&new, get_tools_path()[1].to_str().unwrap()) {
+            Err(SwapPluginError::DifferentPlugin { old, .. }) => assert_eq!(old, new),
+            r => panic!("Expected a different plugin, got {r:?}"),
+        }
+        assert_eq!(loader.plugin_count(), 2);
+
+        // Brush cannot be downgraded
+        match loader.swap_plugin(&new, path(0)) {
+            Err(SwapPluginError::OlderVersion { old, .. }) => assert_eq!(old, new),
+            r => panic!("Expected an older version, got {r:?}"),
+        }
+        assert_eq!(loader.plugin_count(), 2);
+
+        // Paint does not accept 3.0.0, so the swap is rolled back
+        match loader.swap_plugin(&new, path(2)) {
+            Err(SwapPluginError::UnloadPlugin(_)) => {}
+            r => panic!("Expected an unload error, got {r:?}"),
+        }
+        assert_eq!(loader.plugin_count(), 2);
+        assert!(loader.is_loaded(&new));
+        assert!(loader.is_loaded(&paint));
+        assert_eq!(depends(&loader), vec![new.clone()]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn unload_and_unregister_matching() {
+        let mut loader = loader_init(VoidPluginManager::new());
+
+        let paths = get_versions_path();
+        let brushes = loader
+            .register_plugins(paths.iter().map(|path| path.to_str().unwrap()))
+            .unwrap();
+        let paint = loader
+            .load_plugin_now(get_tools_path()[0].to_str().unwrap())
+            .unwrap();
+        let req = |req: &str| VersionReq::parse(req).unwrap();
+
+        // Paint uses brush 2.0.0
+        assert!(matches!(
+            loader.unload_plugins_matching("brush", &req("^2")),
+            Err(UnloadPluginError::CurrentlyUsesDepend { .. })
+        ));
+        assert!(
+            loader
+                .unload_plugins_matching("pencil", &req("*"))
+                .unwrap()
+                .is_empty()
+        );
+
+        assert_eq!(
+            loader
+                .unregister_plugins_matching("brush", &req("<2.0.0"))
+                .unwrap(),
+            vec![brushes[0].clone()]
+        );
+        assert_eq!(loader.plugin_count(), 3);
+
+        loader.unload_plugin_by_bundle(&paint).unwrap();
+        assert_eq!(
+            loader
+                .unregister_plugins_matching("brush", &req("*"))
+                .unwrap(),
+            brushes[1..]
+        );
+        assert_eq!(loader.plugin_count(), 1);
+
+        loader.stop().unwrap();
+    }
+
     #[test]
     fn call_request() {
         let mut loader = Loader::new();
@@ -127,4 +314,267 @@ mod tests {
             Ok(None) => panic!("Unexpected result"),
         };
     }
+
+    #[test]
+    fn call_request_disabled() {
+        let mut loader = Loader::new();
+        loader.context(move |mut ctx| {
+            ctx.register_request(Request::new(
+                "echo".to_string(),
+                vec![VariableType::String],
+                Some(VariableType::String),
+            ));
+            ctx.register_manager(LuaManager::new()).unwrap();
+        });
+
+        let paths: Vec<_> = ["1.0.0", "2.0.0"]
+            .iter()
+            .map(|&version| get_plugin_path("function_plugin", version, "lua"))
+            .collect();
+
+        let bundles = loader
+            .load_plugins(paths.iter().map(|path| path.to_str().unwrap()))
+            .unwrap();
+
+        let called = |loader: &Loader<'_, FunctionOutput, StdInfo>| {
+            loader
+                .call_request_with_bundles("echo", &["Hello world".into()])
+                .unwrap()
+                .into_iter()
+                .map(|(bundle, _)| bundle)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(called(&loader), vec![bundles[1].clone()]);
+
+        // The older version takes over while the newer one is disabled
+        let plugin = loader.get_plugin_mut_by_bundle(&bundles[1]).unwrap();
+        plugin.set_enabled(false);
+        assert!(plugin.is_load() && !plugin.is_enabled());
+        assert_eq!(called(&loader), vec![bundles[0].clone()]);
+        assert_eq!(
+            loader
+                .par_call_request("echo", &["Hello world".into()])
+                .unwrap()
+                .len(),
+            1
+        );
+
+        loader
+            .get_plugin_mut_by_bundle(&bundles[0])
+            .unwrap()
+            .set_enabled(false);
+        assert!(called(&loader).is_empty());
+
+        loader
+            .get_plugin_mut_by_bundle(&bundles[1])
+            .unwrap()
+            .set_enabled(true);
+        assert_eq!(called(&loader), vec![bundles[1].clone()]);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn call_request_async() {
+        let mut loader = Loader::new();
+        loader.context(move |mut ctx| {
+            ctx.register_request(Request::new(
+                "echo".to_string(),
+                vec![VariableType::String],
+                Some(VariableType::String),
+            ));
+            ctx.register_manager(LuaManager::new()).unwrap();
+        });
+
+        loader
+            .load_plugin_now(
+                get_plugin_path("function_plugin", "2.0.0", "lua")
+                    .to_str()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let results = runtime
+            .block_on(loader.call_request_async("echo", &["Hello world".into()]))
+            .unwrap();
+
+        match results.get(0).unwrap() {
+            Err(e) => panic!("{:?}: {}", e, e.to_string()),
+            Ok(Some(result)) => println!("{:?}", result),
+            Ok(None) => panic!("Unexpected result"),
+        };
+
+        match runtime.block_on(loader.call_request_async("missing", &[])) {
+            Err(PluginCallRequestError::NotFound) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn call_request_async_cancel() {
+        use std::{future::Future, task::Poll, time::Instant};
+
+        let finished = Arc::new(AtomicUsize::new(0));
+
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_request(Request::new("wait".to_string(), vec![], None));
+            ctx.register_manager(SleepManager {
+                finished: finished.clone(),
+            })
+            .unwrap();
+        });
+
+        let dir = std::env::temp_dir().join("plux_call_request_async_cancel");
+        std::fs::create_dir_all(dir.join("slow-v1.0.0.slp")).unwrap();
+        loader
+            .load_plugin_now(dir.join("slow-v1.0.0.slp").to_str().unwrap())
+            .unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        // Poll the future once to spawn the call, then cancel it
+        let mut future = Box::pin(loader.call_request_async("wait", &[]));
+        runtime.block_on(std::future::poll_fn(|cx| {
+            assert!(future.as_mut().poll(cx).is_pending());
+            Poll::Ready(())
+        }));
+
+        // Dropping the future does not wait for the call, which does not borrow the loader
+        let start = Instant::now();
+        drop(future);
+        assert!(start.elapsed() < Duration::from_millis(300));
+        loader.stop().unwrap();
+
+        while finished.load(Ordering::SeqCst) < 1 {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    struct SleepManager {
+        finished: Arc<AtomicUsize>,
+    }
+
+    impl<'a> Manager<'a, FunctionOutput, StdInfo> for SleepManager {
+        fn format(&self) -> &'static str {
+            "slp"
+        }
+
+        fn register_plugin(&mut self, _: RegisterPluginContext) -> ManagerResult<StdInfo> {
+            Ok(StdInfo::new())
+        }
+
+        fn load_plugin(
+            &mut self,
+            mut context: LoadPluginContext<'a, '_, FunctionOutput, StdInfo>,
+            _: Api<FunctionOutput, StdInfo>,
+        ) -> ManagerResult<()> {
+            let id = context.plugin().info().bundle.id.clone();
+            let delay = match id.as_str() {
+                "slow" => Duration::from_millis(300),
+                _ => Duration::ZERO,
+            };
+            let finished = self.finished.clone();
+
+            context.register_request(DynamicFunction::new("wait", vec![], None, move |_| {
+                if id == "panic" {
+                    panic!("request panicked");
+                }
+                std::thread::sleep(delay);
+                finished.fetch_add(1, Ordering::SeqCst);
+                Ok(None)
+            }))?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn call_request_timeout() {
+        let finished = Arc::new(AtomicUsize::new(0));
+
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_request(Request::new("wait".to_string(), vec![], None));
+            ctx.register_manager(SleepManager {
+                finished: finished.clone(),
+            })
+            .unwrap();
+        });
+
+        let dir = std::env::temp_dir().join("plux_call_request_timeout");
+        let mut bundles = vec![];
+        for name in ["fast-v1.0.0.slp", "slow-v1.0.0.slp"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+            bundles.push(
+                loader
+                    .load_plugin_now(dir.join(name).to_str().unwrap())
+                    .unwrap(),
+            );
+        }
+
+        match loader.call_request_timeout("wait", &[], Duration::from_millis(50)) {
+            Err(PluginCallRequestError::Timeout(bundle)) => assert_eq!(bundle, bundles[1]),
+            _ => assert!(false),
+        };
+
+        let results = loader
+            .call_request_timeout("wait", &[], Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(results.len(), 2);
+
+        match loader.call_request_timeout("missing", &[], Duration::from_secs(5)) {
+            Err(PluginCallRequestError::NotFound) => assert!(true),
+            _ => assert!(false),
+        };
+
+        // Wait for the abandoned request, so that it does not outlive the test
+        while finished.load(Ordering::SeqCst) < 4 {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+        loader.stop().unwrap();
+    }
+
+    #[test]
+    fn call_request_timeout_panic() {
+        let finished = Arc::new(AtomicUsize::new(0));
+
+        let mut loader = Loader::new();
+        loader.context(|mut ctx| {
+            ctx.register_request(Request::new("wait".to_string(), vec![], None));
+            ctx.register_manager(SleepManager {
+                finished: finished.clone(),
+            })
+            .unwrap();
+        });
+
+        let dir = std::env::temp_dir().join("plux_call_request_timeout_panic");
+        for name in ["panic-v1.0.0.slp", "slow-v1.0.0.slp"] {
+            std::fs::create_dir_all(dir.join(name)).unwrap();
+            loader
+                .load_plugin_now(dir.join(name).to_str().unwrap())
+                .unwrap();
+        }
+
+        // The panicking plugin is not reported as timed out, its panic is resumed
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            loader.call_request_timeout("wait", &[], Duration::from_millis(100))
+        }));
+        assert!(result.is_err());
+
+        // The abandoned request owns its function, so the loader can be dropped before it ends
+        drop(loader);
+        while finished.load(Ordering::SeqCst) < 1 {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
 }